@@ -1,5 +1,9 @@
-use crate::math::{Complex, Matrix, One, Point, Vector, Zero};
-use core::ops::{Add, Mul, Neg, Sub};
+use crate::math::{
+    Angle, Complex, Cos, DeltaAngle, Exp, FitMode, Matrix, NonNeg, One, Point, Positive, Rect, Sin,
+    Size, Sq, Sqrt, Two, Vector, Zero, lerp_factor_dt,
+};
+use crate::range::{Range, RangeInclusive};
+use core::ops::{Add, Div, Mul, Neg, Sub};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -10,6 +14,8 @@ pub struct Camera<T> {
     translation: Matrix<T>,
     scale: Matrix<T>,
     rotation: Matrix<T>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    scale_limits: Option<RangeInclusive<T>>,
 }
 
 impl<T> Default for Camera<T>
@@ -21,6 +27,7 @@ where
             translation: Matrix::identity(),
             scale: Matrix::identity(),
             rotation: Matrix::identity(),
+            scale_limits: None,
         }
     }
 }
@@ -33,13 +40,41 @@ impl<T> Camera<T> {
         self.translation = Matrix::translate(translation - Point::origin());
     }
 
+    /// Scale is clamped to scale limits (see `set_scale_limits`)
     pub fn set_scale(&mut self, s: T)
     where
-        T: Zero + One + Clone,
+        T: Zero + One + Clone + PartialOrd,
     {
+        let s = self.clamp_scale(s);
         self.scale = Matrix::scale(s.clone(), s);
     }
 
+    /// Limit zoom applied by `set_scale`, `concat_scale_centered` and `pinch`.
+    /// Current scale is clamped immediately. Limits are swapped if `min` is greater than `max`.
+    pub fn set_scale_limits(&mut self, min: Positive<T>, max: Positive<T>)
+    where
+        T: Zero + One + Clone + PartialOrd,
+    {
+        let (start, end) = (min.into_inner(), max.into_inner());
+        self.scale_limits = Some(if start > end {
+            RangeInclusive {
+                start: end,
+                end: start,
+            }
+        } else {
+            RangeInclusive { start, end }
+        });
+        self.set_scale(self.scale.scale_x().clone());
+    }
+
+    pub fn clear_scale_limits(&mut self) {
+        self.scale_limits = None;
+    }
+
+    pub fn scale_limits(&self) -> Option<&RangeInclusive<T>> {
+        self.scale_limits.as_ref()
+    }
+
     pub fn set_rotation(&mut self, rotor: Complex<T>)
     where
         T: Zero + One + Clone + Neg<Output = T>,
@@ -47,6 +82,83 @@ impl<T> Camera<T> {
         self.rotation = Matrix::rotate(rotor);
     }
 
+    /// Rotate view around `pivot` given in screen coordinates, so that pivot stays in place
+    pub fn rotate_about(&mut self, pivot: Point<T>, delta: DeltaAngle<T>)
+    where
+        T: Zero
+            + One
+            + Clone
+            + Neg<Output = T>
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Cos<Output = T>
+            + Sin<Output = T>,
+    {
+        let rotor = Complex::from_polar(T::one(), Angle::from_radians(T::zero()) + delta);
+        let rotation = Matrix::rotate(rotor);
+        let translation = &(&Matrix::translate(pivot.clone() - Point::origin()) * &rotation)
+            * &Matrix::translate(Point::origin() - pivot);
+        self.translation = Matrix::translate((&translation * &self.translation).translation());
+        self.rotation = &rotation * &self.rotation;
+    }
+
+    /**
+     * @brief pinch - apply two finger gesture
+     * @param prev - touch points (screen coordinates) at previous event
+     * @param current - the same touch points at current event
+     *
+     * Midpoint movement translates, distance change scales (respecting scale limits)
+     * and direction change rotates the view, all around the gesture midpoint.
+     */
+    pub fn pinch(&mut self, prev: [Point<T>; 2], current: [Point<T>; 2])
+    where
+        T: Zero
+            + One
+            + Two
+            + Clone
+            + PartialOrd
+            + Sq<Output = T>
+            + Sqrt<Output = T>
+            + Neg<Output = T>
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>,
+    {
+        let [p0, p1] = prev;
+        let [c0, c1] = current;
+        let prev_vec = p1 - p0.clone();
+        let vec = c1 - c0.clone();
+        let prev_center = p0 + prev_vec.clone() / T::two();
+        let center = c0 + vec.clone() / T::two();
+
+        let prev_len = prev_vec.clone().len();
+        let len = vec.clone().len();
+        if prev_len == T::zero() || len == T::zero() {
+            self.add_translation(center - prev_center);
+            return;
+        }
+
+        let rotor = Complex::div(vec, prev_vec);
+        let rotor = Complex::from_cartesian(
+            rotor.real().clone() * prev_len.clone() / len.clone(),
+            rotor.imag().clone() * prev_len.clone() / len.clone(),
+        );
+
+        let scale = self.scale.scale_x().clone();
+        let scale_division = self.clamp_scale(scale.clone() * len / prev_len) / scale;
+
+        let rotation = Matrix::rotate(rotor);
+        let translation = &(&(&Matrix::translate(center - Point::origin()) * &rotation)
+            * &Matrix::scale(scale_division.clone(), scale_division.clone()))
+            * &Matrix::translate(Point::origin() - prev_center);
+
+        self.translation = Matrix::translate((&translation * &self.translation).translation());
+        self.scale = &Matrix::scale(scale_division.clone(), scale_division) * &self.scale;
+        self.rotation = &rotation * &self.rotation;
+    }
+
     pub fn add_translation(&mut self, vec: Vector<T>)
     where
         T: Zero + One + Clone + Add<Output = T>,
@@ -67,8 +179,17 @@ impl<T> Camera<T> {
         center: Point<T>,
         prev_center: Point<T>,
     ) where
-        T: Clone + Zero + One + Sub<Output = T> + Mul<Output = T> + Add<Output = T>,
+        T: Clone
+            + Zero
+            + One
+            + PartialOrd
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Add<Output = T>
+            + Div<Output = T>,
     {
+        let scale = self.scale.scale_x().clone();
+        let scale_division = self.clamp_scale(scale.clone() * scale_division) / scale;
         concat_scale_centered(
             &mut self.scale,
             &mut self.translation,
@@ -96,6 +217,223 @@ impl<T> Camera<T> {
     {
         &self.translation * &self.scale * &self.rotation
     }
+
+    pub fn world_to_screen(&self, point: &Point<T>) -> Point<T>
+    where
+        T: Clone + One + Mul<Output = T> + Add<Output = T> + Div<Output = T>,
+    {
+        &self.transformation() * point
+    }
+
+    /// None if camera transformation is degenerate (e.g. zero scale)
+    pub fn screen_to_world(&self, point: &Point<T>) -> Option<Point<T>>
+    where
+        T: Clone
+            + Zero
+            + One
+            + PartialEq
+            + Default
+            + Mul<Output = T>
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Div<Output = T>
+            + Neg<Output = T>,
+    {
+        Some(&(!&self.transformation())? * point)
+    }
+
+    /// Axis aligned bounds of the world area visible in a viewport placed at screen origin
+    pub fn visible_world_rect(&self, viewport: Size<T>) -> Option<Rect<T>>
+    where
+        T: Clone
+            + Zero
+            + One
+            + PartialOrd
+            + Default
+            + Mul<Output = T>
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Div<Output = T>
+            + Neg<Output = T>,
+    {
+        Some(&(!&self.transformation())? * &Rect::from(viewport))
+    }
+
+    /**
+     * @brief fit_rect - frame world rect so it is fully visible in the viewport.
+     *  Rotation is reset, scale is clamped to scale limits, rect center goes to viewport center.
+     * @param rect - world rect to show
+     * @param viewport - size of the viewport placed at screen origin
+     * @param padding - screen space margin kept on every side of the viewport
+     * @return false (camera is left untouched) if rect has zero width or height
+     *  or padding leaves no space in the viewport
+     */
+    pub fn fit_rect(&mut self, rect: Rect<T>, viewport: Size<T>, padding: NonNeg<T>) -> bool
+    where
+        T: Clone
+            + Zero
+            + One
+            + Two
+            + PartialOrd
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>,
+    {
+        let padding = padding.into_inner();
+        let (w, h) = viewport.into();
+        if padding.clone() * T::two() >= w || padding.clone() * T::two() >= h {
+            return false;
+        }
+        let target = Rect::from_lrtb_unchecked(
+            padding.clone(),
+            w - padding.clone(),
+            padding.clone(),
+            h - padding,
+        );
+        let Some(fit) = Matrix::from_rect_to_rect(rect.clone(), target.clone(), FitMode::Fit)
+        else {
+            return false;
+        };
+        let s = self.clamp_scale(fit.scale_x().clone());
+        let offset =
+            target.center() - Point::origin() - (rect.center() - Point::origin()) * s.clone();
+        self.translation = Matrix::translate(offset);
+        self.scale = Matrix::scale(s.clone(), s);
+        self.rotation = Matrix::identity();
+        true
+    }
+
+    /**
+     * @brief follow - move camera minimally so that target stays inside dead zone
+     * @param target - world point to follow
+     * @param dead_zone - screen rect in which target can move freely
+     */
+    pub fn follow(&mut self, target: Point<T>, dead_zone: Rect<T>)
+    where
+        T: Clone
+            + Zero
+            + One
+            + PartialOrd
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>,
+    {
+        let offset = self.follow_offset(target, dead_zone);
+        self.add_translation(offset);
+    }
+
+    /**
+     * @brief smooth_follow - frame-rate independent version of `follow` which eases into target translation.
+     *  After time `dt` the remaining distance to the translation `follow` would set is multiplied by `exp(-rate * dt)`,
+     *  so calling it twice with `dt / 2` gives the same result as calling once with `dt`.
+     * @param target - world point to follow
+     * @param dead_zone - screen rect in which target can move freely
+     * @param rate - decay rate, the higher the faster camera catches up
+     * @param dt - time passed since previous call
+     */
+    pub fn smooth_follow(&mut self, target: Point<T>, dead_zone: Rect<T>, rate: T, dt: T)
+    where
+        T: Clone
+            + Zero
+            + One
+            + PartialOrd
+            + Neg<Output = T>
+            + Exp<Output = T>
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>,
+    {
+        let offset = self.follow_offset(target, dead_zone);
+        self.add_translation(offset * lerp_factor_dt(rate, dt));
+    }
+
+    /**
+     * @brief clamp_to_bounds - translate camera so that visible world area does not leave bounds.
+     *  If visible area is larger than bounds along an axis it is centered on bounds along that axis.
+     * @param bounds - world rect
+     * @param viewport - size of the viewport placed at screen origin
+     */
+    pub fn clamp_to_bounds(&mut self, bounds: Rect<T>, viewport: Size<T>)
+    where
+        T: Clone
+            + Zero
+            + One
+            + Two
+            + PartialOrd
+            + Default
+            + Mul<Output = T>
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Div<Output = T>
+            + Neg<Output = T>,
+    {
+        let Some(visible) = self.visible_world_rect(viewport) else {
+            return;
+        };
+        let shift = |visible: Range<T>, bounds: Range<T>| {
+            if visible.end.clone() - visible.start.clone()
+                > bounds.end.clone() - bounds.start.clone()
+            {
+                (bounds.start + bounds.end - visible.start - visible.end) / T::two()
+            } else if visible.start < bounds.start {
+                bounds.start - visible.start
+            } else if visible.end > bounds.end {
+                bounds.end - visible.end
+            } else {
+                T::zero()
+            }
+        };
+        let world_shift = Point::from((
+            shift(visible.x_range(), bounds.x_range()),
+            shift(visible.y_range(), bounds.y_range()),
+        ));
+        let screen_shift = self
+            .transformation()
+            .apply_affine_without_translation(&world_shift);
+        self.add_translation(Point::origin() - screen_shift);
+    }
+
+    fn follow_offset(&self, target: Point<T>, dead_zone: Rect<T>) -> Vector<T>
+    where
+        T: Clone
+            + Zero
+            + One
+            + PartialOrd
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>,
+    {
+        let screen = self.world_to_screen(&target);
+        let offset = |x: T, range: Range<T>| {
+            if x < range.start {
+                range.start - x
+            } else if x > range.end {
+                range.end - x
+            } else {
+                T::zero()
+            }
+        };
+        (
+            offset(screen.x().clone(), dead_zone.x_range()),
+            offset(screen.y().clone(), dead_zone.y_range()),
+        )
+            .into()
+    }
+
+    fn clamp_scale(&self, s: T) -> T
+    where
+        T: Clone + PartialOrd,
+    {
+        match &self.scale_limits {
+            Some(limits) if s < limits.start => limits.start.clone(),
+            Some(limits) if s > limits.end => limits.end.clone(),
+            _ => s,
+        }
+    }
 }
 
 fn concat_scale_centered<T>(
@@ -124,3 +462,160 @@ fn concat_scale_centered<T>(
     *scale_output = filter_accepts_scale(&output);
     *translation_output = filter_accepts_translation(&output);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Camera;
+    use crate::math::{Point, Positive, Rect, noneg_f64};
+    use approx::assert_abs_diff_eq;
+
+    fn assert_point_eq(a: Point<f64>, b: Point<f64>) {
+        assert_abs_diff_eq!(*a.x(), *b.x(), epsilon = 1e-9);
+        assert_abs_diff_eq!(*a.y(), *b.y(), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn screen_to_world() {
+        let mut camera = Camera::<f64>::default();
+        camera.set_translation((10., 20.).into());
+        camera.set_scale(2.);
+        let world: Point<f64> = (3., -4.).into();
+        let screen = camera.world_to_screen(&world);
+        assert_point_eq(screen, (16., 12.).into());
+        assert_point_eq(camera.screen_to_world(&screen).unwrap(), world);
+
+        let rect = camera.visible_world_rect((100., 50.).into()).unwrap();
+        assert_point_eq(rect.left_top(), (-5., -10.).into());
+        assert_point_eq(rect.right_bottom(), (45., 15.).into());
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn rotate_about() {
+        use crate::math::DeltaAngle;
+
+        let mut camera = Camera::<f64>::default();
+        camera.set_translation((10., 0.).into());
+        let pivot: Point<f64> = (20., 5.).into();
+        let world = camera.screen_to_world(&pivot).unwrap();
+        camera.rotate_about(pivot, DeltaAngle::from_degrees(90.));
+        assert_point_eq(camera.world_to_screen(&world), pivot);
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn pinch() {
+        let mut camera = Camera::<f64>::default();
+        camera.set_translation((5., 5.).into());
+        let prev: [Point<f64>; 2] = [(0., 0.).into(), (10., 0.).into()];
+        let current: [Point<f64>; 2] = [(10., 10.).into(), (10., 30.).into()];
+        let worlds = prev.map(|p| camera.screen_to_world(&p).unwrap());
+        camera.pinch(prev, current);
+        assert_abs_diff_eq!(*camera.scale().scale_x(), 2., epsilon = 1e-9);
+        for (world, screen) in worlds.into_iter().zip(current) {
+            assert_point_eq(camera.world_to_screen(&world), screen);
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn scale_limits() {
+        let mut camera = Camera::<f64>::default();
+        camera.set_scale_limits(Positive::new(0.5).unwrap(), Positive::new(4.).unwrap());
+        camera.set_scale(10.);
+        assert_eq!(*camera.scale().scale_x(), 4.);
+        camera.concat_scale_centered(0.01, Point::origin(), Point::origin());
+        assert_eq!(*camera.scale().scale_x(), 0.5);
+        camera.pinch(
+            [(0., 0.).into(), (1., 0.).into()],
+            [(0., 0.).into(), (100., 0.).into()],
+        );
+        assert_eq!(*camera.scale().scale_x(), 4.);
+    }
+
+    #[test]
+    fn swapped_scale_limits() {
+        let mut camera = Camera::<f64>::default();
+        camera.set_scale_limits(Positive::new(4.).unwrap(), Positive::new(0.5).unwrap());
+        let limits = camera.scale_limits().unwrap();
+        assert_eq!((limits.start, limits.end), (0.5, 4.));
+        camera.set_scale(0.1);
+        assert_eq!(*camera.scale().scale_x(), 0.5);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn deserialize_without_scale_limits() {
+        let mut json = serde_json::to_value(Camera::<f64>::default()).unwrap();
+        json.as_object_mut().unwrap().remove("scale_limits");
+        let camera: Camera<f64> = serde_json::from_value(json).unwrap();
+        assert!(camera.scale_limits().is_none());
+    }
+
+    #[test]
+    fn fit_rect() {
+        let mut camera = Camera::<f64>::default();
+        assert!(camera.fit_rect(
+            (10., 10., 20., 10.).into(),
+            (100., 100.).into(),
+            noneg_f64(10.),
+        ));
+        assert_point_eq(
+            camera.world_to_screen(&(10., 10.).into()),
+            (10., 30.).into(),
+        );
+        assert_point_eq(
+            camera.world_to_screen(&(30., 20.).into()),
+            (90., 70.).into(),
+        );
+
+        let mut camera = Camera::<f64>::default();
+        camera.set_translation((1., 2.).into());
+        assert!(!camera.fit_rect((0., 0., 0., 10.).into(), (100., 100.).into(), noneg_f64(0.)));
+        assert!(!camera.fit_rect((0., 0., 5., 5.).into(), (100., 40.).into(), noneg_f64(20.)));
+        assert_eq!(camera.translation().translation(), (1., 2.).into());
+    }
+
+    #[test]
+    fn follow() {
+        let mut camera = Camera::<f64>::default();
+        let dead_zone: Rect<f64> = (40., 40., 20., 20.).into();
+        camera.follow((50., 50.).into(), dead_zone);
+        assert_point_eq(
+            camera.world_to_screen(&(50., 50.).into()),
+            (50., 50.).into(),
+        );
+        camera.follow((70., 30.).into(), dead_zone);
+        assert_point_eq(
+            camera.world_to_screen(&(70., 30.).into()),
+            (60., 40.).into(),
+        );
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn smooth_follow() {
+        let dead_zone: Rect<f64> = (0., 0., 0., 0.).into();
+        let mut coarse = Camera::<f64>::default();
+        coarse.smooth_follow((10., 0.).into(), dead_zone, 2., 0.5);
+
+        let mut fine = Camera::<f64>::default();
+        for _ in 0..10 {
+            fine.smooth_follow((10., 0.).into(), dead_zone, 2., 0.05);
+        }
+        let x = *coarse.translation().translation().x();
+        assert_abs_diff_eq!(x, -10. * (1. - (-1f64).exp()), epsilon = 1e-9);
+        assert_abs_diff_eq!(x, *fine.translation().translation().x(), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn clamp_to_bounds() {
+        let mut camera = Camera::<f64>::default();
+        camera.set_scale(2.);
+        camera.set_translation((50., 0.).into());
+        camera.clamp_to_bounds((0., 0., 100., 20.).into(), (100., 100.).into());
+        let visible = camera.visible_world_rect((100., 100.).into()).unwrap();
+        assert_point_eq(visible.left_top(), (0., -15.).into());
+        assert_point_eq(visible.right_bottom(), (50., 35.).into());
+    }
+}
@@ -0,0 +1,818 @@
+use crate::math::{
+    Angle, Cos, DeltaAngle, Matrix4, One, Pi, Positive, Quaternion, RemEuclid, Sin, Size, Sq, Sqrt,
+    Two, Vector3, Zero,
+};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Camera projection. Both variants are right handed: camera looks along -z, up is +y.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "T: Serialize",
+        deserialize = "T: Deserialize<'de> + crate::math::IsPositive + core::fmt::Debug"
+    ))
+)]
+pub enum Projection<T> {
+    Perspective {
+        /// vertical field of view
+        fov_y: Angle<T>,
+        /// width / height
+        aspect: T,
+        near: Positive<T>,
+        far: Positive<T>,
+    },
+    Orthographic {
+        /// height of the visible volume in world units
+        height: Positive<T>,
+        /// width / height
+        aspect: T,
+        near: T,
+        far: T,
+    },
+}
+
+impl<T> Projection<T> {
+    pub fn perspective(
+        fov_y: Angle<T>,
+        viewport: Size<T>,
+        near: Positive<T>,
+        far: Positive<T>,
+    ) -> Self
+    where
+        T: Div<Output = T>,
+    {
+        let (w, h) = viewport.into();
+        Self::Perspective {
+            fov_y,
+            aspect: w / h,
+            near,
+            far,
+        }
+    }
+
+    pub fn orthographic(height: Positive<T>, viewport: Size<T>, near: T, far: T) -> Self
+    where
+        T: Div<Output = T>,
+    {
+        let (w, h) = viewport.into();
+        Self::Orthographic {
+            height,
+            aspect: w / h,
+            near,
+            far,
+        }
+    }
+
+    /// Update aspect ratio after viewport resize
+    pub fn set_viewport(&mut self, viewport: Size<T>)
+    where
+        T: Div<Output = T>,
+    {
+        let (w, h) = viewport.into();
+        match self {
+            Self::Perspective { aspect, .. } | Self::Orthographic { aspect, .. } => *aspect = w / h,
+        }
+    }
+
+    pub fn matrix(&self) -> Matrix4<T>
+    where
+        T: Clone
+            + Zero
+            + One
+            + Two
+            + Neg<Output = T>
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>
+            + Cos<Output = T>
+            + Sin<Output = T>,
+    {
+        match self.clone() {
+            Self::Perspective {
+                fov_y,
+                aspect,
+                near,
+                far,
+            } => Matrix4::perspective(fov_y, aspect, near.into_inner(), far.into_inner()),
+            Self::Orthographic {
+                height,
+                aspect,
+                near,
+                far,
+            } => {
+                let top = height.into_inner() / T::two();
+                let right = top.clone() * aspect;
+                Matrix4::orthographic(-right.clone(), right, -top.clone(), top, near, far)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "T: Serialize",
+        deserialize = "T: Deserialize<'de> + crate::math::IsPositive + core::fmt::Debug"
+    ))
+)]
+pub struct Camera3<T> {
+    position: Vector3<T>,
+    orientation: Quaternion<T>,
+    projection: Projection<T>,
+}
+
+impl<T> Camera3<T> {
+    /// `orientation` is a unit rotor mapping camera local axes to world axes
+    pub fn new(
+        position: Vector3<T>,
+        orientation: Quaternion<T>,
+        projection: Projection<T>,
+    ) -> Self {
+        Self {
+            position,
+            orientation,
+            projection,
+        }
+    }
+
+    pub fn position(&self) -> &Vector3<T> {
+        &self.position
+    }
+
+    pub fn set_position(&mut self, position: Vector3<T>) {
+        self.position = position
+    }
+
+    pub fn orientation(&self) -> &Quaternion<T> {
+        &self.orientation
+    }
+
+    pub fn set_orientation(&mut self, orientation: Quaternion<T>) {
+        self.orientation = orientation
+    }
+
+    pub fn projection(&self) -> &Projection<T> {
+        &self.projection
+    }
+
+    pub fn projection_mut(&mut self) -> &mut Projection<T> {
+        &mut self.projection
+    }
+
+    /// Direction the camera looks at (local -z)
+    pub fn forward(&self) -> Vector3<T>
+    where
+        T: Clone
+            + Zero
+            + One
+            + Neg<Output = T>
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>,
+    {
+        self.orientation
+            .rotate(Vector3::new(T::zero(), T::zero(), -T::one()))
+    }
+
+    /// Local +x
+    pub fn right(&self) -> Vector3<T>
+    where
+        T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+    {
+        self.orientation
+            .rotate(Vector3::new(T::one(), T::zero(), T::zero()))
+    }
+
+    /// Local +y
+    pub fn up(&self) -> Vector3<T>
+    where
+        T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+    {
+        self.orientation
+            .rotate(Vector3::new(T::zero(), T::one(), T::zero()))
+    }
+
+    /// Orient camera towards `target` keeping its local +y as close to `up` as possible.
+    /// None (orientation is left untouched) if `target` coincides with camera position
+    /// or `up` is parallel to the view direction
+    pub fn look_at(&mut self, target: Vector3<T>, up: Vector3<T>) -> Option<()>
+    where
+        T: Clone
+            + Zero
+            + One
+            + Two
+            + PartialOrd
+            + Sq<Output = T>
+            + Sqrt<Output = T>
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>,
+    {
+        let back = self.position.clone() - target;
+        if back.clone().len_sqr() == T::zero() {
+            return None;
+        }
+        let back = back.norm();
+        let right = up.cross(back.clone());
+        if right.clone().len_sqr() == T::zero() {
+            return None;
+        }
+        let right = right.norm();
+        let up = back.clone().cross(right.clone());
+        self.orientation = Quaternion::from_basis(right, up, back);
+        Some(())
+    }
+
+    /// World to camera space transformation
+    pub fn view_matrix(&self) -> Matrix4<T>
+    where
+        T: Clone
+            + Zero
+            + One
+            + Two
+            + Neg<Output = T>
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>,
+    {
+        &Matrix4::rotate(self.orientation.clone().conjugate())
+            * &Matrix4::translate(-self.position.clone())
+    }
+
+    pub fn projection_matrix(&self) -> Matrix4<T>
+    where
+        T: Clone
+            + Zero
+            + One
+            + Two
+            + Neg<Output = T>
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>
+            + Cos<Output = T>
+            + Sin<Output = T>,
+    {
+        self.projection.matrix()
+    }
+
+    /// World to clip space transformation
+    pub fn view_projection(&self) -> Matrix4<T>
+    where
+        T: Clone
+            + Zero
+            + One
+            + Two
+            + Neg<Output = T>
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>
+            + Cos<Output = T>
+            + Sin<Output = T>,
+    {
+        &self.projection_matrix() * &self.view_matrix()
+    }
+
+    /// World point to normalized device coordinates (-1..1 on every axis if visible)
+    pub fn project(&self, point: Vector3<T>) -> Vector3<T>
+    where
+        T: Clone
+            + Zero
+            + One
+            + Two
+            + Neg<Output = T>
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>
+            + Cos<Output = T>
+            + Sin<Output = T>,
+    {
+        self.view_projection().transform_point(point)
+    }
+
+    pub fn frustum(&self) -> Frustum<T>
+    where
+        T: Clone
+            + Zero
+            + One
+            + Two
+            + Neg<Output = T>
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>
+            + Sq<Output = T>
+            + Sqrt<Output = T>
+            + Cos<Output = T>
+            + Sin<Output = T>,
+    {
+        Frustum::from_matrix(&self.view_projection())
+    }
+}
+
+/// Plane `normal · p + d = 0`, normal points to the positive half-space
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Plane<T> {
+    pub normal: Vector3<T>,
+    pub d: T,
+}
+
+impl<T> Plane<T> {
+    /// Signed distance if normal is normalized
+    pub fn signed_distance(&self, p: Vector3<T>) -> T
+    where
+        T: Clone + Add<Output = T> + Mul<Output = T>,
+    {
+        self.normal.clone().dot(p) + self.d.clone()
+    }
+
+    fn from_coefficients([a, b, c, d]: [T; 4]) -> Self
+    where
+        T: Clone + Sq<Output = T> + Sqrt<Output = T> + Add<Output = T> + Div<Output = T>,
+    {
+        let normal = Vector3::new(a, b, c);
+        let len = normal.clone().len();
+        Self {
+            normal: normal / len.clone(),
+            d: d / len,
+        }
+    }
+}
+
+/// Axis aligned bounding box
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Aabb3<T> {
+    pub min: Vector3<T>,
+    pub max: Vector3<T>,
+}
+
+impl<T> Aabb3<T> {
+    pub fn new(min: Vector3<T>, max: Vector3<T>) -> Self {
+        Self { min, max }
+    }
+}
+
+/// Six inward facing planes of camera view volume
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Frustum<T> {
+    /// left, right, bottom, top, near, far
+    pub planes: [Plane<T>; 6],
+}
+
+impl<T> Frustum<T> {
+    /// Gribb–Hartmann extraction from world to clip space matrix
+    pub fn from_matrix(m: &Matrix4<T>) -> Self
+    where
+        T: Clone
+            + Sq<Output = T>
+            + Sqrt<Output = T>
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Div<Output = T>,
+    {
+        let r3 = m.row(3);
+        let plane = |row: [T; 4], sub: bool| {
+            Plane::from_coefficients(core::array::from_fn(|i| {
+                if sub {
+                    r3[i].clone() - row[i].clone()
+                } else {
+                    r3[i].clone() + row[i].clone()
+                }
+            }))
+        };
+        Self {
+            planes: [
+                plane(m.row(0), false),
+                plane(m.row(0), true),
+                plane(m.row(1), false),
+                plane(m.row(1), true),
+                plane(m.row(2), false),
+                plane(m.row(2), true),
+            ],
+        }
+    }
+
+    pub fn contains_point(&self, p: &Vector3<T>) -> bool
+    where
+        T: Clone + Zero + PartialOrd + Add<Output = T> + Mul<Output = T>,
+    {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance(p.clone()) >= T::zero())
+    }
+
+    /// Conservative test: false only if the box is certainly outside
+    pub fn intersects_aabb(&self, aabb: &Aabb3<T>) -> bool
+    where
+        T: Clone + Zero + PartialOrd + Add<Output = T> + Mul<Output = T>,
+    {
+        let pick = |n: &T, min: &T, max: &T| {
+            if *n >= T::zero() {
+                max.clone()
+            } else {
+                min.clone()
+            }
+        };
+        self.planes.iter().all(|plane| {
+            let n = &plane.normal;
+            let positive = Vector3::new(
+                pick(n.x(), aabb.min.x(), aabb.max.x()),
+                pick(n.y(), aabb.min.y(), aabb.max.y()),
+                pick(n.z(), aabb.min.z(), aabb.max.z()),
+            );
+            plane.signed_distance(positive) >= T::zero()
+        })
+    }
+}
+
+fn yaw_pitch_orientation<T>(yaw: Angle<T>, pitch: DeltaAngle<T>) -> Quaternion<T>
+where
+    T: Clone
+        + Zero
+        + One
+        + Pi
+        + Two
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + RemEuclid<Output = T>
+        + Cos<Output = T>
+        + Sin<Output = T>,
+{
+    let y_axis = Vector3::new(T::zero(), T::one(), T::zero());
+    let x_axis = Vector3::new(T::one(), T::zero(), T::zero());
+    Quaternion::from_axis_angle(y_axis, yaw)
+        * Quaternion::from_axis_angle(x_axis, Angle::from_radians(T::zero()) + pitch)
+}
+
+fn clamp_pitch<T>(pitch: DeltaAngle<T>) -> DeltaAngle<T>
+where
+    T: Pi + Two + Div<Output = T> + Neg<Output = T> + PartialOrd,
+{
+    let max = DeltaAngle::from_radians(T::pi() / T::two());
+    let min = DeltaAngle::from_radians(-(T::pi() / T::two()));
+    if pitch > max {
+        max
+    } else if pitch < min {
+        min
+    } else {
+        pitch
+    }
+}
+
+/// Camera rotating around a target point at a distance (editor style)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrbitController<T> {
+    target: Vector3<T>,
+    distance: Positive<T>,
+    yaw: Angle<T>,
+    pitch: DeltaAngle<T>,
+}
+
+impl<T> OrbitController<T> {
+    /// Positive pitch looks from above, pitch is clamped to -π/2..π/2
+    pub fn new(
+        target: Vector3<T>,
+        distance: Positive<T>,
+        yaw: Angle<T>,
+        pitch: DeltaAngle<T>,
+    ) -> Self
+    where
+        T: Pi + Two + Div<Output = T> + Neg<Output = T> + PartialOrd,
+    {
+        Self {
+            target,
+            distance,
+            yaw,
+            pitch: clamp_pitch(pitch),
+        }
+    }
+
+    pub fn target(&self) -> &Vector3<T> {
+        &self.target
+    }
+
+    pub fn distance(&self) -> &Positive<T> {
+        &self.distance
+    }
+
+    pub fn rotate(&mut self, d_yaw: DeltaAngle<T>, d_pitch: DeltaAngle<T>)
+    where
+        T: Clone + Pi + Two + Add<Output = T> + Div<Output = T> + Neg<Output = T> + PartialOrd,
+    {
+        self.yaw = self.yaw.clone() + d_yaw;
+        self.pitch = clamp_pitch(self.pitch.clone() + d_pitch);
+    }
+
+    /// Multiply distance to target, factor < 1 zooms in
+    pub fn zoom(&mut self, factor: Positive<T>)
+    where
+        T: Clone + Mul<Output = T>,
+    {
+        self.distance = self.distance.clone() * factor;
+    }
+
+    /// Move target in the camera plane by `dx` to the right and `dy` up
+    pub fn pan(&mut self, dx: T, dy: T)
+    where
+        T: Clone
+            + Zero
+            + One
+            + Pi
+            + Two
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>
+            + Neg<Output = T>
+            + RemEuclid<Output = T>
+            + Cos<Output = T>
+            + Sin<Output = T>,
+    {
+        let orientation = self.orientation();
+        let right = orientation.rotate(Vector3::new(T::one(), T::zero(), T::zero()));
+        let up = orientation.rotate(Vector3::new(T::zero(), T::one(), T::zero()));
+        self.target = self.target.clone() + right * dx + up * dy;
+    }
+
+    pub fn orientation(&self) -> Quaternion<T>
+    where
+        T: Clone
+            + Zero
+            + One
+            + Pi
+            + Two
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>
+            + Neg<Output = T>
+            + RemEuclid<Output = T>
+            + Cos<Output = T>
+            + Sin<Output = T>,
+    {
+        yaw_pitch_orientation(self.yaw.clone(), -self.pitch.clone())
+    }
+
+    pub fn apply(&self, camera: &mut Camera3<T>)
+    where
+        T: Clone
+            + Zero
+            + One
+            + Pi
+            + Two
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>
+            + Neg<Output = T>
+            + RemEuclid<Output = T>
+            + Cos<Output = T>
+            + Sin<Output = T>,
+    {
+        let orientation = self.orientation();
+        let back = orientation.rotate(Vector3::new(T::zero(), T::zero(), T::one()));
+        camera.position = self.target.clone() + back * self.distance.clone().into_inner();
+        camera.orientation = orientation;
+    }
+}
+
+/// First person camera: mouse look and movement in camera local axes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlyController<T> {
+    yaw: Angle<T>,
+    pitch: DeltaAngle<T>,
+}
+
+impl<T> FlyController<T> {
+    /// Positive pitch looks up, pitch is clamped to -π/2..π/2
+    pub fn new(yaw: Angle<T>, pitch: DeltaAngle<T>) -> Self
+    where
+        T: Pi + Two + Div<Output = T> + Neg<Output = T> + PartialOrd,
+    {
+        Self {
+            yaw,
+            pitch: clamp_pitch(pitch),
+        }
+    }
+
+    pub fn look(&mut self, d_yaw: DeltaAngle<T>, d_pitch: DeltaAngle<T>)
+    where
+        T: Clone + Pi + Two + Add<Output = T> + Div<Output = T> + Neg<Output = T> + PartialOrd,
+    {
+        self.yaw = self.yaw.clone() + d_yaw;
+        self.pitch = clamp_pitch(self.pitch.clone() + d_pitch);
+    }
+
+    pub fn orientation(&self) -> Quaternion<T>
+    where
+        T: Clone
+            + Zero
+            + One
+            + Pi
+            + Two
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>
+            + RemEuclid<Output = T>
+            + Cos<Output = T>
+            + Sin<Output = T>,
+    {
+        yaw_pitch_orientation(self.yaw.clone(), self.pitch.clone())
+    }
+
+    /**
+     * @brief apply - orient camera and move it
+     * @param movement - offset in camera local axes (x right, y up, -z forward), already scaled by speed and dt
+     */
+    pub fn apply(&self, camera: &mut Camera3<T>, movement: Vector3<T>)
+    where
+        T: Clone
+            + Zero
+            + One
+            + Pi
+            + Two
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>
+            + RemEuclid<Output = T>
+            + Cos<Output = T>
+            + Sin<Output = T>,
+    {
+        let orientation = self.orientation();
+        camera.position = camera.position.clone() + orientation.rotate(movement);
+        camera.orientation = orientation;
+    }
+}
+
+#[cfg(test)]
+#[cfg(any(feature = "std", feature = "libm"))]
+mod tests {
+    use super::{Aabb3, Camera3, FlyController, OrbitController, Projection};
+    use crate::math::{Angle, DeltaAngle, Positive, Quaternion, Vector3};
+    use approx::assert_abs_diff_eq;
+
+    fn assert_vec_eq(a: Vector3<f64>, b: Vector3<f64>) {
+        assert_abs_diff_eq!(*a.x(), *b.x(), epsilon = 1e-9);
+        assert_abs_diff_eq!(*a.y(), *b.y(), epsilon = 1e-9);
+        assert_abs_diff_eq!(*a.z(), *b.z(), epsilon = 1e-9);
+    }
+
+    fn perspective() -> Projection<f64> {
+        Projection::perspective(
+            Angle::from_degrees(90.),
+            (200., 100.).into(),
+            Positive::new(1.).unwrap(),
+            Positive::new(10.).unwrap(),
+        )
+    }
+
+    #[test]
+    fn perspective_projection() {
+        let camera = Camera3::new(
+            Vector3::new(0., 0., 5.),
+            Quaternion::identity(),
+            perspective(),
+        );
+        assert_vec_eq(
+            camera.project(Vector3::new(0., 0., 4.)),
+            Vector3::new(0., 0., -1.),
+        );
+        assert_vec_eq(
+            camera.project(Vector3::new(0., 0., -5.)),
+            Vector3::new(0., 0., 1.),
+        );
+        let p = camera.project(Vector3::new(2., 1., 4.));
+        assert_abs_diff_eq!(*p.x(), 1., epsilon = 1e-9);
+        assert_abs_diff_eq!(*p.y(), 1., epsilon = 1e-9);
+    }
+
+    #[test]
+    fn orthographic_projection() {
+        let projection =
+            Projection::orthographic(Positive::new(4.).unwrap(), (2., 1.).into(), 0., 10.);
+        let camera = Camera3::new(Vector3::new(0., 0., 0.), Quaternion::identity(), projection);
+        assert_vec_eq(
+            camera.project(Vector3::new(4., 2., -5.)),
+            Vector3::new(1., 1., 0.),
+        );
+    }
+
+    #[test]
+    fn frustum_culling() {
+        let camera = Camera3::new(
+            Vector3::new(0., 0., 0.),
+            Quaternion::identity(),
+            perspective(),
+        );
+        let frustum = camera.frustum();
+        assert!(frustum.contains_point(&Vector3::new(0., 0., -5.)));
+        assert!(!frustum.contains_point(&Vector3::new(0., 0., 5.)));
+        assert!(!frustum.contains_point(&Vector3::new(0., 0., -11.)));
+
+        let visible = Aabb3::new(Vector3::new(-1., -1., -6.), Vector3::new(1., 1., -4.));
+        let behind = Aabb3::new(Vector3::new(-1., -1., 4.), Vector3::new(1., 1., 6.));
+        let crossing = Aabb3::new(Vector3::new(-100., -1., -6.), Vector3::new(-3., 1., -4.));
+        let aside = Aabb3::new(Vector3::new(-100., -1., -3.), Vector3::new(-50., 1., -2.));
+        assert!(frustum.intersects_aabb(&visible));
+        assert!(!frustum.intersects_aabb(&behind));
+        assert!(frustum.intersects_aabb(&crossing));
+        assert!(!frustum.intersects_aabb(&aside));
+    }
+
+    #[test]
+    fn look_at() {
+        let mut camera = Camera3::new(
+            Vector3::new(3., 4., 5.),
+            Quaternion::identity(),
+            perspective(),
+        );
+        camera
+            .look_at(Vector3::new(-1., 0., 2.), Vector3::new(0., 1., 0.))
+            .unwrap();
+        assert_vec_eq(
+            camera.forward(),
+            (Vector3::new(-1., 0., 2.) - Vector3::new(3., 4., 5.)).norm(),
+        );
+        let p = camera.project(Vector3::new(-1., 0., 2.));
+        assert_abs_diff_eq!(*p.x(), 0., epsilon = 1e-9);
+        assert_abs_diff_eq!(*p.y(), 0., epsilon = 1e-9);
+        assert!(*camera.up().y() > 0.);
+
+        let forward = camera.forward();
+        assert!(
+            camera
+                .look_at(Vector3::new(3., 4., 5.), Vector3::new(0., 1., 0.))
+                .is_none()
+        );
+        assert!(
+            camera
+                .look_at(Vector3::new(3., 0., 5.), Vector3::new(0., 1., 0.))
+                .is_none()
+        );
+        assert_vec_eq(camera.forward(), forward);
+    }
+
+    #[test]
+    fn orbit() {
+        let mut camera = Camera3::new(
+            Vector3::new(0., 0., 0.),
+            Quaternion::identity(),
+            perspective(),
+        );
+        let mut orbit = OrbitController::new(
+            Vector3::new(1., 2., 3.),
+            Positive::new(5.).unwrap(),
+            Angle::from_degrees(0.),
+            DeltaAngle::from_degrees(0.),
+        );
+        orbit.apply(&mut camera);
+        assert_vec_eq(*camera.position(), Vector3::new(1., 2., 8.));
+
+        orbit.rotate(
+            DeltaAngle::from_degrees(90.),
+            DeltaAngle::from_degrees(200.),
+        );
+        orbit.apply(&mut camera);
+        assert_vec_eq(*camera.position(), Vector3::new(1., 7., 3.));
+        assert_vec_eq(camera.forward(), Vector3::new(0., -1., 0.));
+
+        orbit.zoom(Positive::new(0.5).unwrap());
+        orbit.apply(&mut camera);
+        assert_vec_eq(*camera.position(), Vector3::new(1., 4.5, 3.));
+    }
+
+    #[test]
+    fn fly() {
+        let mut camera = Camera3::new(
+            Vector3::new(0., 0., 0.),
+            Quaternion::identity(),
+            perspective(),
+        );
+        let mut fly = FlyController::new(Angle::from_degrees(0.), DeltaAngle::from_degrees(0.));
+        fly.look(DeltaAngle::from_degrees(90.), DeltaAngle::from_degrees(0.));
+        fly.apply(&mut camera, Vector3::new(0., 0., -2.));
+        assert_vec_eq(*camera.position(), Vector3::new(-2., 0., 0.));
+
+        fly.look(DeltaAngle::from_degrees(0.), DeltaAngle::from_degrees(90.));
+        fly.apply(&mut camera, Vector3::new(0., 0., -1.));
+        assert_vec_eq(*camera.position(), Vector3::new(-2., 1., 0.));
+    }
+}
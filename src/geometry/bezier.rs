@@ -0,0 +1,935 @@
+use super::{Scalar, arc_length, max, min, segment_distance_sqr, t_at_length};
+use crate::math::{Matrix, One, Point, Positive, Rect, Vector, from_u32, lerp, ratio};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Limit of recursive subdivision in flattening, measuring and intersection
+const MAX_DEPTH: u32 = 24;
+
+/// Number of uniform samples used to bracket nearest point
+const NEAREST_SAMPLES: u32 = 16;
+
+/// Operations common to all Bézier curves.
+/// Curve lies inside convex hull of its control points which is what subdivision algorithms here rely on.
+pub trait Bezier<T: Scalar>: Sized {
+    type ControlPoints: AsRef<[Point<T>]>;
+
+    fn control_points(&self) -> Self::ControlPoints;
+
+    /// Point at parameter `t` in range `0..=1`
+    fn eval(&self, t: T) -> Point<T>;
+
+    /// First derivative by `t`
+    fn derivative(&self, t: T) -> Vector<T>;
+
+    /// Two curves covering `0..t` and `t..1` of this one
+    fn split(&self, t: T) -> (Self, Self);
+
+    /// Calls `f` with parameters in `0..1` where x or y derivative turns to zero
+    fn for_each_extremum<F: FnMut(T)>(&self, f: F);
+
+    fn start(&self) -> Point<T> {
+        self.control_points().as_ref()[0]
+    }
+
+    fn end(&self) -> Point<T> {
+        let points = self.control_points();
+        let points = points.as_ref();
+        points[points.len() - 1]
+    }
+
+    /// Unit tangent at `t`. Where derivative vanishes (control point coincides with end point) chord direction is used.
+    /// Returns `None` if curve is degenerated into a point
+    fn tangent(&self, t: T) -> Option<Vector<T>> {
+        let d = self.derivative(t);
+        let d = if d.len_sqr() > T::zero() {
+            d
+        } else {
+            self.end() - self.start()
+        };
+        if d.len_sqr() > T::zero() {
+            Some(d.norm())
+        } else {
+            None
+        }
+    }
+
+    /// Part of curve between parameters `t0` and `t1`
+    fn sub_curve(&self, t0: T, t1: T) -> Self {
+        let (head, _) = self.split(t1);
+        if t1 > T::zero() {
+            head.split(t0 / t1).1
+        } else {
+            head
+        }
+    }
+
+    /// Bounding rect of control points
+    fn control_rect(&self) -> Rect<T> {
+        let points = self.control_points();
+        let points = points.as_ref();
+        points[1..]
+            .iter()
+            .fold(point_rect(points[0]), |rect, p| extend(rect, *p))
+    }
+
+    /// Tight bounding rect of curve
+    fn bounding_rect(&self) -> Rect<T> {
+        let mut rect = extend(point_rect(self.start()), self.end());
+        self.for_each_extremum(|t| rect = extend(rect, self.eval(t)));
+        rect
+    }
+
+    /// Whether every control point is within `tolerance` from chord (and so is the curve)
+    fn is_flat(&self, tolerance: T) -> bool {
+        let points = self.control_points();
+        let points = points.as_ref();
+        let (a, b) = (points[0], points[points.len() - 1]);
+        points[1..points.len() - 1]
+            .iter()
+            .all(|p| segment_distance_sqr(*p, a, b) <= tolerance.sq())
+    }
+
+    /// Approximate curve with polyline deviating from it no further than `tolerance`.
+    /// `f` receives polyline points following `start()`, the last one is `end()`
+    fn for_each_flattened<F: FnMut(Point<T>)>(&self, tolerance: Positive<T>, mut f: F) {
+        flatten(self, tolerance.into_inner(), 0, &mut f)
+    }
+
+    fn arc_length(&self, tolerance: Positive<T>) -> T {
+        self.length_at(T::one(), tolerance)
+    }
+
+    /// Arc length from start to parameter `t`
+    fn length_at(&self, t: T, tolerance: Positive<T>) -> T {
+        arc_length(|t| self.derivative(t).len(), T::zero(), t, tolerance)
+    }
+
+    /// Parameter at which arc length from start reaches `length` (arc-length parametrization).
+    /// `length` outside of curve is clamped
+    fn t_at_length(&self, length: T, tolerance: Positive<T>) -> T {
+        t_at_length(
+            |t| self.derivative(t).len(),
+            (T::zero(), T::one()),
+            length,
+            tolerance,
+        )
+    }
+
+    /// Parameter and point of curve closest to `point`
+    fn nearest(&self, point: Point<T>) -> (T, Point<T>) {
+        let distance = |t: T| (self.eval(t) - point).len_sqr();
+        let n = from_u32::<T>(NEAREST_SAMPLES);
+        let (best, _) = (1..=NEAREST_SAMPLES).map(|i| from_u32::<T>(i) / n).fold(
+            (T::zero(), distance(T::zero())),
+            |best, t| {
+                let d = distance(t);
+                if d < best.1 { (t, d) } else { best }
+            },
+        );
+
+        // ternary search around best sample
+        let step = T::one() / n;
+        let mut low = max(best - step, T::zero());
+        let mut high = min(best + step, T::one());
+        for _ in 0..2 * MAX_DEPTH {
+            let third = (high - low) / from_u32(3);
+            let (a, b) = (low + third, high - third);
+            if distance(a) < distance(b) {
+                high = b
+            } else {
+                low = a
+            }
+        }
+        let t = (low + high) / T::two();
+        (t, self.eval(t))
+    }
+
+    /// Calls `f` in ascending order with parameters where curve crosses infinite line through `a` and `b`.
+    /// Parameters are accurate up to `tolerance` measured in distance.
+    fn for_each_line_intersection<F: FnMut(T)>(
+        &self,
+        a: Point<T>,
+        b: Point<T>,
+        tolerance: Positive<T>,
+        mut f: F,
+    ) {
+        line_intersections(
+            self,
+            (T::zero(), T::one()),
+            (a, b - a),
+            tolerance.into_inner(),
+            0,
+            &mut f,
+        )
+    }
+
+    /// Calls `f` with parameters `(t_self, t_other)` of intersection points of two curves
+    fn for_each_curve_intersection<C, F>(&self, other: &C, tolerance: Positive<T>, mut f: F)
+    where
+        C: Bezier<T>,
+        F: FnMut(T, T),
+    {
+        curve_intersections(
+            self,
+            (T::zero(), T::one()),
+            other,
+            (T::zero(), T::one()),
+            tolerance.into_inner(),
+            0,
+            &mut f,
+        )
+    }
+}
+
+/// Straight segment as first degree Bézier curve
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LineSegment<T> {
+    pub from: Point<T>,
+    pub to: Point<T>,
+}
+
+impl<T> LineSegment<T> {
+    pub fn new(from: Point<T>, to: Point<T>) -> Self {
+        Self { from, to }
+    }
+
+    pub fn transformed(&self, matrix: &Matrix<T>) -> Self
+    where
+        T: Scalar,
+    {
+        Self {
+            from: matrix * &self.from,
+            to: matrix * &self.to,
+        }
+    }
+}
+
+impl<T: Scalar> Bezier<T> for LineSegment<T> {
+    type ControlPoints = [Point<T>; 2];
+
+    fn control_points(&self) -> Self::ControlPoints {
+        [self.from, self.to]
+    }
+
+    fn eval(&self, t: T) -> Point<T> {
+        lerp(self.from, self.to, t)
+    }
+
+    fn derivative(&self, _: T) -> Vector<T> {
+        self.to - self.from
+    }
+
+    fn split(&self, t: T) -> (Self, Self) {
+        let mid = self.eval(t);
+        (Self::new(self.from, mid), Self::new(mid, self.to))
+    }
+
+    fn for_each_extremum<F: FnMut(T)>(&self, _: F) {}
+}
+
+/// Quadratic Bézier curve
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct QuadBezier<T> {
+    pub from: Point<T>,
+    pub ctrl: Point<T>,
+    pub to: Point<T>,
+}
+
+impl<T> QuadBezier<T> {
+    pub fn new(from: Point<T>, ctrl: Point<T>, to: Point<T>) -> Self {
+        Self { from, ctrl, to }
+    }
+
+    /// Same curve represented by cubic Bézier (degree elevation)
+    pub fn to_cubic(&self) -> CubicBezier<T>
+    where
+        T: Scalar,
+    {
+        let third = ratio::<T>(1, 3);
+        CubicBezier {
+            from: self.from,
+            ctrl1: self.from + (self.ctrl - self.from) * (T::two() * third),
+            ctrl2: self.to + (self.ctrl - self.to) * (T::two() * third),
+            to: self.to,
+        }
+    }
+
+    /// Curve with transformed control points. Exact for affine matrices,
+    /// perspective requires `RationalQuadBezier::transformed`
+    pub fn transformed(&self, matrix: &Matrix<T>) -> Self
+    where
+        T: Scalar,
+    {
+        Self {
+            from: matrix * &self.from,
+            ctrl: matrix * &self.ctrl,
+            to: matrix * &self.to,
+        }
+    }
+}
+
+impl<T: Scalar> Bezier<T> for QuadBezier<T> {
+    type ControlPoints = [Point<T>; 3];
+
+    fn control_points(&self) -> Self::ControlPoints {
+        [self.from, self.ctrl, self.to]
+    }
+
+    fn eval(&self, t: T) -> Point<T> {
+        lerp(
+            lerp(self.from, self.ctrl, t),
+            lerp(self.ctrl, self.to, t),
+            t,
+        )
+    }
+
+    fn derivative(&self, t: T) -> Vector<T> {
+        ((self.ctrl - self.from) * (T::one() - t) + (self.to - self.ctrl) * t) * T::two()
+    }
+
+    fn split(&self, t: T) -> (Self, Self) {
+        let a = lerp(self.from, self.ctrl, t);
+        let b = lerp(self.ctrl, self.to, t);
+        let mid = lerp(a, b, t);
+        (Self::new(self.from, a, mid), Self::new(mid, b, self.to))
+    }
+
+    fn for_each_extremum<F: FnMut(T)>(&self, mut f: F) {
+        let axes = |p: Point<T>| -> (T, T) { p.into() };
+        let (from, ctrl, to) = (axes(self.from), axes(self.ctrl), axes(self.to));
+        for (p0, p1, p2) in [(from.0, ctrl.0, to.0), (from.1, ctrl.1, to.1)] {
+            let denominator = p0 - p1 * T::two() + p2;
+            if denominator != T::zero() {
+                let t = (p0 - p1) / denominator;
+                if t > T::zero() && t < T::one() {
+                    f(t)
+                }
+            }
+        }
+    }
+}
+
+/// Cubic Bézier curve
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CubicBezier<T> {
+    pub from: Point<T>,
+    pub ctrl1: Point<T>,
+    pub ctrl2: Point<T>,
+    pub to: Point<T>,
+}
+
+impl<T> CubicBezier<T> {
+    pub fn new(from: Point<T>, ctrl1: Point<T>, ctrl2: Point<T>, to: Point<T>) -> Self {
+        Self {
+            from,
+            ctrl1,
+            ctrl2,
+            to,
+        }
+    }
+
+    /// Curve with transformed control points. Exact for affine matrices only
+    pub fn transformed(&self, matrix: &Matrix<T>) -> Self
+    where
+        T: Scalar,
+    {
+        Self {
+            from: matrix * &self.from,
+            ctrl1: matrix * &self.ctrl1,
+            ctrl2: matrix * &self.ctrl2,
+            to: matrix * &self.to,
+        }
+    }
+}
+
+impl<T: Scalar> Bezier<T> for CubicBezier<T> {
+    type ControlPoints = [Point<T>; 4];
+
+    fn control_points(&self) -> Self::ControlPoints {
+        [self.from, self.ctrl1, self.ctrl2, self.to]
+    }
+
+    fn eval(&self, t: T) -> Point<T> {
+        let a = lerp(self.from, self.ctrl1, t);
+        let b = lerp(self.ctrl1, self.ctrl2, t);
+        let c = lerp(self.ctrl2, self.to, t);
+        lerp(lerp(a, b, t), lerp(b, c, t), t)
+    }
+
+    fn derivative(&self, t: T) -> Vector<T> {
+        let u = T::one() - t;
+        ((self.ctrl1 - self.from) * (u * u)
+            + (self.ctrl2 - self.ctrl1) * (T::two() * u * t)
+            + (self.to - self.ctrl2) * (t * t))
+            * from_u32::<T>(3)
+    }
+
+    fn split(&self, t: T) -> (Self, Self) {
+        let a = lerp(self.from, self.ctrl1, t);
+        let b = lerp(self.ctrl1, self.ctrl2, t);
+        let c = lerp(self.ctrl2, self.to, t);
+        let ab = lerp(a, b, t);
+        let bc = lerp(b, c, t);
+        let mid = lerp(ab, bc, t);
+        (
+            Self::new(self.from, a, ab, mid),
+            Self::new(mid, bc, c, self.to),
+        )
+    }
+
+    fn for_each_extremum<F: FnMut(T)>(&self, mut f: F) {
+        let axes = |p: Point<T>| -> (T, T) { p.into() };
+        let [p0, p1, p2, p3] = self.control_points().map(axes);
+        for [p0, p1, p2, p3] in [[p0.0, p1.0, p2.0, p3.0], [p0.1, p1.1, p2.1, p3.1]] {
+            // derivative / 3 in power basis
+            let (d0, d1, d2) = (p1 - p0, p2 - p1, p3 - p2);
+            quadratic_roots(d0 - d1 * T::two() + d2, (d1 - d0) * T::two(), d0, |t| {
+                if t > T::zero() && t < T::one() {
+                    f(t)
+                }
+            });
+        }
+    }
+}
+
+/// Rational quadratic Bézier curve (conic section).
+/// With end weights equal to one middle weight below one gives ellipse arc, one gives parabola (`QuadBezier`) and above one gives hyperbola.
+/// Unlike polynomial curves it stays exact under perspective transformation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RationalQuadBezier<T> {
+    from: Point<T>,
+    ctrl: Point<T>,
+    to: Point<T>,
+    /// weights of `from`, `ctrl` and `to`. Splitting and transformation keep them unnormalized to preserve parametrization
+    weights: [T; 3],
+}
+
+impl<T> RationalQuadBezier<T> {
+    /// Curve in standard form: end weights are one and `weight` is weight of control point
+    pub fn new(from: Point<T>, ctrl: Point<T>, to: Point<T>, weight: Positive<T>) -> Self
+    where
+        T: One,
+    {
+        Self {
+            from,
+            ctrl,
+            to,
+            weights: [T::one(), weight.into_inner(), T::one()],
+        }
+    }
+
+    pub fn from(&self) -> &Point<T> {
+        &self.from
+    }
+
+    pub fn ctrl(&self) -> &Point<T> {
+        &self.ctrl
+    }
+
+    pub fn to(&self) -> &Point<T> {
+        &self.to
+    }
+
+    pub fn weights(&self) -> &[T; 3] {
+        &self.weights
+    }
+
+    /// Weight of control point in standard form (shape of conic does not depend on other representations)
+    pub fn weight(&self) -> T
+    where
+        T: Scalar,
+    {
+        let [w0, w1, w2] = self.weights;
+        w1 / (w0 * w2).sqrt()
+    }
+
+    /// Curve with transformed control points. Exact for any projective matrix and keeps parametrization.
+    /// Returns `None` if matrix sends any control point to infinity or behind it
+    pub fn transformed(&self, matrix: &Matrix<T>) -> Option<Self>
+    where
+        T: Scalar,
+    {
+        Self::from_homogeneous(self.homogeneous().map(|p| matrix * p))
+    }
+
+    /// Control points multiplied by weights with weight as third coordinate
+    fn homogeneous(&self) -> [[T; 3]; 3]
+    where
+        T: Scalar,
+    {
+        let homogeneous = |p: Point<T>, w: T| {
+            let (x, y) = p.into();
+            [x * w, y * w, w]
+        };
+        let [w0, w1, w2] = self.weights;
+        [
+            homogeneous(self.from, w0),
+            homogeneous(self.ctrl, w1),
+            homogeneous(self.to, w2),
+        ]
+    }
+
+    fn from_homogeneous(points: [[T; 3]; 3]) -> Option<Self>
+    where
+        T: Scalar,
+    {
+        if points.iter().any(|p| p[2] <= T::zero()) {
+            return None;
+        }
+        let [from, ctrl, to] = points.map(|p| Point::from((p[0] / p[2], p[1] / p[2])));
+        Some(Self {
+            from,
+            ctrl,
+            to,
+            weights: points.map(|p| p[2]),
+        })
+    }
+
+    /// Weighted control points as vectors from origin
+    fn weighted(&self) -> [Vector<T>; 3]
+    where
+        T: Scalar,
+    {
+        self.homogeneous().map(|p| Vector::from((p[0], p[1])))
+    }
+
+    /// Numerator and denominator of curve coordinates
+    fn numerator_and_denominator(&self, t: T) -> (Vector<T>, T)
+    where
+        T: Scalar,
+    {
+        let u = T::one() - t;
+        let basis = [u * u, T::two() * u * t, t * t];
+        let [p0, p1, p2] = self.weighted();
+        let [w0, w1, w2] = self.weights;
+        (
+            p0 * basis[0] + p1 * basis[1] + p2 * basis[2],
+            w0 * basis[0] + w1 * basis[1] + w2 * basis[2],
+        )
+    }
+}
+
+impl<T: Scalar> From<QuadBezier<T>> for RationalQuadBezier<T> {
+    fn from(value: QuadBezier<T>) -> Self {
+        Self {
+            from: value.from,
+            ctrl: value.ctrl,
+            to: value.to,
+            weights: [T::one(); 3],
+        }
+    }
+}
+
+impl<T: Scalar> Bezier<T> for RationalQuadBezier<T> {
+    type ControlPoints = [Point<T>; 3];
+
+    fn control_points(&self) -> Self::ControlPoints {
+        [self.from, self.ctrl, self.to]
+    }
+
+    fn eval(&self, t: T) -> Point<T> {
+        let (numerator, denominator) = self.numerator_and_denominator(t);
+        Point::origin() + numerator / denominator
+    }
+
+    fn derivative(&self, t: T) -> Vector<T> {
+        let (numerator, denominator) = self.numerator_and_denominator(t);
+        let u = T::one() - t;
+        let [p0, p1, p2] = self.weighted();
+        let [w0, w1, w2] = self.weights;
+        let numerator_derivative = ((p1 - p0) * u + (p2 - p1) * t) * T::two();
+        let denominator_derivative = ((w1 - w0) * u + (w2 - w1) * t) * T::two();
+        (numerator_derivative * denominator - numerator * denominator_derivative)
+            / (denominator * denominator)
+    }
+
+    fn split(&self, t: T) -> (Self, Self) {
+        let lerp3 = |a: [T; 3], b: [T; 3]| [0, 1, 2].map(|i| a[i] + (b[i] - a[i]) * t);
+        let [p0, p1, p2] = self.homogeneous();
+        let (a, b) = (lerp3(p0, p1), lerp3(p1, p2));
+        let mid = lerp3(a, b);
+        // weights stay positive for `t` in `0..=1`
+        (
+            Self::from_homogeneous([p0, a, mid]).unwrap_or(*self),
+            Self::from_homogeneous([mid, b, p2]).unwrap_or(*self),
+        )
+    }
+
+    fn for_each_extremum<F: FnMut(T)>(&self, mut f: F) {
+        // power basis coefficients of quadratic in Bernstein form
+        let power = |c0: T, c1: T, c2: T| (c0, (c1 - c0) * T::two(), c0 - c1 * T::two() + c2);
+        let [w0, w1, w2] = self.weights;
+        let (b0, b1, b2) = power(w0, w1, w2);
+        let [p0, p1, p2] = self.homogeneous();
+        for axis in 0..2 {
+            let (a0, a1, a2) = power(p0[axis], p1[axis], p2[axis]);
+            // numerator of derivative of `a(t) / b(t)` is quadratic
+            quadratic_roots(
+                a2 * b1 - a1 * b2,
+                (a2 * b0 - a0 * b2) * T::two(),
+                a1 * b0 - a0 * b1,
+                |t| {
+                    if t > T::zero() && t < T::one() {
+                        f(t)
+                    }
+                },
+            );
+        }
+    }
+}
+
+fn point_rect<T: Scalar>(p: Point<T>) -> Rect<T> {
+    let (x, y) = p.into();
+    Rect::from_lrtb_unchecked(x, x, y, y)
+}
+
+fn extend<T: Scalar>(rect: Rect<T>, p: Point<T>) -> Rect<T> {
+    let (x, y) = p.into();
+    Rect::from_lrtb_unchecked(
+        min(rect.left(), x),
+        max(rect.right(), x),
+        min(rect.top(), y),
+        max(rect.bottom(), y),
+    )
+}
+
+/// Unlike `Rect::intersects` touching (and degenerate) rects overlap
+fn overlaps<T: Scalar>(a: &Rect<T>, b: &Rect<T>) -> bool {
+    a.left() <= b.right() && b.left() <= a.right() && a.top() <= b.bottom() && b.top() <= a.bottom()
+}
+
+/// Real roots of `a·t² + b·t + c`
+fn quadratic_roots<T: Scalar, F: FnMut(T)>(a: T, b: T, c: T, mut f: F) {
+    if a == T::zero() {
+        if b != T::zero() {
+            f(-c / b)
+        }
+        return;
+    }
+    let discriminant = b * b - from_u32::<T>(4) * a * c;
+    if discriminant < T::zero() {
+        return;
+    }
+    // avoid cancellation of `-b ± √d`
+    let sqrt = discriminant.sqrt();
+    let q = if b < T::zero() { sqrt - b } else { -b - sqrt } / T::two();
+    if q == T::zero() {
+        f(T::zero())
+    } else {
+        f(q / a);
+        if discriminant > T::zero() {
+            f(c / q)
+        }
+    }
+}
+
+fn flatten<T: Scalar, C: Bezier<T>, F: FnMut(Point<T>)>(
+    curve: &C,
+    tolerance: T,
+    depth: u32,
+    f: &mut F,
+) {
+    if depth >= MAX_DEPTH || curve.is_flat(tolerance) {
+        f(curve.end())
+    } else {
+        let (a, b) = curve.split(T::one() / T::two());
+        flatten(&a, tolerance, depth + 1, f);
+        flatten(&b, tolerance, depth + 1, f);
+    }
+}
+
+/// Whether `s` lies in `0..1` (or `0..=1` if `end` is end of whole curve).
+/// Half open ranges prevent reporting intersection at split point twice.
+fn in_leaf<T: Scalar>(s: T, end: T) -> bool {
+    s >= T::zero() && (s < T::one() || (s == T::one() && end == T::one()))
+}
+
+fn line_intersections<T: Scalar, C: Bezier<T>, F: FnMut(T)>(
+    curve: &C,
+    range: (T, T),
+    line: (Point<T>, Vector<T>),
+    tolerance: T,
+    depth: u32,
+    f: &mut F,
+) {
+    let side = |p: Point<T>| line.1.cross(p - line.0);
+    let points = curve.control_points();
+    let (below, above) = points.as_ref().iter().fold((false, false), |acc, p| {
+        let side = side(*p);
+        (acc.0 || side <= T::zero(), acc.1 || side >= T::zero())
+    });
+    if !(below && above) {
+        return;
+    }
+    if depth >= MAX_DEPTH || curve.is_flat(tolerance) {
+        let (d0, d1) = (side(curve.start()), side(curve.end()));
+        if d0 != d1 {
+            let s = d0 / (d0 - d1);
+            if in_leaf(s, range.1) {
+                f(range.0 + (range.1 - range.0) * s)
+            }
+        }
+    } else {
+        let mid = (range.0 + range.1) / T::two();
+        let (a, b) = curve.split(T::one() / T::two());
+        line_intersections(&a, (range.0, mid), line, tolerance, depth + 1, f);
+        line_intersections(&b, (mid, range.1), line, tolerance, depth + 1, f);
+    }
+}
+
+fn curve_intersections<T, A, B, F>(
+    a: &A,
+    a_range: (T, T),
+    b: &B,
+    b_range: (T, T),
+    tolerance: T,
+    depth: u32,
+    f: &mut F,
+) where
+    T: Scalar,
+    A: Bezier<T>,
+    B: Bezier<T>,
+    F: FnMut(T, T),
+{
+    let (a_rect, b_rect) = (a.control_rect(), b.control_rect());
+    if !overlaps(&a_rect, &b_rect) {
+        return;
+    }
+    let (a_flat, b_flat) = (a.is_flat(tolerance), b.is_flat(tolerance));
+    if depth >= 2 * MAX_DEPTH || (a_flat && b_flat) {
+        let (p, r) = (a.start(), a.end() - a.start());
+        let (q, w) = (b.start(), b.end() - b.start());
+        let denominator = r.cross(w);
+        if denominator != T::zero() {
+            let s = (q - p).cross(w) / denominator;
+            let u = (q - p).cross(r) / denominator;
+            if in_leaf(s, a_range.1) && in_leaf(u, b_range.1) {
+                f(
+                    a_range.0 + (a_range.1 - a_range.0) * s,
+                    b_range.0 + (b_range.1 - b_range.0) * u,
+                )
+            }
+        }
+        return;
+    }
+    let size = |rect: &Rect<T>| *rect.w() + *rect.h();
+    let half = T::one() / T::two();
+    if !a_flat && (b_flat || size(&a_rect) >= size(&b_rect)) {
+        let mid = (a_range.0 + a_range.1) / T::two();
+        let (a0, a1) = a.split(half);
+        curve_intersections(&a0, (a_range.0, mid), b, b_range, tolerance, depth + 1, f);
+        curve_intersections(&a1, (mid, a_range.1), b, b_range, tolerance, depth + 1, f);
+    } else {
+        let mid = (b_range.0 + b_range.1) / T::two();
+        let (b0, b1) = b.split(half);
+        curve_intersections(a, a_range, &b0, (b_range.0, mid), tolerance, depth + 1, f);
+        curve_intersections(a, a_range, &b1, (mid, b_range.1), tolerance, depth + 1, f);
+    }
+}
+
+#[cfg(test)]
+#[cfg(any(feature = "std", feature = "libm"))]
+mod tests {
+    use super::{Bezier, CubicBezier, QuadBezier, RationalQuadBezier};
+    use crate::math::{Matrix, Point, Positive};
+    use approx::assert_abs_diff_eq;
+
+    fn p(x: f64, y: f64) -> Point<f64> {
+        Point::from((x, y))
+    }
+
+    fn tolerance() -> Positive<f64> {
+        Positive::new(1e-9).unwrap()
+    }
+
+    fn assert_points_eq(a: Point<f64>, b: Point<f64>, epsilon: f64) {
+        assert_abs_diff_eq!(*a.x(), *b.x(), epsilon = epsilon);
+        assert_abs_diff_eq!(*a.y(), *b.y(), epsilon = epsilon);
+    }
+
+    fn quarter_circle() -> RationalQuadBezier<f64> {
+        RationalQuadBezier::new(
+            p(1., 0.),
+            p(1., 1.),
+            p(0., 1.),
+            Positive::new(core::f64::consts::FRAC_1_SQRT_2).unwrap(),
+        )
+    }
+
+    #[test]
+    fn eval_and_split() {
+        let quad = QuadBezier::new(p(0., 0.), p(1., 2.), p(2., 0.));
+        assert_eq!(quad.eval(0.5), p(1., 1.));
+        assert_eq!(quad.derivative(0.5), (2., 0.).into());
+        let cubic = quad.to_cubic();
+        let (head, tail) = cubic.split(0.25);
+        for t in [0., 0.1, 0.25, 0.6, 1.] {
+            assert_points_eq(cubic.eval(t), quad.eval(t), 1e-12);
+        }
+        assert_points_eq(head.eval(0.5), cubic.eval(0.125), 1e-12);
+        assert_points_eq(tail.eval(0.5), cubic.eval(0.625), 1e-12);
+        assert_points_eq(
+            cubic.sub_curve(0.25, 0.75).eval(0.5),
+            cubic.eval(0.5),
+            1e-12,
+        );
+
+        let circle = quarter_circle();
+        let (head, tail) = circle.split(0.5);
+        for t in [0., 0.3, 0.5, 1.] {
+            assert_abs_diff_eq!((head.eval(t) - Point::origin()).len(), 1., epsilon = 1e-12);
+            assert_abs_diff_eq!((tail.eval(t) - Point::origin()).len(), 1., epsilon = 1e-12);
+        }
+        assert_points_eq(head.end(), circle.eval(0.5), 1e-12);
+    }
+
+    #[test]
+    fn derivative() {
+        let cubic = CubicBezier::new(p(0., 0.), p(0., 1.), p(1., 3.), p(4., 2.));
+        let circle = quarter_circle();
+        let h = 1e-6;
+        for t in [0.1, 0.5, 0.9] {
+            let numeric = (cubic.eval(t + h) - cubic.eval(t - h)) / (2. * h);
+            assert_abs_diff_eq!(*cubic.derivative(t).x(), *numeric.x(), epsilon = 1e-6);
+            assert_abs_diff_eq!(*cubic.derivative(t).y(), *numeric.y(), epsilon = 1e-6);
+            let numeric = (circle.eval(t + h) - circle.eval(t - h)) / (2. * h);
+            assert_abs_diff_eq!(*circle.derivative(t).x(), *numeric.x(), epsilon = 1e-6);
+            assert_abs_diff_eq!(*circle.derivative(t).y(), *numeric.y(), epsilon = 1e-6);
+        }
+        let tangent = QuadBezier::new(p(0., 0.), p(0., 0.), p(2., 0.)).tangent(0.);
+        assert_eq!(tangent, Some((1., 0.).into()));
+    }
+
+    #[test]
+    fn bounding_rect() {
+        let cubic = CubicBezier::new(p(0., 0.), p(0., 1.), p(1., 1.), p(1., 0.));
+        let rect = cubic.bounding_rect();
+        assert_abs_diff_eq!(rect.left(), 0.);
+        assert_abs_diff_eq!(rect.right(), 1.);
+        assert_abs_diff_eq!(rect.top(), 0.);
+        assert_abs_diff_eq!(rect.bottom(), 0.75, epsilon = 1e-12);
+        assert_abs_diff_eq!(cubic.control_rect().bottom(), 1.);
+
+        let rect = QuadBezier::new(p(0., 0.), p(1., 2.), p(2., 0.)).bounding_rect();
+        assert_abs_diff_eq!(rect.bottom(), 1.);
+
+        // half circle from (1, 0) to (-1, 0) through (0, 1)
+        let half = RationalQuadBezier::new(
+            p(1., 0.),
+            p(0., 1.),
+            p(-1., 0.),
+            Positive::new(1e-9).unwrap(),
+        );
+        let rect = half.bounding_rect();
+        assert_abs_diff_eq!(rect.bottom(), 1e-9, epsilon = 1e-12);
+        let rect = quarter_circle().split(0.5).1.bounding_rect();
+        assert_abs_diff_eq!(rect.left(), 0., epsilon = 1e-12);
+        assert_abs_diff_eq!(rect.bottom(), 1., epsilon = 1e-12);
+    }
+
+    #[test]
+    fn arc_length() {
+        let line = CubicBezier::new(p(0., 0.), p(0., 0.), p(3., 0.), p(3., 0.));
+        assert_abs_diff_eq!(line.arc_length(tolerance()), 3., epsilon = 1e-9);
+        assert_abs_diff_eq!(line.t_at_length(1.5, tolerance()), 0.5, epsilon = 1e-9);
+        let t = line.t_at_length(0.5, tolerance());
+        assert_abs_diff_eq!(*line.eval(t).x(), 0.5, epsilon = 1e-9);
+
+        let circle = quarter_circle();
+        let quarter = core::f64::consts::FRAC_PI_2;
+        assert_abs_diff_eq!(circle.arc_length(tolerance()), quarter, epsilon = 1e-9);
+        let t = circle.t_at_length(quarter / 3., tolerance());
+        assert_points_eq(circle.eval(t), p(3_f64.sqrt() / 2., 0.5), 1e-9);
+    }
+
+    #[test]
+    fn nearest() {
+        let quad = QuadBezier::new(p(0., 0.), p(1., 2.), p(2., 0.));
+        let (t, point) = quad.nearest(p(1., 3.));
+        assert_abs_diff_eq!(t, 0.5, epsilon = 1e-6);
+        assert_points_eq(point, p(1., 1.), 1e-6);
+        let (t, _) = quad.nearest(p(-1., -1.));
+        assert_abs_diff_eq!(t, 0., epsilon = 1e-6);
+    }
+
+    #[test]
+    fn intersections() {
+        let s_curve = CubicBezier::new(p(0., -1.), p(1., 2.), p(2., -2.), p(3., 1.));
+        let mut hits = [0.; 3];
+        let mut count = 0;
+        s_curve.for_each_line_intersection(p(0., 0.), p(1., 0.), tolerance(), |t| {
+            hits[count] = t;
+            count += 1;
+        });
+        assert_eq!(count, 3);
+        assert!(hits[0] < hits[1] && hits[1] < hits[2]);
+        for t in hits {
+            assert_abs_diff_eq!(*s_curve.eval(t).y(), 0., epsilon = 1e-6);
+        }
+
+        let a = QuadBezier::new(p(0., 0.), p(1., 2.), p(2., 0.));
+        let b = QuadBezier::new(p(0., 1.), p(1., -1.), p(2., 1.));
+        let mut count = 0;
+        a.for_each_curve_intersection(&b, tolerance(), |ta, tb| {
+            assert_points_eq(a.eval(ta), b.eval(tb), 1e-6);
+            assert_abs_diff_eq!(*a.eval(ta).y(), 0.5, epsilon = 1e-6);
+            count += 1;
+        });
+        assert_eq!(count, 2);
+
+        let circle = quarter_circle();
+        let mut count = 0;
+        circle.for_each_curve_intersection(&s_curve, tolerance(), |ta, tb| {
+            assert_points_eq(circle.eval(ta), s_curve.eval(tb), 1e-6);
+            count += 1;
+        });
+        assert_eq!(count, 1);
+
+        let far = QuadBezier::new(p(5., 5.), p(6., 7.), p(7., 5.));
+        let mut count = 0;
+        circle.for_each_curve_intersection(&far, tolerance(), |_, _| count += 1);
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn flatten() {
+        let cubic = CubicBezier::new(p(0., 0.), p(0., 1.), p(1., 3.), p(4., 2.));
+        let mut count = 0;
+        let mut last = cubic.start();
+        cubic.for_each_flattened(Positive::new(1e-3).unwrap(), |point| {
+            let mid = Point::from(((*last.x() + point.x()) / 2., (*last.y() + point.y()) / 2.));
+            let (_, nearest) = cubic.nearest(mid);
+            assert!(mid.distance(nearest) <= 1e-3);
+            last = point;
+            count += 1;
+        });
+        assert_eq!(last, cubic.to);
+        let mut coarse = 0;
+        cubic.for_each_flattened(Positive::new(1e-1).unwrap(), |_| coarse += 1);
+        assert!(coarse < count);
+    }
+
+    #[test]
+    fn transform() {
+        let quad = QuadBezier::new(p(0., 0.), p(1., 2.), p(2., 0.));
+        let m = Matrix::translate((1., 1.).into());
+        assert_eq!(quad.transformed(&m).eval(0.5), p(2., 2.));
+
+        let m = Matrix::homography_from_quads(
+            [p(0., 0.), p(1., 0.), p(1., 1.), p(0., 1.)],
+            [p(0., 0.), p(2., 0.), p(1.5, 1.), p(0.5, 1.)],
+        )
+        .unwrap();
+        let circle = quarter_circle();
+        let projected = circle.transformed(&m).unwrap();
+        for t in [0., 0.2, 0.5, 0.8, 1.] {
+            assert_points_eq(projected.eval(t), &m * &circle.eval(t), 1e-12);
+        }
+    }
+}
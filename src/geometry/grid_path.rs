@@ -0,0 +1,681 @@
+use super::SupercoverLine;
+use crate::math::{Point, Size, Vector};
+use alloc::{collections::BinaryHeap, vec::Vec};
+use core::cmp::Reverse;
+
+/// Cost of horizontal or vertical step. Costs are integers scaled so that diagonal step is `DIAGONAL_COST` ≈ `STRAIGHT_COST`·√2
+pub const STRAIGHT_COST: u64 = 1000;
+
+pub const DIAGONAL_COST: u64 = 1414;
+
+/// Moves allowed between cells
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    Four,
+    /// Diagonal moves are allowed only if both cells they pass by are passable (no corner cutting)
+    Eight,
+}
+
+/// Estimate of remaining cost for A*
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Heuristic {
+    /// Exact for four-connected grid, overestimates on eight-connected one (faster, not always shortest)
+    Manhattan,
+    /// Exact for eight-connected grid without obstacles
+    Octile,
+    Euclidean,
+}
+
+impl Heuristic {
+    pub fn estimate(self, from: Point<i32>, to: Point<i32>) -> u64 {
+        let offset = to - from;
+        let (dx, dy) = (offset.x().unsigned_abs(), offset.y().unsigned_abs());
+        match self {
+            Heuristic::Manhattan => (u64::from(dx) + u64::from(dy)) * STRAIGHT_COST,
+            Heuristic::Octile => octile(offset),
+            Heuristic::Euclidean => {
+                let (dx, dy) = (u128::from(dx), u128::from(dy));
+                let euclidean = ((dx * dx + dy * dy) * u128::from(STRAIGHT_COST).pow(2)).isqrt();
+                // rounding of diagonal cost would make exact distance overestimate long diagonals
+                (euclidean as u64).min(octile(offset))
+            }
+        }
+    }
+}
+
+/// Estimate of remaining cost from cell to goal, in units of `STRAIGHT_COST`.
+/// Implemented for built-in `Heuristic` and any `Fn(from, to) -> u64`
+pub trait GridHeuristic {
+    fn estimate(&self, from: Point<i32>, to: Point<i32>) -> u64;
+}
+
+impl GridHeuristic for Heuristic {
+    fn estimate(&self, from: Point<i32>, to: Point<i32>) -> u64 {
+        Heuristic::estimate(*self, from, to)
+    }
+}
+
+impl<F: Fn(Point<i32>, Point<i32>) -> u64> GridHeuristic for F {
+    fn estimate(&self, from: Point<i32>, to: Point<i32>) -> u64 {
+        self(from, to)
+    }
+}
+
+fn octile(offset: Vector<i32>) -> u64 {
+    let (dx, dy) = (
+        u64::from(offset.x().unsigned_abs()),
+        u64::from(offset.y().unsigned_abs()),
+    );
+    let (long, short) = (dx.max(dy), dx.min(dy));
+    (long - short) * STRAIGHT_COST + short * DIAGONAL_COST
+}
+
+/// Cost of step between neighbour cells
+fn step_cost(from: Point<i32>, to: Point<i32>) -> u64 {
+    let offset = to - from;
+    if *offset.x() != 0 && *offset.y() != 0 {
+        DIAGONAL_COST
+    } else {
+        STRAIGHT_COST
+    }
+}
+
+/// Sum of step costs along path of neighbour cells
+pub fn grid_path_cost(path: &[Point<i32>]) -> u64 {
+    path.windows(2)
+        .map(|pair| step_cost(pair[0], pair[1]))
+        .sum()
+}
+
+/// Grid of cells `0..size` with passability given by callback
+#[derive(Debug, Clone)]
+pub struct GridGraph<F> {
+    size: Size<u32>,
+    connectivity: Connectivity,
+    passable: F,
+}
+
+/// Costs to reach nearest goal and next step towards it for every cell (Dijkstra map)
+#[derive(Debug, Clone)]
+pub struct FlowField {
+    size: Size<u32>,
+    costs: Vec<u64>,
+    next: Vec<usize>,
+}
+
+const NONE: usize = usize::MAX;
+
+impl<F: Fn(Point<i32>) -> bool> GridGraph<F> {
+    pub fn new(size: Size<u32>, connectivity: Connectivity, passable: F) -> Self {
+        Self {
+            size,
+            connectivity,
+            passable,
+        }
+    }
+
+    pub fn size(&self) -> Size<u32> {
+        self.size
+    }
+
+    pub fn connectivity(&self) -> Connectivity {
+        self.connectivity
+    }
+
+    /// Whether cell is inside grid and passable
+    pub fn is_passable(&self, cell: Point<i32>) -> bool {
+        index(self.size, cell).is_some() && (self.passable)(cell)
+    }
+
+    fn passable_at(&self, x: i32, y: i32) -> bool {
+        self.is_passable(Point::from((x, y)))
+    }
+
+    fn for_each_neighbour<G: FnMut(Point<i32>, u64)>(&self, cell: Point<i32>, mut f: G) {
+        let (x, y) = cell.into();
+        for (dx, dy) in [(1, 0), (-1, 0), (0, 1), (0, -1)] {
+            if self.passable_at(x + dx, y + dy) {
+                f(Point::from((x + dx, y + dy)), STRAIGHT_COST)
+            }
+        }
+        if self.connectivity == Connectivity::Eight {
+            for (dx, dy) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+                if self.passable_at(x + dx, y + dy)
+                    && self.passable_at(x + dx, y)
+                    && self.passable_at(x, y + dy)
+                {
+                    f(Point::from((x + dx, y + dy)), DIAGONAL_COST)
+                }
+            }
+        }
+    }
+
+    /// Cheapest path from `start` to `goal` including both, `None` if goal is unreachable.
+    /// Path is optimal if heuristic never overestimates
+    pub fn a_star<H: GridHeuristic>(
+        &self,
+        start: Point<i32>,
+        goal: Point<i32>,
+        heuristic: H,
+    ) -> Option<Vec<Point<i32>>> {
+        if !self.is_passable(start) || !self.is_passable(goal) {
+            return None;
+        }
+        let goal_index = index(self.size, goal)?;
+        let count = cell_count(self.size);
+        let mut costs = alloc::vec![u64::MAX; count];
+        let mut parents = alloc::vec![NONE; count];
+        let start_index = index(self.size, start)?;
+        costs[start_index] = 0;
+        let mut open =
+            BinaryHeap::from([Reverse((heuristic.estimate(start, goal), 0, start_index))]);
+        while let Some(Reverse((_, cost, current))) = open.pop() {
+            if current == goal_index {
+                return Some(trace(self.size, &parents, current));
+            }
+            // skip entries superseded by cheaper path
+            if cost > costs[current] {
+                continue;
+            }
+            self.for_each_neighbour(point(self.size, current), |neighbour, step| {
+                let cost = cost + step;
+                let Some(i) = index(self.size, neighbour) else {
+                    return;
+                };
+                if cost < costs[i] {
+                    costs[i] = cost;
+                    parents[i] = current;
+                    open.push(Reverse((
+                        cost + heuristic.estimate(neighbour, goal),
+                        cost,
+                        i,
+                    )));
+                }
+            });
+        }
+        None
+    }
+
+    /// Dijkstra flow field towards nearest of `goals`, impassable goals are ignored
+    pub fn flow_field(&self, goals: &[Point<i32>]) -> FlowField {
+        let count = cell_count(self.size);
+        let mut costs = alloc::vec![u64::MAX; count];
+        let mut next = alloc::vec![NONE; count];
+        let mut open = BinaryHeap::new();
+        for goal in goals {
+            if !self.is_passable(*goal) {
+                continue;
+            }
+            if let Some(i) = index(self.size, *goal) {
+                costs[i] = 0;
+                open.push(Reverse((0, i)));
+            }
+        }
+        while let Some(Reverse((cost, current))) = open.pop() {
+            if cost > costs[current] {
+                continue;
+            }
+            // moves are symmetric so searching from goals gives costs towards them
+            self.for_each_neighbour(point(self.size, current), |neighbour, step| {
+                let cost = cost + step;
+                let Some(i) = index(self.size, neighbour) else {
+                    return;
+                };
+                if cost < costs[i] {
+                    costs[i] = cost;
+                    next[i] = current;
+                    open.push(Reverse((cost, i)));
+                }
+            });
+        }
+        FlowField {
+            size: self.size,
+            costs,
+            next,
+        }
+    }
+
+    /// Jump point search: same result as A* with octile heuristic on eight-connected grid without corner cutting,
+    /// but expands only cells where path may turn. Ignores connectivity of graph
+    pub fn jump_point_search(
+        &self,
+        start: Point<i32>,
+        goal: Point<i32>,
+    ) -> Option<Vec<Point<i32>>> {
+        if !self.is_passable(start) || !self.is_passable(goal) {
+            return None;
+        }
+        let goal_index = index(self.size, goal)?;
+        let count = cell_count(self.size);
+        let mut costs = alloc::vec![u64::MAX; count];
+        let mut parents = alloc::vec![NONE; count];
+        let start_index = index(self.size, start)?;
+        costs[start_index] = 0;
+        let mut open = BinaryHeap::from([Reverse((octile(goal - start), 0, start_index))]);
+        while let Some(Reverse((_, cost, current))) = open.pop() {
+            let cell = point(self.size, current);
+            if current == goal_index {
+                // fill straight and diagonal runs between jump points
+                let jump_points = trace(self.size, &parents, current);
+                let mut path = alloc::vec![start];
+                for pair in jump_points.windows(2) {
+                    let offset = pair[1] - pair[0];
+                    let step = Vector::from((offset.x().signum(), offset.y().signum()));
+                    let mut cell = pair[0];
+                    while cell != pair[1] {
+                        cell = cell + step;
+                        path.push(cell)
+                    }
+                }
+                return Some(path);
+            }
+            if cost > costs[current] {
+                continue;
+            }
+            let parent = (parents[current] != NONE).then(|| point(self.size, parents[current]));
+            self.for_each_pruned_direction(cell, parent, |direction| {
+                let Some(jump_point) = self.jump(cell, direction, goal) else {
+                    return;
+                };
+                let Some(i) = index(self.size, jump_point) else {
+                    return;
+                };
+                let cost = cost + octile(jump_point - cell);
+                if cost < costs[i] {
+                    costs[i] = cost;
+                    parents[i] = current;
+                    open.push(Reverse((cost + octile(goal - jump_point), cost, i)));
+                }
+            });
+        }
+        None
+    }
+
+    /// Directions worth exploring from `cell` reached from `parent`: natural and forced neighbours
+    fn for_each_pruned_direction<G: FnMut(Vector<i32>)>(
+        &self,
+        cell: Point<i32>,
+        parent: Option<Point<i32>>,
+        mut f: G,
+    ) {
+        let (x, y) = cell.into();
+        let walkable = |dx: i32, dy: i32| self.passable_at(x + dx, y + dy);
+        let mut direction = |dx: i32, dy: i32| f(Vector::from((dx, dy)));
+        let Some(parent) = parent else {
+            for (dx, dy) in [(1, 0), (-1, 0), (0, 1), (0, -1)] {
+                if walkable(dx, dy) {
+                    direction(dx, dy)
+                }
+            }
+            for (dx, dy) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+                if walkable(dx, dy) && walkable(dx, 0) && walkable(0, dy) {
+                    direction(dx, dy)
+                }
+            }
+            return;
+        };
+        let offset = cell - parent;
+        let (dx, dy) = (offset.x().signum(), offset.y().signum());
+        if dx != 0 && dy != 0 {
+            let (horizontal, vertical) = (walkable(dx, 0), walkable(0, dy));
+            if vertical {
+                direction(0, dy)
+            }
+            if horizontal {
+                direction(dx, 0)
+            }
+            if horizontal && vertical && walkable(dx, dy) {
+                direction(dx, dy)
+            }
+        } else {
+            // sides perpendicular to straight move
+            let (side_x, side_y) = (dy.abs(), dx.abs());
+            let forward = walkable(dx, dy);
+            for sign in [1, -1] {
+                let side = walkable(side_x * sign, side_y * sign);
+                if side {
+                    direction(side_x * sign, side_y * sign);
+                    if forward && walkable(dx + side_x * sign, dy + side_y * sign) {
+                        direction(dx + side_x * sign, dy + side_y * sign)
+                    }
+                }
+            }
+            if forward {
+                direction(dx, dy)
+            }
+        }
+    }
+
+    /// First jump point reached moving from `cell` in `direction`
+    fn jump(
+        &self,
+        cell: Point<i32>,
+        direction: Vector<i32>,
+        goal: Point<i32>,
+    ) -> Option<Point<i32>> {
+        let (dx, dy) = direction.into();
+        let mut cell = cell;
+        loop {
+            let (x, y) = cell.into();
+            // diagonal moves never cut corners
+            if dx != 0 && dy != 0 && !(self.passable_at(x + dx, y) && self.passable_at(x, y + dy)) {
+                return None;
+            }
+            cell = cell + direction;
+            let (x, y) = cell.into();
+            if !self.is_passable(cell) {
+                return None;
+            }
+            if cell == goal {
+                return Some(cell);
+            }
+            if dx != 0 && dy != 0 {
+                if self.jump(cell, Vector::from((dx, 0)), goal).is_some()
+                    || self.jump(cell, Vector::from((0, dy)), goal).is_some()
+                {
+                    return Some(cell);
+                }
+            } else if dx != 0 {
+                if (self.passable_at(x, y - 1) && !self.passable_at(x - dx, y - 1))
+                    || (self.passable_at(x, y + 1) && !self.passable_at(x - dx, y + 1))
+                {
+                    return Some(cell);
+                }
+            } else if (self.passable_at(x - 1, y) && !self.passable_at(x - 1, y - dy))
+                || (self.passable_at(x + 1, y) && !self.passable_at(x + 1, y - dy))
+            {
+                return Some(cell);
+            }
+        }
+    }
+
+    /// Whether every cell touched by segment between cell centers is passable
+    pub fn has_line_of_sight(&self, from: Point<i32>, to: Point<i32>) -> bool {
+        SupercoverLine::new(from, to).all(|cell| self.is_passable(cell))
+    }
+
+    /// Path with cells removed wherever straight line of sight allows skipping them (string pulling).
+    /// Consecutive cells of result are no longer neighbours
+    pub fn smooth_path(&self, path: &[Point<i32>]) -> Vec<Point<i32>> {
+        let Some(first) = path.first() else {
+            return Vec::new();
+        };
+        let mut result = alloc::vec![*first];
+        let mut anchor = 0;
+        while anchor + 1 < path.len() {
+            let farthest = (anchor + 1..path.len())
+                .rev()
+                .find(|&i| self.has_line_of_sight(path[anchor], path[i]))
+                .unwrap_or(anchor + 1);
+            result.push(path[farthest]);
+            anchor = farthest;
+        }
+        result
+    }
+}
+
+impl FlowField {
+    /// Cost to reach nearest goal, `None` if it can not be reached
+    pub fn cost(&self, cell: Point<i32>) -> Option<u64> {
+        index(self.size, cell)
+            .map(|i| self.costs[i])
+            .filter(|cost| *cost != u64::MAX)
+    }
+
+    /// Next cell on cheapest path towards goal, `None` at goal and in unreachable cells
+    pub fn next(&self, cell: Point<i32>) -> Option<Point<i32>> {
+        index(self.size, cell)
+            .map(|i| self.next[i])
+            .filter(|next| *next != NONE)
+            .map(|next| point(self.size, next))
+    }
+
+    /// Step towards goal
+    pub fn direction(&self, cell: Point<i32>) -> Option<Vector<i32>> {
+        self.next(cell).map(|next| next - cell)
+    }
+
+    /// Cells from `start` to goal by following field, `None` if goal is unreachable
+    pub fn path(&self, start: Point<i32>) -> Option<Vec<Point<i32>>> {
+        self.cost(start)?;
+        let mut path = alloc::vec![start];
+        let mut cell = start;
+        while let Some(next) = self.next(cell) {
+            path.push(next);
+            cell = next;
+        }
+        Some(path)
+    }
+}
+
+fn cell_count(size: Size<u32>) -> usize {
+    let (w, h) = size.into();
+    w as usize * h as usize
+}
+
+fn index(size: Size<u32>, cell: Point<i32>) -> Option<usize> {
+    let (w, h) = size.into();
+    let (x, y) = (
+        u32::try_from(*cell.x()).ok()?,
+        u32::try_from(*cell.y()).ok()?,
+    );
+    (x < w && y < h).then(|| y as usize * w as usize + x as usize)
+}
+
+fn point(size: Size<u32>, index: usize) -> Point<i32> {
+    let (w, _) = size.into();
+    let w = w as usize;
+    Point::from(((index % w) as i32, (index / w) as i32))
+}
+
+/// Cells from root of `parents` tree to `last`
+fn trace(size: Size<u32>, parents: &[usize], last: usize) -> Vec<Point<i32>> {
+    let mut path = alloc::vec![point(size, last)];
+    let mut current = last;
+    while parents[current] != NONE {
+        current = parents[current];
+        path.push(point(size, current));
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Connectivity, DIAGONAL_COST, GridGraph, Heuristic, STRAIGHT_COST, grid_path_cost};
+    use crate::math::{Point, Size};
+
+    const MAZE: [&str; 7] = [
+        "..........",
+        ".#######..",
+        ".......#..",
+        "######.#.#",
+        "......#...",
+        ".####...#.",
+        "......#...",
+    ];
+
+    fn passable(cell: Point<i32>) -> bool {
+        MAZE[*cell.y() as usize].as_bytes()[*cell.x() as usize] == b'.'
+    }
+
+    fn size() -> Size<u32> {
+        Size::from((10, 7))
+    }
+
+    fn is_connected(path: &[Point<i32>], graph: &GridGraph<fn(Point<i32>) -> bool>) -> bool {
+        path.iter().all(|cell| graph.is_passable(*cell))
+            && path.windows(2).all(|pair| {
+                let offset = pair[1] - pair[0];
+                offset.x().abs() <= 1 && offset.y().abs() <= 1 && offset.manhattan_len() > 0
+            })
+    }
+
+    #[test]
+    fn a_star_heuristics_agree() {
+        let graph: GridGraph<fn(Point<i32>) -> bool> =
+            GridGraph::new(size(), Connectivity::Eight, passable);
+        let (start, goal) = (Point::from((0, 2)), Point::from((0, 6)));
+        let octile = graph.a_star(start, goal, Heuristic::Octile).unwrap();
+        let euclidean = graph.a_star(start, goal, Heuristic::Euclidean).unwrap();
+        assert!(is_connected(&octile, &graph));
+        assert_eq!(octile[0], start);
+        assert_eq!(*octile.last().unwrap(), goal);
+        assert_eq!(grid_path_cost(&octile), grid_path_cost(&euclidean));
+
+        let four: GridGraph<fn(Point<i32>) -> bool> =
+            GridGraph::new(size(), Connectivity::Four, passable);
+        let path = four.a_star(start, goal, Heuristic::Manhattan).unwrap();
+        assert!(is_connected(&path, &four));
+        assert!(grid_path_cost(&path) % STRAIGHT_COST == 0);
+        assert_eq!(grid_path_cost(&path), grid_path_cost(&octile));
+
+        let open: GridGraph<fn(Point<i32>) -> bool> =
+            GridGraph::new(Size::from((5, 5)), Connectivity::Four, |_| true);
+        let path = open
+            .a_star((0, 0).into(), (3, 2).into(), Heuristic::Manhattan)
+            .unwrap();
+        assert_eq!(grid_path_cost(&path), 5 * STRAIGHT_COST);
+        let open = GridGraph {
+            connectivity: Connectivity::Eight,
+            ..open
+        };
+        let path = open
+            .a_star((0, 0).into(), (3, 2).into(), Heuristic::Euclidean)
+            .unwrap();
+        assert_eq!(grid_path_cost(&path), STRAIGHT_COST + 2 * DIAGONAL_COST);
+        let dijkstra = open.a_star((0, 0).into(), (3, 2).into(), |_, _| 0).unwrap();
+        assert_eq!(grid_path_cost(&dijkstra), grid_path_cost(&path));
+
+        assert!(
+            graph
+                .a_star(start, (1, 1).into(), Heuristic::Octile)
+                .is_none()
+        );
+        assert!(
+            graph
+                .a_star(start, (10, 0).into(), Heuristic::Octile)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn large_costs() {
+        // costs across long distances exceed u32
+        let (from, to) = (Point::from((0, 0)), Point::from((i32::MAX, i32::MAX / 2)));
+        let long = u64::from(i32::MAX.unsigned_abs());
+        let short = long / 2;
+        assert_eq!(
+            Heuristic::Manhattan.estimate(from, to),
+            (long + short) * STRAIGHT_COST
+        );
+        let octile = Heuristic::Octile.estimate(from, to);
+        assert_eq!(
+            octile,
+            (long - short) * STRAIGHT_COST + short * DIAGONAL_COST
+        );
+        assert!(Heuristic::Euclidean.estimate(from, to) > long * STRAIGHT_COST);
+        assert!(Heuristic::Euclidean.estimate(from, to) <= octile);
+    }
+
+    #[test]
+    fn flow_field_matches_a_star() {
+        let graph: GridGraph<fn(Point<i32>) -> bool> =
+            GridGraph::new(size(), Connectivity::Eight, passable);
+        let goal = Point::from((9, 6));
+        let field = graph.flow_field(&[goal]);
+        assert_eq!(field.cost(goal), Some(0));
+        assert_eq!(field.next(goal), None);
+        assert_eq!(field.cost((1, 1).into()), None);
+        for start in [(0, 0), (0, 4), (3, 2), (9, 0)] {
+            let start = Point::from(start);
+            let path = field.path(start).unwrap();
+            assert!(is_connected(&path, &graph));
+            let a_star = graph.a_star(start, goal, Heuristic::Octile).unwrap();
+            assert_eq!(grid_path_cost(&path), grid_path_cost(&a_star));
+            assert_eq!(field.cost(start), Some(grid_path_cost(&path)));
+        }
+        assert_eq!(field.direction((8, 6).into()), Some((1, 0).into()));
+    }
+
+    #[test]
+    fn jump_point_search_is_optimal() {
+        let graph: GridGraph<fn(Point<i32>) -> bool> =
+            GridGraph::new(size(), Connectivity::Eight, passable);
+        for (start, goal) in [
+            ((0, 2), (0, 6)),
+            ((0, 0), (9, 6)),
+            ((9, 0), (0, 4)),
+            ((5, 6), (5, 6)),
+        ] {
+            let (start, goal) = (Point::from(start), Point::from(goal));
+            let jps = graph.jump_point_search(start, goal).unwrap();
+            let a_star = graph.a_star(start, goal, Heuristic::Octile).unwrap();
+            assert!(is_connected(&jps, &graph) || jps.len() == 1);
+            assert_eq!((jps[0], *jps.last().unwrap()), (start, goal));
+            assert_eq!(grid_path_cost(&jps), grid_path_cost(&a_star));
+        }
+        assert!(
+            graph
+                .jump_point_search((0, 0).into(), (1, 1).into())
+                .is_none()
+        );
+
+        let open: GridGraph<fn(Point<i32>) -> bool> =
+            GridGraph::new(Size::from((20, 20)), Connectivity::Eight, |_| true);
+        let path = open
+            .jump_point_search((0, 0).into(), (19, 5).into())
+            .unwrap();
+        assert_eq!(
+            grid_path_cost(&path),
+            14 * STRAIGHT_COST + 5 * DIAGONAL_COST
+        );
+
+        // scattered obstacles from hash of coordinates
+        let scattered: GridGraph<fn(Point<i32>) -> bool> =
+            GridGraph::new(Size::from((24, 24)), Connectivity::Eight, |cell| {
+                let hash = (*cell.x() as u32).wrapping_mul(73_856_093)
+                    ^ (*cell.y() as u32).wrapping_mul(19_349_663);
+                hash.wrapping_mul(2_654_435_761) >> 29 > 1
+            });
+        let mut found = 0;
+        for i in 0..40 {
+            let start = Point::from(((i * 7) % 24, (i * 5) % 24));
+            let goal = Point::from(((i * 11 + 13) % 24, (i * 3 + 17) % 24));
+            let jps = scattered.jump_point_search(start, goal);
+            let a_star = scattered.a_star(start, goal, Heuristic::Octile);
+            assert_eq!(jps.is_some(), a_star.is_some());
+            if let (Some(jps), Some(a_star)) = (jps, a_star) {
+                assert!(is_connected(&jps, &scattered));
+                assert_eq!(grid_path_cost(&jps), grid_path_cost(&a_star));
+                found += 1;
+            }
+        }
+        assert!(found > 20);
+    }
+
+    #[test]
+    fn smoothing() {
+        let graph: GridGraph<fn(Point<i32>) -> bool> =
+            GridGraph::new(size(), Connectivity::Eight, passable);
+        assert!(graph.has_line_of_sight((0, 0).into(), (9, 0).into()));
+        assert!(!graph.has_line_of_sight((0, 2).into(), (0, 4).into()));
+        // squeezing between diagonal walls is not line of sight
+        assert!(!graph.has_line_of_sight((5, 4).into(), (7, 2).into()));
+
+        let path = graph
+            .a_star((0, 2).into(), (9, 6).into(), Heuristic::Octile)
+            .unwrap();
+        let smooth = graph.smooth_path(&path);
+        assert!(smooth.len() < path.len());
+        assert_eq!(
+            (smooth[0], *smooth.last().unwrap()),
+            (path[0], *path.last().unwrap())
+        );
+        assert!(
+            smooth
+                .windows(2)
+                .all(|pair| graph.has_line_of_sight(pair[0], pair[1]))
+        );
+    }
+}
@@ -0,0 +1,637 @@
+use super::Scalar;
+use crate::math::{Point, Rect, Size, from_u32, ratio};
+use core::ops::{Add, Mul, Neg, Sub};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Hex in axial coordinates, third cube coordinate is `s = -q - r`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Hex {
+    q: i32,
+    r: i32,
+}
+
+/// Hex in cube coordinates, `q + r + s == 0`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CubeHex {
+    q: i32,
+    r: i32,
+    s: i32,
+}
+
+/// Hex in offset coordinates of rectangular map, meaning depends on `OffsetKind`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OffsetHex {
+    col: i32,
+    row: i32,
+}
+
+/// Which rows or columns of offset map are shoved by half hex
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OffsetKind {
+    /// Pointy hexes, odd rows shoved right
+    OddRows,
+    /// Pointy hexes, even rows shoved right
+    EvenRows,
+    /// Flat hexes, odd columns shoved down
+    OddColumns,
+    /// Flat hexes, even columns shoved down
+    EvenColumns,
+}
+
+impl From<(i32, i32)> for Hex {
+    fn from(value: (i32, i32)) -> Self {
+        Self::new(value.0, value.1)
+    }
+}
+
+impl From<Hex> for (i32, i32) {
+    fn from(value: Hex) -> Self {
+        (value.q, value.r)
+    }
+}
+
+impl From<CubeHex> for Hex {
+    fn from(value: CubeHex) -> Self {
+        Self::new(value.q, value.r)
+    }
+}
+
+impl From<Hex> for CubeHex {
+    fn from(value: Hex) -> Self {
+        Self {
+            q: value.q,
+            r: value.r,
+            s: value.s(),
+        }
+    }
+}
+
+impl Add for Hex {
+    type Output = Hex;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.q + rhs.q, self.r + rhs.r)
+    }
+}
+
+impl Sub for Hex {
+    type Output = Hex;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.q - rhs.q, self.r - rhs.r)
+    }
+}
+
+impl Neg for Hex {
+    type Output = Hex;
+    fn neg(self) -> Self::Output {
+        Self::new(-self.q, -self.r)
+    }
+}
+
+impl Mul<i32> for Hex {
+    type Output = Hex;
+    fn mul(self, rhs: i32) -> Self::Output {
+        Self::new(self.q * rhs, self.r * rhs)
+    }
+}
+
+impl Hex {
+    /// Neighbour offsets, each next one turned 60° towards negative `r`. First one points along x axis in pointy layout
+    pub const DIRECTIONS: [Hex; 6] = [
+        Hex::new(1, 0),
+        Hex::new(1, -1),
+        Hex::new(0, -1),
+        Hex::new(-1, 0),
+        Hex::new(-1, 1),
+        Hex::new(0, 1),
+    ];
+
+    pub const fn new(q: i32, r: i32) -> Self {
+        Self { q, r }
+    }
+
+    pub fn q(&self) -> i32 {
+        self.q
+    }
+
+    pub fn r(&self) -> i32 {
+        self.r
+    }
+
+    pub fn s(&self) -> i32 {
+        -self.q - self.r
+    }
+
+    /// Nearest hex to fractional axial coordinates
+    pub fn round(q: f64, r: f64) -> Self {
+        let s = -q - r;
+        let (mut rounded_q, mut rounded_r, rounded_s) = (nearest(q), nearest(r), nearest(s));
+        let (dq, dr, ds) = (
+            (rounded_q as f64 - q).abs(),
+            (rounded_r as f64 - r).abs(),
+            (rounded_s as f64 - s).abs(),
+        );
+        // recompute coordinate with largest rounding error to keep cube constraint
+        if dq > dr && dq > ds {
+            rounded_q = -rounded_r - rounded_s;
+        } else if dr > ds {
+            rounded_r = -rounded_q - rounded_s;
+        }
+        Self::new(rounded_q, rounded_r)
+    }
+
+    /// Neighbour in `Hex::DIRECTIONS[direction % 6]`
+    pub fn neighbor(self, direction: usize) -> Self {
+        self + Self::DIRECTIONS[direction % 6]
+    }
+
+    pub fn neighbors(self) -> [Hex; 6] {
+        Self::DIRECTIONS.map(|direction| self + direction)
+    }
+
+    /// Number of steps from origin
+    pub fn length(self) -> u32 {
+        (self.q.unsigned_abs() + self.r.unsigned_abs() + self.s().unsigned_abs()) / 2
+    }
+
+    pub fn distance(self, other: Hex) -> u32 {
+        (self - other).length()
+    }
+
+    /// Rotated around origin by `steps` times 60° in order of `Hex::DIRECTIONS`
+    pub fn rotated(self, steps: i32) -> Self {
+        (0..steps.rem_euclid(6)).fold(self, |hex, _| {
+            // (q, r, s) -> (-s, -q, -r) maps each direction to the next one
+            Self::new(-hex.s(), -hex.q)
+        })
+    }
+
+    pub fn rotated_around(self, center: Hex, steps: i32) -> Self {
+        center + (self - center).rotated(steps)
+    }
+
+    /// Hexes at distance `radius`, single hex for zero radius
+    pub fn ring(self, radius: u32) -> HexRing {
+        HexRing {
+            current: self + Self::DIRECTIONS[4] * radius as i32,
+            radius,
+            side: 0,
+            step: 0,
+        }
+    }
+
+    /// Hexes at distance up to `radius` ordered by rings from center
+    pub fn spiral(self, radius: u32) -> HexSpiral {
+        HexSpiral {
+            center: self,
+            ring: self.ring(0),
+            max_radius: radius,
+        }
+    }
+
+    /// Hexes on straight line to `other` including both ends
+    pub fn line_to(self, other: Hex) -> HexLine {
+        HexLine {
+            from: self,
+            to: other,
+            count: self.distance(other),
+            i: 0,
+        }
+    }
+}
+
+/// Nearest integer, halves rounded away from zero
+fn nearest(value: f64) -> i32 {
+    let truncated = value as i32;
+    let fraction = value - truncated as f64;
+    if fraction >= 0.5 {
+        truncated + 1
+    } else if fraction <= -0.5 {
+        truncated - 1
+    } else {
+        truncated
+    }
+}
+
+impl CubeHex {
+    /// `None` if coordinates do not sum to zero
+    pub fn new(q: i32, r: i32, s: i32) -> Option<Self> {
+        (q + r + s == 0).then_some(Self { q, r, s })
+    }
+
+    pub fn q(&self) -> i32 {
+        self.q
+    }
+
+    pub fn r(&self) -> i32 {
+        self.r
+    }
+
+    pub fn s(&self) -> i32 {
+        self.s
+    }
+}
+
+impl From<(i32, i32)> for OffsetHex {
+    fn from(value: (i32, i32)) -> Self {
+        Self::new(value.0, value.1)
+    }
+}
+
+impl From<OffsetHex> for (i32, i32) {
+    fn from(value: OffsetHex) -> Self {
+        (value.col, value.row)
+    }
+}
+
+impl OffsetHex {
+    pub fn new(col: i32, row: i32) -> Self {
+        Self { col, row }
+    }
+
+    pub fn col(&self) -> i32 {
+        self.col
+    }
+
+    pub fn row(&self) -> i32 {
+        self.row
+    }
+
+    pub fn from_hex(hex: Hex, kind: OffsetKind) -> Self {
+        let (q, r) = hex.into();
+        // `n & 1` is parity for negative `n` too, so halves are exact
+        match kind {
+            OffsetKind::OddRows => Self::new(q + (r - (r & 1)) / 2, r),
+            OffsetKind::EvenRows => Self::new(q + (r + (r & 1)) / 2, r),
+            OffsetKind::OddColumns => Self::new(q, r + (q - (q & 1)) / 2),
+            OffsetKind::EvenColumns => Self::new(q, r + (q + (q & 1)) / 2),
+        }
+    }
+
+    pub fn to_hex(self, kind: OffsetKind) -> Hex {
+        let (col, row) = self.into();
+        match kind {
+            OffsetKind::OddRows => Hex::new(col - (row - (row & 1)) / 2, row),
+            OffsetKind::EvenRows => Hex::new(col - (row + (row & 1)) / 2, row),
+            OffsetKind::OddColumns => Hex::new(col, row - (col - (col & 1)) / 2),
+            OffsetKind::EvenColumns => Hex::new(col, row - (col + (col & 1)) / 2),
+        }
+    }
+}
+
+/// Iterator over hexes of ring, see `Hex::ring`
+#[derive(Debug, Clone)]
+pub struct HexRing {
+    current: Hex,
+    radius: u32,
+    side: usize,
+    step: u32,
+}
+
+impl Iterator for HexRing {
+    type Item = Hex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.side == 6 {
+            return None;
+        }
+        let hex = self.current;
+        if self.radius == 0 {
+            self.side = 6;
+            return Some(hex);
+        }
+        self.current = hex + Hex::DIRECTIONS[self.side];
+        self.step += 1;
+        if self.step == self.radius {
+            (self.side, self.step) = (self.side + 1, 0);
+        }
+        Some(hex)
+    }
+}
+
+/// Iterator over hexes of filled hexagon, see `Hex::spiral`
+#[derive(Debug, Clone)]
+pub struct HexSpiral {
+    center: Hex,
+    ring: HexRing,
+    max_radius: u32,
+}
+
+impl Iterator for HexSpiral {
+    type Item = Hex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(hex) = self.ring.next() {
+                return Some(hex);
+            }
+            if self.ring.radius >= self.max_radius {
+                return None;
+            }
+            self.ring = self.center.ring(self.ring.radius + 1);
+        }
+    }
+}
+
+/// Iterator over hexes of line, see `Hex::line_to`
+#[derive(Debug, Clone)]
+pub struct HexLine {
+    from: Hex,
+    to: Hex,
+    count: u32,
+    i: u32,
+}
+
+impl Iterator for HexLine {
+    type Item = Hex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.i > self.count {
+            return None;
+        }
+        let i = self.i;
+        self.i += 1;
+        if i == 0 || i == self.count {
+            return Some(if i == 0 { self.from } else { self.to });
+        }
+        let t = i as f64 / self.count as f64;
+        let lerp = |a: i32, b: i32| a as f64 + (b - a) as f64 * t;
+        // nudge keeps points exactly between two hexes on consistent side
+        Some(Hex::round(
+            lerp(self.from.q, self.to.q) + 1e-6,
+            lerp(self.from.r, self.to.r) + 1e-6,
+        ))
+    }
+}
+
+/// Hexes with pointed top (rows) or flat top (columns)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HexOrientation {
+    Pointy,
+    Flat,
+}
+
+/// Placement of hex grid in pixel space
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HexLayout<T> {
+    pub orientation: HexOrientation,
+    /// Distance from hex center to corner along each axis, equal for regular hexes
+    pub size: Size<T>,
+    /// Center of origin hex
+    pub origin: Point<T>,
+}
+
+impl<T: Scalar> HexLayout<T> {
+    pub fn new(orientation: HexOrientation, size: Size<T>, origin: Point<T>) -> Self {
+        Self {
+            orientation,
+            size,
+            origin,
+        }
+    }
+
+    fn sqrt3() -> T {
+        from_u32::<T>(3).sqrt()
+    }
+
+    /// Pixel position of hex center
+    pub fn hex_to_pixel(&self, hex: Hex) -> Point<T> {
+        let (q, r) = (ratio::<T>(hex.q, 1), ratio::<T>(hex.r, 1));
+        let (w, h) = self.size.into();
+        let (sqrt3, three_halves) = (Self::sqrt3(), ratio::<T>(3, 2));
+        let (x, y) = match self.orientation {
+            HexOrientation::Pointy => (sqrt3 * (q + r / T::two()), three_halves * r),
+            HexOrientation::Flat => (three_halves * q, sqrt3 * (r + q / T::two())),
+        };
+        (*self.origin.x() + x * w, *self.origin.y() + y * h).into()
+    }
+
+    /// Fractional axial coordinates of pixel
+    pub fn pixel_to_fractional_hex(&self, point: Point<T>) -> (T, T) {
+        let (w, h) = self.size.into();
+        let offset = point - self.origin;
+        let (x, y) = (*offset.x() / w, *offset.y() / h);
+        let (third, two_thirds) = (ratio::<T>(1, 3), ratio::<T>(2, 3));
+        let sqrt3_third = Self::sqrt3() * third;
+        match self.orientation {
+            HexOrientation::Pointy => (sqrt3_third * x - third * y, two_thirds * y),
+            HexOrientation::Flat => (two_thirds * x, sqrt3_third * y - third * x),
+        }
+    }
+
+    /// Corners counterclockwise in y-up axes starting right of center
+    pub fn corners(&self, hex: Hex) -> [Point<T>; 6] {
+        let center = self.hex_to_pixel(hex);
+        let (w, h) = self.size.into();
+        let (half, half_sqrt3) = (ratio::<T>(1, 2), Self::sqrt3() / T::two());
+        let (zero, one) = (T::zero(), T::one());
+        let unit = match self.orientation {
+            HexOrientation::Pointy => [
+                (half_sqrt3, half),
+                (zero, one),
+                (-half_sqrt3, half),
+                (-half_sqrt3, -half),
+                (zero, -one),
+                (half_sqrt3, -half),
+            ],
+            HexOrientation::Flat => [
+                (one, zero),
+                (half, half_sqrt3),
+                (-half, half_sqrt3),
+                (-one, zero),
+                (-half, -half_sqrt3),
+                (half, -half_sqrt3),
+            ],
+        };
+        unit.map(|(x, y)| (*center.x() + x * w, *center.y() + y * h).into())
+    }
+
+    /// Bounding rect of hex in pixel space
+    pub fn hex_rect(&self, hex: Hex) -> Rect<T> {
+        let (w, h) = self.size.into();
+        let sqrt3 = Self::sqrt3();
+        let size = match self.orientation {
+            HexOrientation::Pointy => (sqrt3 * w, T::two() * h),
+            HexOrientation::Flat => (T::two() * w, sqrt3 * h),
+        };
+        Rect::from_center(self.hex_to_pixel(hex), size.into())
+    }
+}
+
+impl<T: Scalar + Into<f64>> HexLayout<T> {
+    /// Hex containing pixel
+    pub fn pixel_to_hex(&self, point: Point<T>) -> Hex {
+        let (q, r) = self.pixel_to_fractional_hex(point);
+        Hex::round(q.into(), r.into())
+    }
+
+    /// Hexes whose bounding rect intersects `viewport`, for culling
+    pub fn visible_hexes(&self, viewport: Rect<T>) -> impl Iterator<Item = Hex> + '_ {
+        let corners = [
+            viewport.left_top(),
+            viewport.right_top(),
+            viewport.right_bottom(),
+            viewport.left_bottom(),
+        ]
+        .map(|corner| self.pixel_to_hex(corner));
+        // rect maps to parallelogram in axial coordinates, bounded by its corners
+        let q_range = corners.iter().map(Hex::q);
+        let (q_min, q_max) = (
+            q_range.clone().min().unwrap_or(0),
+            q_range.max().unwrap_or(0),
+        );
+        let r_range = corners.iter().map(Hex::r);
+        let (r_min, r_max) = (
+            r_range.clone().min().unwrap_or(0),
+            r_range.max().unwrap_or(0),
+        );
+        (r_min - 1..=r_max + 1)
+            .flat_map(move |r| (q_min - 1..=q_max + 1).map(move |q| Hex::new(q, r)))
+            .filter(move |hex| self.hex_rect(*hex).intersects(&viewport))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CubeHex, Hex, OffsetHex, OffsetKind};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn coordinates() {
+        for q in -4..=4 {
+            for r in -4..=4 {
+                let hex = Hex::new(q, r);
+                let cube = CubeHex::from(hex);
+                assert_eq!(cube.q() + cube.r() + cube.s(), 0);
+                assert_eq!(Hex::from(cube), hex);
+                for kind in [
+                    OffsetKind::OddRows,
+                    OffsetKind::EvenRows,
+                    OffsetKind::OddColumns,
+                    OffsetKind::EvenColumns,
+                ] {
+                    assert_eq!(OffsetHex::from_hex(hex, kind).to_hex(kind), hex);
+                }
+            }
+        }
+        assert_eq!(CubeHex::new(1, 2, 3), None);
+        assert_eq!(
+            OffsetHex::from_hex(Hex::new(-1, 3), OffsetKind::OddRows),
+            OffsetHex::new(0, 3)
+        );
+        assert_eq!(
+            OffsetHex::from_hex(Hex::new(-1, 3), OffsetKind::EvenRows),
+            OffsetHex::new(1, 3)
+        );
+        assert_eq!(
+            OffsetHex::from_hex(Hex::new(3, -1), OffsetKind::OddColumns),
+            OffsetHex::new(3, 0)
+        );
+
+        let hex = Hex::new(2, -3);
+        assert_eq!(hex.length(), 3);
+        assert_eq!(hex.distance(Hex::new(-1, 1)), 4);
+        assert!(hex.neighbors().iter().all(|n| n.distance(hex) == 1));
+        assert_eq!(hex.neighbor(7), hex + Hex::DIRECTIONS[1]);
+        assert_eq!(Hex::round(0.4, 0.4), Hex::new(0, 1));
+        assert_eq!(Hex::round(-1.6, 0.3), Hex::new(-1, 0));
+    }
+
+    #[test]
+    fn rings_lines_and_rotation() {
+        let center = Hex::new(1, -2);
+        assert_eq!(center.ring(0).collect::<Vec<_>>(), [center]);
+        for radius in 1..5 {
+            let ring: Vec<_> = center.ring(radius).collect();
+            assert_eq!(ring.len(), 6 * radius as usize);
+            assert!(ring.iter().all(|hex| hex.distance(center) == radius));
+            // consecutive and closing hexes are neighbours
+            assert!(
+                ring.iter()
+                    .zip(ring.iter().cycle().skip(1))
+                    .all(|(a, b)| a.distance(*b) == 1)
+            );
+        }
+        let spiral: Vec<_> = center.spiral(3).collect();
+        assert_eq!(spiral.len(), 1 + 3 * 3 * 4);
+        assert!(spiral.windows(2).all(|pair| pair[0] != pair[1]));
+        assert!(spiral.iter().all(|hex| hex.distance(center) <= 3));
+
+        let to = Hex::new(-3, 4);
+        let line: Vec<_> = center.line_to(to).collect();
+        assert_eq!(line.len(), center.distance(to) as usize + 1);
+        assert_eq!((line[0], *line.last().unwrap()), (center, to));
+        assert!(line.windows(2).all(|pair| pair[0].distance(pair[1]) == 1));
+        assert_eq!(center.line_to(center).collect::<Vec<_>>(), [center]);
+
+        for direction in 0..6 {
+            assert_eq!(
+                Hex::DIRECTIONS[direction].rotated(1),
+                Hex::DIRECTIONS[(direction + 1) % 6]
+            );
+        }
+        let hex = Hex::new(3, -1);
+        assert_eq!(hex.rotated(6), hex);
+        assert_eq!(hex.rotated(-1).rotated(1), hex);
+        assert_eq!(
+            hex.rotated_around(center, 2).distance(center),
+            hex.distance(center)
+        );
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn layout() {
+        use super::{HexLayout, HexOrientation};
+        use crate::math::{Point, Rect};
+        use approx::assert_abs_diff_eq;
+
+        for orientation in [HexOrientation::Pointy, HexOrientation::Flat] {
+            let layout = HexLayout::new(orientation, (10., 10.).into(), (5., -3.).into());
+            for hex in Hex::default().spiral(3) {
+                let center = layout.hex_to_pixel(hex);
+                assert_eq!(layout.pixel_to_hex(center), hex);
+                let corners = layout.corners(hex);
+                for corner in corners {
+                    assert_abs_diff_eq!(corner.distance(center), 10., epsilon = 1e-9);
+                    // slightly inside corner still belongs to hex
+                    let inside = Point::from((
+                        center.x() + (corner.x() - center.x()) * 0.95,
+                        center.y() + (corner.y() - center.y()) * 0.95,
+                    ));
+                    assert_eq!(layout.pixel_to_hex(inside), hex);
+                }
+                let rect = layout.hex_rect(hex);
+                let bounds = Rect::aabb_from_points(corners.into_iter()).unwrap();
+                assert_abs_diff_eq!(
+                    [rect.left(), rect.right(), rect.top(), rect.bottom()][..],
+                    [bounds.left(), bounds.right(), bounds.top(), bounds.bottom()][..],
+                    epsilon = 1e-9
+                );
+            }
+            let viewport = Rect::from((0., 0., 100., 60.));
+            let visible: Vec<_> = layout.visible_hexes(viewport).collect();
+            for x in (0..100).step_by(7) {
+                for y in (0..60).step_by(7) {
+                    let hex = layout.pixel_to_hex((x as f64, y as f64).into());
+                    assert!(visible.contains(&hex));
+                }
+            }
+            assert!(
+                visible
+                    .iter()
+                    .all(|hex| layout.hex_rect(*hex).intersects(&viewport))
+            );
+        }
+        let pointy = HexLayout::new(HexOrientation::Pointy, (1., 1.).into(), Point::origin());
+        let center: [f64; 2] = pointy.hex_to_pixel(Hex::new(1, 1)).into();
+        assert_abs_diff_eq!(center[..], [1.5 * 3f64.sqrt(), 1.5][..], epsilon = 1e-12);
+    }
+}
@@ -0,0 +1,195 @@
+mod bezier;
+mod grid_path;
+mod hex;
+mod navmesh;
+mod path;
+mod polyline;
+mod raster;
+mod spline;
+mod stroke;
+
+pub use bezier::*;
+pub use grid_path::*;
+pub use hex::*;
+pub use navmesh::*;
+pub use path::*;
+pub use polyline::*;
+pub use raster::*;
+pub use spline::*;
+pub use stroke::*;
+
+use crate::math::{
+    Abs, Atan2, Cos, DegToRad, IsPositive, One, Pi, Point, Positive, RadToDeg, RemEuclid, Sin, Sq,
+    Sqrt, Two, Zero, from_u32, lerp, ratio,
+};
+use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// Real number used by geometry algorithms: `f32` and `f64` with either `std` or `libm` feature
+pub trait Scalar:
+    Copy
+    + PartialOrd
+    + Zero
+    + One
+    + Two
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + Abs<Output = Self>
+    + Sq<Output = Self>
+    + Sqrt<Output = Self>
+{
+}
+
+impl<T> Scalar for T where
+    T: Copy
+        + PartialOrd
+        + Zero
+        + One
+        + Two
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Neg<Output = T>
+        + Abs<Output = T>
+        + Sq<Output = T>
+        + Sqrt<Output = T>
+{
+}
+
+/// `Scalar` with trigonometry and angle conversions, needed by elliptical arcs
+pub trait TrigScalar:
+    Scalar
+    + Pi
+    + Sin<Output = Self>
+    + Cos<Output = Self>
+    + Atan2<Output = Self>
+    + Rem<Output = Self>
+    + RemEuclid<Output = Self>
+    + DegToRad<Output = Self>
+    + RadToDeg<Output = Self>
+    + IsPositive
+{
+}
+
+impl<T> TrigScalar for T where
+    T: Scalar
+        + Pi
+        + Sin<Output = T>
+        + Cos<Output = T>
+        + Atan2<Output = T>
+        + Rem<Output = T>
+        + RemEuclid<Output = T>
+        + DegToRad<Output = T>
+        + RadToDeg<Output = T>
+        + IsPositive
+{
+}
+
+fn min<T: PartialOrd>(a: T, b: T) -> T {
+    if b < a { b } else { a }
+}
+
+fn max<T: PartialOrd>(a: T, b: T) -> T {
+    if b > a { b } else { a }
+}
+
+/// Parameter in `0..=1` of point of segment `a..b` closest to `p`
+fn segment_parameter<T: Scalar>(p: Point<T>, a: Point<T>, b: Point<T>) -> T {
+    let chord = b - a;
+    let len_sqr = chord.len_sqr();
+    if len_sqr <= T::zero() {
+        return T::zero();
+    }
+    max(T::zero(), min(T::one(), chord.dot(p - a) / len_sqr))
+}
+
+fn segment_distance_sqr<T: Scalar>(p: Point<T>, a: Point<T>, b: Point<T>) -> T {
+    (p - lerp(a, b, segment_parameter(p, a, b))).len_sqr()
+}
+
+/// Limit of interval halving in arc length computation
+const MAX_LENGTH_DEPTH: u32 = 12;
+
+/// Limit of root finding iterations in arc-length parametrization
+const MAX_LENGTH_ITERATIONS: u32 = 24;
+
+/// Gauss–Legendre quadrature of `speed` over `t0..t1` with three nodes
+fn gauss_legendre<T: Scalar, S: Fn(T) -> T>(speed: &S, t0: T, t1: T) -> T {
+    let half = (t1 - t0) / T::two();
+    let mid = t0 + half;
+    let node = ratio::<T>(3, 5).sqrt() * half;
+    (speed(mid - node) * from_u32(5) + speed(mid) * from_u32(8) + speed(mid + node) * from_u32(5))
+        * half
+        / from_u32(9)
+}
+
+fn adaptive_length<T: Scalar, S: Fn(T) -> T>(
+    speed: &S,
+    t0: T,
+    t1: T,
+    whole: T,
+    tolerance: T,
+    depth: u32,
+) -> T {
+    let mid = (t0 + t1) / T::two();
+    let left = gauss_legendre(speed, t0, mid);
+    let right = gauss_legendre(speed, mid, t1);
+    if depth >= MAX_LENGTH_DEPTH || (left + right - whole).abs() <= tolerance {
+        left + right
+    } else {
+        let tolerance = tolerance / T::two();
+        adaptive_length(speed, t0, mid, left, tolerance, depth + 1)
+            + adaptive_length(speed, mid, t1, right, tolerance, depth + 1)
+    }
+}
+
+/// Length of curve moving with `speed` (magnitude of derivative) from `t0` to `t1`
+fn arc_length<T: Scalar, S: Fn(T) -> T>(speed: S, t0: T, t1: T, tolerance: Positive<T>) -> T {
+    let whole = gauss_legendre(&speed, t0, t1);
+    adaptive_length(&speed, t0, t1, whole, tolerance.into_inner(), 0)
+}
+
+/// Parameter in `domain` at which arc length from domain start reaches `length`.
+/// Newton iterations are kept inside bracket by bisection
+fn t_at_length<T: Scalar, S: Fn(T) -> T>(
+    speed: S,
+    domain: (T, T),
+    length: T,
+    tolerance: Positive<T>,
+) -> T {
+    let (start, end) = domain;
+    let total = arc_length(&speed, start, end, tolerance);
+    if length <= T::zero() {
+        return start;
+    } else if length >= total {
+        return end;
+    }
+    let (mut low, mut high) = (start, end);
+    let mut t = start + (end - start) * length / total;
+    for _ in 0..MAX_LENGTH_ITERATIONS {
+        let error = arc_length(&speed, start, t, tolerance) - length;
+        if error.abs() <= tolerance.into_inner() {
+            break;
+        }
+        if error > T::zero() {
+            high = t
+        } else {
+            low = t
+        }
+        let speed = speed(t);
+        let next = if speed > T::zero() {
+            t - error / speed
+        } else {
+            low
+        };
+        t = if next > low && next < high {
+            next
+        } else {
+            (low + high) / T::two()
+        };
+    }
+    t
+}
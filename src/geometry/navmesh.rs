@@ -0,0 +1,537 @@
+use super::Scalar;
+use crate::math::Point;
+use alloc::{collections::BinaryHeap, vec::Vec};
+use core::{
+    cmp::Ordering,
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Positive if `c` is to the left of ray from `a` through `b` (y-up axes)
+fn orientation<T: Scalar>(a: Point<T>, b: Point<T>, c: Point<T>) -> T {
+    (b - a).cross(c - a)
+}
+
+/// Whether `p` is inside counterclockwise triangle or on its border
+fn triangle_contains<T: Scalar>(a: Point<T>, b: Point<T>, c: Point<T>, p: Point<T>) -> bool {
+    orientation(a, b, p) >= T::zero()
+        && orientation(b, c, p) >= T::zero()
+        && orientation(c, a, p) >= T::zero()
+}
+
+/// Ear clipping triangulation of simple polygon of either orientation.
+/// Triangles index `points` and are counterclockwise (positive area), collinear vertices are dropped.
+/// `None` if polygon has less than 3 vertices, zero area or no ear can be found.
+/// Self-intersecting polygon is not always detected and may give overlapping triangles
+pub fn triangulate_polygon<T: Scalar>(points: &[Point<T>]) -> Option<Vec<[usize; 3]>> {
+    if points.len() < 3 {
+        return None;
+    }
+    let doubled_area = points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .fold(T::zero(), |area, (a, b)| {
+            area + (*a - Point::origin()).cross(*b - Point::origin())
+        });
+    let mut remaining: Vec<usize> = (0..points.len()).collect();
+    if doubled_area < T::zero() {
+        remaining.reverse();
+    }
+    let mut triangles = Vec::with_capacity(points.len() - 2);
+    while remaining.len() > 3 {
+        let count = remaining.len();
+        let corner = |i: usize| {
+            (
+                remaining[(i + count - 1) % count],
+                remaining[i],
+                remaining[(i + 1) % count],
+            )
+        };
+        let is_ear = |i: usize| {
+            let (a, b, c) = corner(i);
+            let (pa, pb, pc) = (points[a], points[b], points[c]);
+            orientation(pa, pb, pc) > T::zero()
+                && remaining.iter().all(|&j| {
+                    let p = points[j];
+                    j == a
+                        || j == b
+                        || j == c
+                        || p == pa
+                        || p == pb
+                        || p == pc
+                        || !triangle_contains(pa, pb, pc, p)
+                })
+        };
+        if let Some(i) = (0..count).find(|&i| is_ear(i)) {
+            let (a, b, c) = corner(i);
+            triangles.push([a, b, c]);
+            remaining.remove(i);
+        } else {
+            // collinear or repeated vertex only makes zero area corner, drop it
+            let i = (0..count).find(|&i| {
+                let (a, b, c) = corner(i);
+                orientation(points[a], points[b], points[c]) == T::zero()
+            })?;
+            remaining.remove(i);
+        }
+    }
+    let (a, b, c) = (remaining[0], remaining[1], remaining[2]);
+    if orientation(points[a], points[b], points[c]) > T::zero() {
+        triangles.push([a, b, c]);
+    }
+    (!triangles.is_empty()).then_some(triangles)
+}
+
+/// Error of navigation mesh construction, values are polygon or triangle indices
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NavMeshError {
+    /// Polygon has less than 3 vertices
+    DegeneratePolygon(usize),
+    /// Polygon is not simple
+    TriangulationFailed(usize),
+    /// Deserialized triangle has vertex index out of range or is not counterclockwise
+    InvalidTriangle(usize),
+    /// Deserialized triangle neighbors do not match its shared edges
+    InvalidNeighbors(usize),
+}
+
+impl Display for NavMeshError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            NavMeshError::DegeneratePolygon(i) => {
+                write!(f, "polygon {} has less than 3 vertices", i)
+            }
+            NavMeshError::TriangulationFailed(i) => {
+                write!(f, "polygon {} can not be triangulated", i)
+            }
+            NavMeshError::InvalidTriangle(i) => {
+                write!(f, "triangle {} is out of range or not counterclockwise", i)
+            }
+            NavMeshError::InvalidNeighbors(i) => {
+                write!(f, "triangle {} neighbors do not match shared edges", i)
+            }
+        }
+    }
+}
+
+impl Error for NavMeshError {}
+
+/// Walkable area made of counterclockwise triangles connected through shared edges (portals)
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        try_from = "RawNavMesh<T>",
+        bound(deserialize = "T: Deserialize<'de> + Scalar")
+    )
+)]
+pub struct NavMesh<T> {
+    vertices: Vec<Point<T>>,
+    triangles: Vec<[usize; 3]>,
+    /// triangle across edge from vertex `i` to vertex `i + 1`
+    neighbors: Vec<[Option<usize>; 3]>,
+}
+
+/// Unvalidated `NavMesh` fields, checked against triangles before use
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RawNavMesh<T> {
+    vertices: Vec<Point<T>>,
+    triangles: Vec<[usize; 3]>,
+    neighbors: Vec<[Option<usize>; 3]>,
+}
+
+#[cfg(feature = "serde")]
+impl<T: Scalar> TryFrom<RawNavMesh<T>> for NavMesh<T> {
+    type Error = NavMeshError;
+
+    fn try_from(value: RawNavMesh<T>) -> Result<Self, Self::Error> {
+        let RawNavMesh {
+            vertices,
+            triangles,
+            neighbors,
+        } = value;
+        for (i, triangle) in triangles.iter().enumerate() {
+            let [a, b, c] = triangle.map(|j| vertices.get(j).copied());
+            match (a, b, c) {
+                (Some(a), Some(b), Some(c)) if orientation(a, b, c) > T::zero() => {}
+                _ => return Err(NavMeshError::InvalidTriangle(i)),
+            }
+        }
+        let connected = connect(&triangles);
+        if neighbors != connected {
+            // first mismatching triangle, or first one missing from shorter list
+            let i = neighbors
+                .iter()
+                .zip(&connected)
+                .position(|(stored, actual)| stored != actual)
+                .unwrap_or(neighbors.len().min(connected.len()));
+            return Err(NavMeshError::InvalidNeighbors(i));
+        }
+        Ok(Self {
+            vertices,
+            triangles,
+            neighbors,
+        })
+    }
+}
+
+/// Triangles adjacent across each edge, matched by identical end point indices
+fn connect(triangles: &[[usize; 3]]) -> Vec<[Option<usize>; 3]> {
+    // edges sorted by their vertices put both sides of shared edge next to each other
+    let mut edges: Vec<(usize, usize, usize, usize)> = triangles
+        .iter()
+        .enumerate()
+        .flat_map(|(t, triangle): (usize, &[usize; 3])| {
+            (0..3).map(move |e| {
+                let (a, b) = (triangle[e], triangle[(e + 1) % 3]);
+                (a.min(b), a.max(b), t, e)
+            })
+        })
+        .collect();
+    edges.sort_unstable();
+    let mut neighbors = alloc::vec![[None; 3]; triangles.len()];
+    for pair in edges.windows(2) {
+        let ((a0, b0, t0, e0), (a1, b1, t1, e1)) = (pair[0], pair[1]);
+        if (a0, b0) == (a1, b1) {
+            neighbors[t0][e0] = Some(t1);
+            neighbors[t1][e1] = Some(t0);
+        }
+    }
+    neighbors
+}
+
+/// Open set entry ordered so that `BinaryHeap` pops smallest estimate first
+struct Candidate<T> {
+    estimate: T,
+    triangle: usize,
+}
+
+impl<T: PartialOrd> PartialEq for Candidate<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: PartialOrd> Eq for Candidate<T> {}
+
+impl<T: PartialOrd> PartialOrd for Candidate<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: PartialOrd> Ord for Candidate<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .partial_cmp(&self.estimate)
+            .unwrap_or(Ordering::Equal)
+            .then(other.triangle.cmp(&self.triangle))
+    }
+}
+
+impl<T: Scalar> NavMesh<T> {
+    /// Triangulates walkable simple polygons (without holes). Polygons are connected where they
+    /// share edge with identical end points, edges meeting partially (T-junctions) are not connected
+    pub fn new<P: AsRef<[Point<T>]>>(polygons: &[P]) -> Result<Self, NavMeshError> {
+        let mut vertices: Vec<Point<T>> = Vec::new();
+        let mut triangles = Vec::new();
+        for (i, polygon) in polygons.iter().enumerate() {
+            let polygon = polygon.as_ref();
+            if polygon.len() < 3 {
+                return Err(NavMeshError::DegeneratePolygon(i));
+            }
+            let indices: Vec<usize> = polygon
+                .iter()
+                .map(|point| {
+                    vertices.iter().position(|v| v == point).unwrap_or_else(|| {
+                        vertices.push(*point);
+                        vertices.len() - 1
+                    })
+                })
+                .collect();
+            let polygon_triangles =
+                triangulate_polygon(polygon).ok_or(NavMeshError::TriangulationFailed(i))?;
+            triangles.extend(
+                polygon_triangles
+                    .into_iter()
+                    .map(|triangle| triangle.map(|j| indices[j])),
+            );
+        }
+        let neighbors = connect(&triangles);
+        Ok(Self {
+            vertices,
+            triangles,
+            neighbors,
+        })
+    }
+
+    pub fn vertices(&self) -> &[Point<T>] {
+        &self.vertices
+    }
+
+    /// Vertex indices of counterclockwise triangles
+    pub fn triangles(&self) -> &[[usize; 3]] {
+        &self.triangles
+    }
+
+    /// Triangles adjacent to `triangle` across each of its edges
+    pub fn neighbors(&self, triangle: usize) -> [Option<usize>; 3] {
+        self.neighbors[triangle]
+    }
+
+    pub fn triangle_points(&self, triangle: usize) -> [Point<T>; 3] {
+        self.triangles[triangle].map(|i| self.vertices[i])
+    }
+
+    /// Shared edge as `(left, right)` end points when moving from triangle `from` to adjacent triangle `to`
+    pub fn portal(&self, from: usize, to: usize) -> Option<(Point<T>, Point<T>)> {
+        let edge = self.neighbors[from]
+            .iter()
+            .position(|neighbor| *neighbor == Some(to))?;
+        let triangle = self.triangles[from];
+        Some((
+            self.vertices[triangle[(edge + 1) % 3]],
+            self.vertices[triangle[edge]],
+        ))
+    }
+
+    /// Triangle containing `point`, first one if it lies on shared border
+    pub fn locate(&self, point: Point<T>) -> Option<usize> {
+        (0..self.triangles.len()).find(|&triangle| {
+            let [a, b, c] = self.triangle_points(triangle);
+            triangle_contains(a, b, c, point)
+        })
+    }
+
+    /// A* over portal graph from triangle containing `start` to one containing `goal`.
+    /// Costs are measured between portal midpoints
+    pub fn triangle_path(&self, start: Point<T>, goal: Point<T>) -> Option<Vec<usize>> {
+        let (from, to) = (self.locate(start)?, self.locate(goal)?);
+        let count = self.triangles.len();
+        let mut costs: Vec<Option<T>> = alloc::vec![None; count];
+        let mut entries = alloc::vec![start; count];
+        let mut parents: Vec<Option<usize>> = alloc::vec![None; count];
+        let mut closed = alloc::vec![false; count];
+        costs[from] = Some(T::zero());
+        let mut open = BinaryHeap::from([Candidate {
+            estimate: (goal - start).len(),
+            triangle: from,
+        }]);
+        while let Some(Candidate { triangle, .. }) = open.pop() {
+            if triangle == to {
+                let mut path = alloc::vec![triangle];
+                while let Some(parent) = parents[*path.last()?] {
+                    path.push(parent)
+                }
+                path.reverse();
+                return Some(path);
+            }
+            if closed[triangle] {
+                continue;
+            }
+            closed[triangle] = true;
+            let cost = costs[triangle]?;
+            for neighbor in self.neighbors[triangle].into_iter().flatten() {
+                if closed[neighbor] {
+                    continue;
+                }
+                let (left, right) = self.portal(triangle, neighbor)?;
+                let midpoint = left + (right - left) / T::two();
+                let neighbor_cost = cost + (midpoint - entries[triangle]).len();
+                if costs[neighbor].is_none_or(|known| neighbor_cost < known) {
+                    costs[neighbor] = Some(neighbor_cost);
+                    entries[neighbor] = midpoint;
+                    parents[neighbor] = Some(triangle);
+                    open.push(Candidate {
+                        estimate: neighbor_cost + (goal - midpoint).len(),
+                        triangle: neighbor,
+                    });
+                }
+            }
+        }
+        None
+    }
+
+    /// Shortest path inside corridor of triangles found by `triangle_path`, `None` if `start` or `goal`
+    /// is outside of mesh or they are not connected
+    pub fn find_path(&self, start: Point<T>, goal: Point<T>) -> Option<Vec<Point<T>>> {
+        let triangles = self.triangle_path(start, goal)?;
+        let portals = triangles
+            .windows(2)
+            .map(|pair| self.portal(pair[0], pair[1]))
+            .collect::<Option<Vec<_>>>()?;
+        Some(funnel(start, goal, &portals))
+    }
+}
+
+/** @brief Simple stupid funnel algorithm (string pulling)
+ *  @param start - first point of path
+ *  @param goal - last point of path
+ *  @param portals - `(left, right)` end points of edges crossed in order, as seen when moving towards goal
+ *  @return shortest polyline from `start` to `goal` passing through every portal
+ */
+pub fn funnel<T: Scalar>(
+    start: Point<T>,
+    goal: Point<T>,
+    portals: &[(Point<T>, Point<T>)],
+) -> Vec<Point<T>> {
+    let mut points = alloc::vec![start];
+    let all: Vec<(Point<T>, Point<T>)> = core::iter::once((start, start))
+        .chain(portals.iter().copied())
+        .chain(core::iter::once((goal, goal)))
+        .collect();
+    let (mut apex, mut left, mut right) = (start, start, start);
+    let (mut left_index, mut right_index) = (0, 0);
+    let mut i = 1;
+    while i < all.len() {
+        let (next_left, next_right) = all[i];
+        // right side narrows when it moves left
+        if orientation(apex, right, next_right) >= T::zero() {
+            if apex == right || orientation(apex, left, next_right) < T::zero() {
+                (right, right_index) = (next_right, i);
+            } else {
+                // right crossed over left, so left corner becomes new apex
+                if points.last() != Some(&left) {
+                    points.push(left);
+                }
+                (apex, right, right_index) = (left, left, left_index);
+                i = left_index + 1;
+                continue;
+            }
+        }
+        if orientation(apex, left, next_left) <= T::zero() {
+            if apex == left || orientation(apex, right, next_left) > T::zero() {
+                (left, left_index) = (next_left, i);
+            } else {
+                if points.last() != Some(&right) {
+                    points.push(right);
+                }
+                (apex, left, left_index) = (right, right, right_index);
+                i = right_index + 1;
+                continue;
+            }
+        }
+        i += 1;
+    }
+    if points.last() != Some(&goal) {
+        points.push(goal);
+    }
+    points
+}
+
+#[cfg(test)]
+#[cfg(any(feature = "std", feature = "libm"))]
+mod tests {
+    use super::{NavMesh, NavMeshError, funnel, triangulate_polygon};
+    use crate::math::Point;
+    use alloc::vec::Vec;
+
+    fn points(coordinates: &[(f64, f64)]) -> Vec<Point<f64>> {
+        coordinates.iter().map(|p| Point::from(*p)).collect()
+    }
+
+    #[test]
+    fn triangulation() {
+        // clockwise concave polygon with collinear vertex
+        let polygon = points(&[
+            (0., 0.),
+            (0., 4.),
+            (4., 4.),
+            (4., 2.),
+            (2., 2.),
+            (2., 0.),
+            (1., 0.),
+        ]);
+        let triangles = triangulate_polygon(&polygon).unwrap();
+        assert_eq!(triangles.len(), 5);
+        let area: f64 = triangles
+            .iter()
+            .map(|t| (polygon[t[1]] - polygon[t[0]]).cross(polygon[t[2]] - polygon[t[0]]) / 2.)
+            .sum();
+        assert_eq!(area, 12.);
+        assert_eq!(triangulate_polygon(&polygon[..2]), None);
+        let collinear = points(&[(0., 0.), (1., 1.), (3., 3.), (2., 2.)]);
+        assert_eq!(triangulate_polygon(&collinear), None);
+    }
+
+    #[test]
+    fn path_around_corners() {
+        // U shape made of three rectangles
+        let mesh = NavMesh::new(&[
+            points(&[(0., 0.), (2., 0.), (2., 2.), (2., 6.), (0., 6.)]),
+            points(&[(2., 0.), (6., 0.), (6., 2.), (2., 2.)]),
+            points(&[(6., 0.), (8., 0.), (8., 6.), (6., 6.), (6., 2.)]),
+        ])
+        .unwrap();
+        assert_eq!(mesh.vertices().len(), 10);
+        assert_eq!(mesh.locate((4., 4.).into()), None);
+        assert!(mesh.locate((4., 1.).into()).is_some());
+
+        let path = mesh.find_path((1., 5.).into(), (7., 5.).into()).unwrap();
+        assert_eq!(path, points(&[(1., 5.), (2., 2.), (6., 2.), (7., 5.)]));
+        let back = mesh.find_path((7., 5.).into(), (1., 5.).into()).unwrap();
+        assert_eq!(back, path.iter().rev().copied().collect::<Vec<_>>());
+
+        let straight = mesh.find_path((0.5, 1.).into(), (7.5, 0.5).into()).unwrap();
+        assert_eq!(straight, points(&[(0.5, 1.), (7.5, 0.5)]));
+        assert_eq!(mesh.find_path((1., 1.).into(), (9., 1.).into()), None);
+
+        let separate = NavMesh::new(&[
+            points(&[(0., 0.), (1., 0.), (0., 1.)]),
+            points(&[(5., 0.), (6., 0.), (5., 1.)]),
+        ])
+        .unwrap();
+        assert_eq!(
+            separate.find_path((0.1, 0.1).into(), (5.1, 0.1).into()),
+            None
+        );
+        assert_eq!(
+            NavMesh::new(&[points(&[(0., 0.), (1., 0.)])]),
+            Err(NavMeshError::DegeneratePolygon(0))
+        );
+    }
+
+    #[test]
+    fn funnel_through_portals() {
+        // corridor turning left around (2, 2)
+        let portals = [
+            ((2., 2.).into(), (2., 0.).into()),
+            ((2., 2.).into(), (4., 2.).into()),
+        ];
+        assert_eq!(
+            funnel((0., 1.).into(), (3., 5.).into(), &portals),
+            points(&[(0., 1.), (2., 2.), (3., 5.)])
+        );
+        assert_eq!(
+            funnel::<f64>((0., 0.).into(), (1., 1.).into(), &[]),
+            points(&[(0., 0.), (1., 1.)])
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let mesh = NavMesh::new(&[points(&[(0., 0.), (1., 0.), (1., 1.), (0., 1.)])]).unwrap();
+        let json = serde_json::to_string(&mesh).unwrap();
+        let result: NavMesh<f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(result, mesh);
+
+        let vertices = "[[0.0,0.0],[1.0,0.0],[0.0,1.0]]";
+        let parse = |triangles: &str, neighbors: &str| {
+            serde_json::from_str::<NavMesh<f64>>(&alloc::format!(
+                r#"{{"vertices":{vertices},"triangles":{triangles},"neighbors":{neighbors}}}"#
+            ))
+        };
+        assert!(parse("[[0,1,2]]", "[[null,null,null]]").is_ok());
+        assert!(parse("[[0,1,3]]", "[[null,null,null]]").is_err());
+        assert!(parse("[[0,2,1]]", "[[null,null,null]]").is_err());
+        assert!(parse("[[0,1,2]]", "[[0,null,null]]").is_err());
+        assert!(parse("[[0,1,2]]", "[]").is_err());
+    }
+}
@@ -0,0 +1,1080 @@
+use super::{Bezier, CubicBezier, LineSegment, QuadBezier, RationalQuadBezier, Scalar, TrigScalar};
+use crate::math::{Angle, Matrix, Point, Positive, Rect, Vector, from_u32, indices};
+use alloc::vec::Vec;
+use core::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Path command with absolute coordinates
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PathSegment<T> {
+    /// Start new subpath
+    MoveTo(Point<T>),
+    LineTo(Point<T>),
+    QuadTo {
+        ctrl: Point<T>,
+        to: Point<T>,
+    },
+    CubicTo {
+        ctrl1: Point<T>,
+        ctrl2: Point<T>,
+        to: Point<T>,
+    },
+    /// Elliptical arc in SVG endpoint form: radii and rotation of ellipse, flags choosing one of four possible arcs.
+    /// Radii too small to reach `to` are scaled up uniformly
+    ArcTo {
+        rx: T,
+        ry: T,
+        x_rotation: Angle<T>,
+        large_arc: bool,
+        sweep: bool,
+        to: Point<T>,
+    },
+    /// Line back to start of current subpath
+    Close,
+}
+
+/// Piece of path with single parametrization `0..=1`. Elliptical arcs are split into exact conics
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PathCurve<T> {
+    Line(LineSegment<T>),
+    Quad(QuadBezier<T>),
+    Cubic(CubicBezier<T>),
+    Conic(RationalQuadBezier<T>),
+}
+
+macro_rules! dispatch {
+    ($self: expr, $curve: ident => $body: expr) => {
+        match $self {
+            PathCurve::Line($curve) => $body,
+            PathCurve::Quad($curve) => $body,
+            PathCurve::Cubic($curve) => $body,
+            PathCurve::Conic($curve) => $body,
+        }
+    };
+}
+
+impl<T: Scalar> PathCurve<T> {
+    pub fn start(&self) -> Point<T> {
+        dispatch!(self, curve => curve.start())
+    }
+
+    pub fn end(&self) -> Point<T> {
+        dispatch!(self, curve => curve.end())
+    }
+
+    pub fn eval(&self, t: T) -> Point<T> {
+        dispatch!(self, curve => curve.eval(t))
+    }
+
+    pub fn derivative(&self, t: T) -> Vector<T> {
+        dispatch!(self, curve => curve.derivative(t))
+    }
+
+    pub fn tangent(&self, t: T) -> Option<Vector<T>> {
+        dispatch!(self, curve => curve.tangent(t))
+    }
+
+    pub fn bounding_rect(&self) -> Rect<T> {
+        dispatch!(self, curve => curve.bounding_rect())
+    }
+
+    pub fn for_each_flattened<F: FnMut(Point<T>)>(&self, tolerance: Positive<T>, mut f: F) {
+        dispatch!(self, curve => curve.for_each_flattened(tolerance, &mut f))
+    }
+
+    pub fn arc_length(&self, tolerance: Positive<T>) -> T {
+        dispatch!(self, curve => curve.arc_length(tolerance))
+    }
+
+    pub fn t_at_length(&self, length: T, tolerance: Positive<T>) -> T {
+        dispatch!(self, curve => curve.t_at_length(length, tolerance))
+    }
+}
+
+/// Sequence of subpaths made of lines, Bézier curves and elliptical arcs, same model as SVG `<path>`.
+/// Parsed from and formatted to SVG path data
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Path<T> {
+    segments: Vec<PathSegment<T>>,
+}
+
+/// Traversal of path with implicit starts and closing lines resolved
+enum Event<T> {
+    Begin(Point<T>),
+    Curve(PathCurve<T>),
+    End { closed: bool },
+}
+
+impl<T> Path<T> {
+    pub fn new() -> Self {
+        Self {
+            segments: Vec::new(),
+        }
+    }
+
+    pub fn segments(&self) -> &[PathSegment<T>] {
+        &self.segments
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    pub fn push(&mut self, segment: PathSegment<T>) -> &mut Self {
+        self.segments.push(segment);
+        self
+    }
+
+    pub fn move_to(&mut self, to: Point<T>) -> &mut Self {
+        self.push(PathSegment::MoveTo(to))
+    }
+
+    pub fn line_to(&mut self, to: Point<T>) -> &mut Self {
+        self.push(PathSegment::LineTo(to))
+    }
+
+    pub fn quad_to(&mut self, ctrl: Point<T>, to: Point<T>) -> &mut Self {
+        self.push(PathSegment::QuadTo { ctrl, to })
+    }
+
+    pub fn cubic_to(&mut self, ctrl1: Point<T>, ctrl2: Point<T>, to: Point<T>) -> &mut Self {
+        self.push(PathSegment::CubicTo { ctrl1, ctrl2, to })
+    }
+
+    pub fn arc_to(
+        &mut self,
+        rx: T,
+        ry: T,
+        x_rotation: Angle<T>,
+        large_arc: bool,
+        sweep: bool,
+        to: Point<T>,
+    ) -> &mut Self {
+        self.push(PathSegment::ArcTo {
+            rx,
+            ry,
+            x_rotation,
+            large_arc,
+            sweep,
+            to,
+        })
+    }
+
+    pub fn close(&mut self) -> &mut Self {
+        self.push(PathSegment::Close)
+    }
+}
+
+impl<T> From<Vec<PathSegment<T>>> for Path<T> {
+    fn from(segments: Vec<PathSegment<T>>) -> Self {
+        Self { segments }
+    }
+}
+
+impl<T> FromIterator<PathSegment<T>> for Path<T> {
+    fn from_iter<I: IntoIterator<Item = PathSegment<T>>>(iter: I) -> Self {
+        Self {
+            segments: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T: TrigScalar> Path<T> {
+    fn walk<F: FnMut(Event<T>)>(&self, mut f: F) {
+        let mut current = Point::origin();
+        let mut start = current;
+        let mut open = false;
+        for segment in &self.segments {
+            match *segment {
+                PathSegment::MoveTo(to) => {
+                    if open {
+                        f(Event::End { closed: false })
+                    }
+                    f(Event::Begin(to));
+                    (current, start, open) = (to, to, true);
+                }
+                PathSegment::Close => {
+                    if open {
+                        if current != start {
+                            f(Event::Curve(PathCurve::Line(LineSegment::new(
+                                current, start,
+                            ))))
+                        }
+                        f(Event::End { closed: true });
+                        (current, open) = (start, false);
+                    }
+                }
+                segment => {
+                    if !open {
+                        f(Event::Begin(current));
+                        (start, open) = (current, true);
+                    }
+                    current = match segment {
+                        PathSegment::LineTo(to) => {
+                            f(Event::Curve(PathCurve::Line(LineSegment::new(current, to))));
+                            to
+                        }
+                        PathSegment::QuadTo { ctrl, to } => {
+                            f(Event::Curve(PathCurve::Quad(QuadBezier::new(
+                                current, ctrl, to,
+                            ))));
+                            to
+                        }
+                        PathSegment::CubicTo { ctrl1, ctrl2, to } => {
+                            f(Event::Curve(PathCurve::Cubic(CubicBezier::new(
+                                current, ctrl1, ctrl2, to,
+                            ))));
+                            to
+                        }
+                        PathSegment::ArcTo {
+                            rx,
+                            ry,
+                            x_rotation,
+                            large_arc,
+                            sweep,
+                            to,
+                        } => {
+                            for_each_arc_curve(
+                                current,
+                                (rx, ry),
+                                x_rotation,
+                                large_arc,
+                                sweep,
+                                to,
+                                |curve| f(Event::Curve(curve)),
+                            );
+                            to
+                        }
+                        PathSegment::MoveTo(_) | PathSegment::Close => unreachable!(),
+                    };
+                }
+            }
+        }
+        if open {
+            f(Event::End { closed: false })
+        }
+    }
+
+    /// Calls `f` with every drawable piece in order, including closing lines
+    pub fn for_each_curve<F: FnMut(PathCurve<T>)>(&self, mut f: F) {
+        self.walk(|event| {
+            if let Event::Curve(curve) = event {
+                f(curve)
+            }
+        })
+    }
+
+    /// Tight bounding rect, `None` for empty path
+    pub fn bounding_rect(&self) -> Option<Rect<T>> {
+        // subpath starts and corners of tight curve bounds
+        let mut points = Vec::new();
+        self.walk(|event| match event {
+            Event::Begin(point) => points.push(point),
+            Event::Curve(curve) => {
+                let rect = curve.bounding_rect();
+                points.extend([rect.left_top(), rect.right_bottom()])
+            }
+            Event::End { .. } => {}
+        });
+        Rect::aabb_from_points(points.into_iter())
+    }
+
+    /// Polyline per subpath deviating from path no further than `tolerance`.
+    /// Polylines of closed subpaths end with their first point
+    pub fn flattened(&self, tolerance: Positive<T>) -> Vec<Vec<Point<T>>> {
+        self.flattened_subpaths(tolerance)
+            .into_iter()
+            .map(|(polyline, _)| polyline)
+            .collect()
+    }
+
+    /// Flattened subpaths with flags whether they are closed
+    pub(super) fn flattened_subpaths(&self, tolerance: Positive<T>) -> Vec<(Vec<Point<T>>, bool)> {
+        let mut subpaths: Vec<(Vec<Point<T>>, bool)> = Vec::new();
+        self.walk(|event| match event {
+            Event::Begin(point) => subpaths.push((alloc::vec![point], false)),
+            Event::Curve(curve) => {
+                if let Some((polyline, _)) = subpaths.last_mut() {
+                    curve.for_each_flattened(tolerance, |point| polyline.push(point))
+                }
+            }
+            Event::End { closed } => {
+                if let Some((_, is_closed)) = subpaths.last_mut() {
+                    *is_closed = closed
+                }
+            }
+        });
+        subpaths
+    }
+
+    /// Total length of all subpaths
+    pub fn length(&self, tolerance: Positive<T>) -> T {
+        let mut length = T::zero();
+        self.for_each_curve(|curve| length = length + curve.arc_length(tolerance));
+        length
+    }
+
+    /// Curve and its parameter at distance `length` along path (clamped to path)
+    fn curve_at_length(&self, length: T, tolerance: Positive<T>) -> Option<(PathCurve<T>, T)> {
+        let mut remaining = length;
+        let mut found = None;
+        let mut last = None;
+        self.for_each_curve(|curve| {
+            if found.is_some() {
+                return;
+            }
+            let curve_length = curve.arc_length(tolerance);
+            if remaining <= curve_length {
+                found = Some((curve, curve.t_at_length(remaining, tolerance)));
+            } else {
+                remaining = remaining - curve_length;
+                last = Some(curve);
+            }
+        });
+        found.or(last.map(|curve| (curve, T::one())))
+    }
+
+    /// Point at distance `length` along path. `None` if path has no curves
+    pub fn point_at_length(&self, length: T, tolerance: Positive<T>) -> Option<Point<T>> {
+        self.curve_at_length(length, tolerance)
+            .map(|(curve, t)| curve.eval(t))
+    }
+
+    /// Unit tangent at distance `length` along path
+    pub fn tangent_at_length(&self, length: T, tolerance: Positive<T>) -> Option<Vector<T>> {
+        self.curve_at_length(length, tolerance)
+            .and_then(|(curve, t)| curve.tangent(t))
+    }
+
+    /// Path with every point transformed by `matrix`.
+    /// Arcs stay exact under affine matrices; with perspective they are replaced by cubic approximations
+    pub fn transformed(&self, matrix: &Matrix<T>) -> Self {
+        let elements: [T; 9] = (*matrix).into();
+        let affine = elements[indices::PERSP0] == T::zero()
+            && elements[indices::PERSP1] == T::zero()
+            && elements[indices::PERSP2] != T::zero();
+        let map = |point: Point<T>| matrix * &point;
+        let mut result = Self::new();
+        let mut current = Point::origin();
+        let mut start = current;
+        for segment in &self.segments {
+            match *segment {
+                PathSegment::MoveTo(to) => {
+                    result.move_to(map(to));
+                    (current, start) = (to, to);
+                }
+                PathSegment::LineTo(to) => {
+                    result.line_to(map(to));
+                    current = to;
+                }
+                PathSegment::QuadTo { ctrl, to } => {
+                    result.quad_to(map(ctrl), map(to));
+                    current = to;
+                }
+                PathSegment::CubicTo { ctrl1, ctrl2, to } => {
+                    result.cubic_to(map(ctrl1), map(ctrl2), map(to));
+                    current = to;
+                }
+                PathSegment::ArcTo {
+                    rx,
+                    ry,
+                    x_rotation,
+                    large_arc,
+                    sweep,
+                    to,
+                } => {
+                    if affine {
+                        let (rx, ry, x_rotation, sweep) =
+                            transformed_ellipse(&elements, rx, ry, x_rotation, sweep);
+                        result.arc_to(rx, ry, x_rotation, large_arc, sweep, map(to));
+                    } else {
+                        let mut cubics = Vec::new();
+                        for_each_arc_curve(
+                            current,
+                            (rx, ry),
+                            x_rotation,
+                            large_arc,
+                            sweep,
+                            to,
+                            |curve| cubics.push(approximate_cubic(curve)),
+                        );
+                        for cubic in cubics {
+                            result.cubic_to(map(cubic.ctrl1), map(cubic.ctrl2), map(cubic.to));
+                        }
+                    }
+                    current = to;
+                }
+                PathSegment::Close => {
+                    result.close();
+                    current = start;
+                }
+            }
+        }
+        result
+    }
+}
+
+/// Elliptical arc resolved to center parametrization (SVG implementation notes F.6.5)
+struct Arc<T> {
+    from: Point<T>,
+    to: Point<T>,
+    center: Point<T>,
+    radii: (T, T),
+    /// cosine and sine of x axis rotation
+    rotor: (T, T),
+    start_angle: T,
+    sweep_angle: T,
+}
+
+impl<T: TrigScalar> Arc<T> {
+    /// `None` if either radius is zero, such arc is a straight line
+    fn from_endpoints(
+        from: Point<T>,
+        radii: (T, T),
+        x_rotation: Angle<T>,
+        large_arc: bool,
+        sweep: bool,
+        to: Point<T>,
+    ) -> Option<Self> {
+        let (mut rx, mut ry) = (radii.0.abs(), radii.1.abs());
+        if rx == T::zero() || ry == T::zero() {
+            return None;
+        }
+        let (cos, sin) = (x_rotation.cos(), x_rotation.sin());
+        let (hx, hy) = ((from - to) / T::two()).into();
+        let x1 = cos * hx + sin * hy;
+        let y1 = cos * hy - sin * hx;
+
+        let lambda = x1.sq() / rx.sq() + y1.sq() / ry.sq();
+        if lambda > T::one() {
+            let scale = lambda.sqrt();
+            (rx, ry) = (rx * scale, ry * scale);
+        }
+        let numerator = rx.sq() * ry.sq() - rx.sq() * y1.sq() - ry.sq() * x1.sq();
+        let denominator = rx.sq() * y1.sq() + ry.sq() * x1.sq();
+        let coefficient = if numerator > T::zero() {
+            (numerator / denominator).sqrt()
+        } else {
+            T::zero()
+        };
+        let coefficient = if large_arc == sweep {
+            -coefficient
+        } else {
+            coefficient
+        };
+        let (cx1, cy1) = (coefficient * rx * y1 / ry, -coefficient * ry * x1 / rx);
+        let mid = Point::from((
+            (*from.x() + *to.x()) / T::two(),
+            (*from.y() + *to.y()) / T::two(),
+        ));
+        let center = mid + Vector::from((cos * cx1 - sin * cy1, sin * cx1 + cos * cy1));
+
+        let u = Vector::from(((x1 - cx1) / rx, (y1 - cy1) / ry));
+        let v = Vector::from(((-x1 - cx1) / rx, (-y1 - cy1) / ry));
+        let mut sweep_angle = u.cross(v).atan2(u.dot(v)).radians();
+        if !sweep && sweep_angle > T::zero() {
+            sweep_angle = sweep_angle - T::two() * T::pi();
+        }
+        Some(Self {
+            from,
+            to,
+            center,
+            radii: (rx, ry),
+            rotor: (cos, sin),
+            start_angle: u.y().atan2(*u.x()).radians(),
+            sweep_angle,
+        })
+    }
+
+    /// Point of ellipse corresponding to point `(x, y)` of unit circle
+    fn map(&self, x: T, y: T) -> Point<T> {
+        let ((rx, ry), (cos, sin)) = (self.radii, self.rotor);
+        let (x, y) = (rx * x, ry * y);
+        self.center + Vector::from((cos * x - sin * y, sin * x + cos * y))
+    }
+
+    /// Calls `f` with exact conics of at most quarter turn each
+    fn for_each_conic<F: FnMut(RationalQuadBezier<T>)>(&self, mut f: F) {
+        let quarter = T::pi() / T::two();
+        let pieces = 1
+            + (1..4)
+                .filter(|i| self.sweep_angle.abs() > quarter * from_u32(*i))
+                .count() as u32;
+        let step = self.sweep_angle / from_u32(pieces);
+        let cos = (step / T::two()).cos();
+        // at least cos(π/4) since pieces are at most quarter turn
+        let Ok(weight) = Positive::new(cos) else {
+            return;
+        };
+        let mut from = self.from;
+        for i in 0..pieces {
+            let start = self.start_angle + step * from_u32(i);
+            let mid = start + step / T::two();
+            let to = if i + 1 == pieces {
+                self.to
+            } else {
+                let end = start + step;
+                self.map(end.cos(), end.sin())
+            };
+            let ctrl = self.map(mid.cos() / cos, mid.sin() / cos);
+            f(RationalQuadBezier::new(from, ctrl, to, weight));
+            from = to;
+        }
+    }
+}
+
+/// Calls `f` with curves drawing SVG arc starting at `from`
+fn for_each_arc_curve<T: TrigScalar, F: FnMut(PathCurve<T>)>(
+    from: Point<T>,
+    radii: (T, T),
+    x_rotation: Angle<T>,
+    large_arc: bool,
+    sweep: bool,
+    to: Point<T>,
+    mut f: F,
+) {
+    if from == to {
+        return;
+    }
+    match Arc::from_endpoints(from, radii, x_rotation, large_arc, sweep, to) {
+        Some(arc) => arc.for_each_conic(|conic| f(PathCurve::Conic(conic))),
+        None => f(PathCurve::Line(LineSegment::new(from, to))),
+    }
+}
+
+/// Cubic close to `curve`, exact for polynomial curves
+fn approximate_cubic<T: Scalar>(curve: PathCurve<T>) -> CubicBezier<T> {
+    match curve {
+        PathCurve::Line(line) => CubicBezier::new(line.from, line.from, line.to, line.to),
+        PathCurve::Quad(quad) => quad.to_cubic(),
+        PathCurve::Cubic(cubic) => cubic,
+        PathCurve::Conic(conic) => {
+            // handle lengths of circular arc approximation: 4w / 3(1 + w) of control polygon legs
+            let weight = conic.weight();
+            let k = from_u32::<T>(4) * weight / (from_u32::<T>(3) * (T::one() + weight));
+            let (from, ctrl, to) = (*conic.from(), *conic.ctrl(), *conic.to());
+            CubicBezier::new(from, from + (ctrl - from) * k, to + (ctrl - to) * k, to)
+        }
+    }
+}
+
+/// Radii, rotation and sweep flag of ellipse `(rx, ry, x_rotation)` transformed by affine matrix.
+/// Uses closed-form singular value decomposition of 2×2 linear part
+fn transformed_ellipse<T: TrigScalar>(
+    elements: &[T; 9],
+    rx: T,
+    ry: T,
+    x_rotation: Angle<T>,
+    sweep: bool,
+) -> (T, T, Angle<T>, bool) {
+    let (a, b) = (elements[indices::SCALE_X], elements[indices::SKEW_X]);
+    let (c, d) = (elements[indices::SKEW_Y], elements[indices::SCALE_Y]);
+    let (cos, sin) = (x_rotation.cos(), x_rotation.sin());
+    // linear part applied to rotated and scaled unit circle
+    let (p, q) = ((a * cos + b * sin) * rx, (b * cos - a * sin) * ry);
+    let (r, s) = ((c * cos + d * sin) * rx, (d * cos - c * sin) * ry);
+    let e = (p + s) / T::two();
+    let f = (p - s) / T::two();
+    let g = (r + q) / T::two();
+    let h = (r - q) / T::two();
+    let q1 = (e.sq() + h.sq()).sqrt();
+    let r1 = (f.sq() + g.sq()).sqrt();
+    let a1 = g.atan2(f).radians();
+    let a2 = h.atan2(e).radians();
+    let determinant = a * d - b * c;
+    (
+        q1 + r1,
+        (q1 - r1).abs(),
+        Angle::from_radians((a1 + a2) / T::two()),
+        if determinant < T::zero() {
+            !sweep
+        } else {
+            sweep
+        },
+    )
+}
+
+/// Error of SVG path data parsing, positions are byte offsets
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParsePathError {
+    /// Character can not start command or argument
+    UnexpectedCharacter(usize),
+    /// Number argument is missing or malformed
+    ExpectedNumber(usize),
+    /// Arc flag other than `0` or `1`
+    ExpectedFlag(usize),
+    /// Path data does not start with move-to command
+    MissingMoveTo,
+}
+
+impl Display for ParsePathError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParsePathError::UnexpectedCharacter(at) => {
+                write!(f, "unexpected character at {}", at)
+            }
+            ParsePathError::ExpectedNumber(at) => write!(f, "expected number at {}", at),
+            ParsePathError::ExpectedFlag(at) => write!(f, "expected flag at {}", at),
+            ParsePathError::MissingMoveTo => f.write_str("path data must start with move-to"),
+        }
+    }
+}
+
+impl Error for ParsePathError {}
+
+/// Cursor over path data following SVG grammar
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.pos).copied()
+    }
+
+    fn skip_separators(&mut self) {
+        while matches!(
+            self.peek(),
+            Some(b' ' | b'\t' | b'\n' | b'\r' | b'\x0c' | b',')
+        ) {
+            self.pos += 1
+        }
+    }
+
+    fn digits(&mut self) -> usize {
+        let start = self.pos;
+        while matches!(self.peek(), Some(b'0'..=b'9')) {
+            self.pos += 1
+        }
+        self.pos - start
+    }
+
+    fn has_number(&mut self) -> bool {
+        self.skip_separators();
+        matches!(self.peek(), Some(b'0'..=b'9' | b'+' | b'-' | b'.'))
+    }
+
+    /// Number is taken greedily, so `1.5.5` is two numbers and `1-2` is two as well
+    fn number<T: FromStr>(&mut self) -> Result<T, ParsePathError> {
+        self.skip_separators();
+        let start = self.pos;
+        if matches!(self.peek(), Some(b'+' | b'-')) {
+            self.pos += 1
+        }
+        let mut digits = self.digits();
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            digits += self.digits();
+        }
+        if digits == 0 {
+            self.pos = start;
+            return Err(ParsePathError::ExpectedNumber(start));
+        }
+        if matches!(self.peek(), Some(b'e' | b'E')) {
+            let mark = self.pos;
+            self.pos += 1;
+            if matches!(self.peek(), Some(b'+' | b'-')) {
+                self.pos += 1
+            }
+            if self.digits() == 0 {
+                self.pos = mark
+            }
+        }
+        self.text[start..self.pos]
+            .parse()
+            .map_err(|_| ParsePathError::ExpectedNumber(start))
+    }
+
+    fn point<T: FromStr>(&mut self) -> Result<Point<T>, ParsePathError> {
+        Ok(Point::from((self.number()?, self.number()?)))
+    }
+
+    fn flag(&mut self) -> Result<bool, ParsePathError> {
+        self.skip_separators();
+        let flag = match self.peek() {
+            Some(b'0') => false,
+            Some(b'1') => true,
+            _ => return Err(ParsePathError::ExpectedFlag(self.pos)),
+        };
+        self.pos += 1;
+        Ok(flag)
+    }
+}
+
+/// Parses SVG path data: all commands in absolute and relative form, implicit repetition and smooth curve reflection
+impl<T: TrigScalar + FromStr> FromStr for Path<T> {
+    type Err = ParsePathError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { text: s, pos: 0 };
+        let mut path = Path::new();
+        let mut current = Point::origin();
+        let mut start = current;
+        // control points to reflect by following smooth curve commands
+        let mut cubic_ctrl: Option<Point<T>> = None;
+        let mut quad_ctrl: Option<Point<T>> = None;
+        let mut command: Option<u8> = None;
+        parser.skip_separators();
+        while let Some(byte) = parser.peek() {
+            let at = parser.pos;
+            let next = if byte.is_ascii_alphabetic() {
+                parser.pos += 1;
+                byte
+            } else {
+                match command {
+                    Some(b'M') if parser.has_number() => b'L',
+                    Some(b'm') if parser.has_number() => b'l',
+                    Some(command) if parser.has_number() => command,
+                    _ => return Err(ParsePathError::UnexpectedCharacter(at)),
+                }
+            };
+            if path.is_empty() && !matches!(next, b'M' | b'm') {
+                return Err(ParsePathError::MissingMoveTo);
+            }
+            let origin = if next.is_ascii_lowercase() {
+                current - Point::origin()
+            } else {
+                Vector::from((T::zero(), T::zero()))
+            };
+            let (mut next_cubic, mut next_quad) = (None, None);
+            match next.to_ascii_uppercase() {
+                b'M' => {
+                    let to = parser.point()? + origin;
+                    path.move_to(to);
+                    (current, start) = (to, to);
+                }
+                b'L' => {
+                    current = parser.point()? + origin;
+                    path.line_to(current);
+                }
+                b'H' => {
+                    current = Point::from((parser.number::<T>()? + *origin.x(), *current.y()));
+                    path.line_to(current);
+                }
+                b'V' => {
+                    current = Point::from((*current.x(), parser.number::<T>()? + *origin.y()));
+                    path.line_to(current);
+                }
+                b'C' | b'S' => {
+                    let ctrl1 = if next.eq_ignore_ascii_case(&b'C') {
+                        parser.point()? + origin
+                    } else {
+                        cubic_ctrl.map_or(current, |ctrl| current + (current - ctrl))
+                    };
+                    let ctrl2 = parser.point()? + origin;
+                    current = parser.point()? + origin;
+                    path.cubic_to(ctrl1, ctrl2, current);
+                    next_cubic = Some(ctrl2);
+                }
+                b'Q' | b'T' => {
+                    let ctrl = if next.eq_ignore_ascii_case(&b'Q') {
+                        parser.point()? + origin
+                    } else {
+                        quad_ctrl.map_or(current, |ctrl| current + (current - ctrl))
+                    };
+                    current = parser.point()? + origin;
+                    path.quad_to(ctrl, current);
+                    next_quad = Some(ctrl);
+                }
+                b'A' => {
+                    let rx = parser.number()?;
+                    let ry = parser.number()?;
+                    let x_rotation = Angle::from_degrees(parser.number::<T>()?);
+                    let large_arc = parser.flag()?;
+                    let sweep = parser.flag()?;
+                    current = parser.point()? + origin;
+                    path.arc_to(rx, ry, x_rotation, large_arc, sweep, current);
+                }
+                b'Z' => {
+                    path.close();
+                    current = start;
+                }
+                _ => return Err(ParsePathError::UnexpectedCharacter(at)),
+            }
+            (cubic_ctrl, quad_ctrl) = (next_cubic, next_quad);
+            // close-path takes no arguments so it can not be repeated
+            command = if next.eq_ignore_ascii_case(&b'Z') {
+                None
+            } else {
+                Some(next)
+            };
+            parser.skip_separators();
+        }
+        Ok(path)
+    }
+}
+
+/// Formats as SVG path data with absolute commands, arc rotation in degrees
+impl<T: TrigScalar + Display> Display for Path<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let point = |p: &Point<T>| (*p.x(), *p.y());
+        for (i, segment) in self.segments.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?
+            }
+            match segment {
+                PathSegment::MoveTo(to) => {
+                    let (x, y) = point(to);
+                    write!(f, "M{} {}", x, y)?
+                }
+                PathSegment::LineTo(to) => {
+                    let (x, y) = point(to);
+                    write!(f, "L{} {}", x, y)?
+                }
+                PathSegment::QuadTo { ctrl, to } => {
+                    let ((x1, y1), (x, y)) = (point(ctrl), point(to));
+                    write!(f, "Q{} {} {} {}", x1, y1, x, y)?
+                }
+                PathSegment::CubicTo { ctrl1, ctrl2, to } => {
+                    let ((x1, y1), (x2, y2), (x, y)) = (point(ctrl1), point(ctrl2), point(to));
+                    write!(f, "C{} {} {} {} {} {}", x1, y1, x2, y2, x, y)?
+                }
+                PathSegment::ArcTo {
+                    rx,
+                    ry,
+                    x_rotation,
+                    large_arc,
+                    sweep,
+                    to,
+                } => {
+                    let (x, y) = point(to);
+                    write!(
+                        f,
+                        "A{} {} {} {} {} {} {}",
+                        rx,
+                        ry,
+                        x_rotation.degrees(),
+                        *large_arc as u8,
+                        *sweep as u8,
+                        x,
+                        y
+                    )?
+                }
+                PathSegment::Close => f.write_str("Z")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[cfg(any(feature = "std", feature = "libm"))]
+mod tests {
+    use super::{ParsePathError, Path, PathSegment};
+    use crate::math::{Angle, Point, Positive};
+    use alloc::string::ToString;
+
+    #[test]
+    fn parse_relative_and_implicit_commands() {
+        let path: Path<f64> = "m10 20 5-5h5v5l-5 5,-5-5z M0 0".parse().unwrap();
+        let p = |x, y| Point::from((x, y));
+        assert_eq!(
+            path.segments(),
+            &[
+                PathSegment::MoveTo(p(10., 20.)),
+                PathSegment::LineTo(p(15., 15.)),
+                PathSegment::LineTo(p(20., 15.)),
+                PathSegment::LineTo(p(20., 20.)),
+                PathSegment::LineTo(p(15., 25.)),
+                PathSegment::LineTo(p(10., 20.)),
+                PathSegment::Close,
+                PathSegment::MoveTo(p(0., 0.)),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_smooth_curves_and_packed_flags() {
+        let path: Path<f64> = "M0 0C0 1 1 1 1 0S2-1 2 0Q3 1 4 0T6 0a1 1 0 1010 0"
+            .parse()
+            .unwrap();
+        let p = |x, y| Point::from((x, y));
+        let segments = path.segments();
+        assert_eq!(
+            segments[2],
+            PathSegment::CubicTo {
+                ctrl1: p(1., -1.),
+                ctrl2: p(2., -1.),
+                to: p(2., 0.)
+            }
+        );
+        assert_eq!(
+            segments[4],
+            PathSegment::QuadTo {
+                ctrl: p(5., -1.),
+                to: p(6., 0.)
+            }
+        );
+        assert_eq!(
+            segments[5],
+            PathSegment::ArcTo {
+                rx: 1.,
+                ry: 1.,
+                x_rotation: Angle::from_radians(0.),
+                large_arc: true,
+                sweep: false,
+                to: p(16., 0.)
+            }
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            "L1 2".parse::<Path<f64>>(),
+            Err(ParsePathError::MissingMoveTo)
+        );
+        assert_eq!(
+            "M1 2 X".parse::<Path<f64>>(),
+            Err(ParsePathError::UnexpectedCharacter(5))
+        );
+        assert_eq!(
+            "M1 2 L3".parse::<Path<f64>>(),
+            Err(ParsePathError::ExpectedNumber(7))
+        );
+        assert_eq!(
+            "M0 0 A1 1 0 2 0 1 1".parse::<Path<f64>>(),
+            Err(ParsePathError::ExpectedFlag(12))
+        );
+        assert_eq!(
+            "M0 0 Z 1".parse::<Path<f64>>(),
+            Err(ParsePathError::UnexpectedCharacter(7))
+        );
+        assert!(" ".parse::<Path<f64>>().unwrap().is_empty());
+    }
+
+    #[test]
+    fn display_round_trip() {
+        let mut path = Path::new();
+        path.move_to((1., 2.).into())
+            .line_to((3., 4.5).into())
+            .quad_to((5., 6.).into(), (7., 8.).into())
+            .cubic_to((-1., 0.).into(), (0., -1.).into(), (2., 2.).into())
+            .arc_to(
+                3.,
+                2.,
+                Angle::from_degrees(30.),
+                false,
+                true,
+                (10., 0.).into(),
+            )
+            .close();
+        let text = path.to_string();
+        assert_eq!(
+            text,
+            "M1 2 L3 4.5 Q5 6 7 8 C-1 0 0 -1 2 2 A3 2 30 0 1 10 0 Z"
+        );
+        let parsed: Path<f64> = text.parse().unwrap();
+        assert_eq!(parsed.segments()[..4], path.segments()[..4]);
+        assert_eq!(parsed.to_string(), text);
+    }
+
+    #[test]
+    fn measure_polygon() {
+        use approx::assert_abs_diff_eq;
+
+        let path: Path<f64> = "M0 0 H10 V10 H0 Z".parse().unwrap();
+        let tolerance = Positive::new(1e-9).unwrap();
+        assert_abs_diff_eq!(path.length(tolerance), 40., epsilon = 1e-9);
+        let point = path.point_at_length(15., tolerance).unwrap();
+        assert_abs_diff_eq!(*point.x(), 10., epsilon = 1e-9);
+        assert_abs_diff_eq!(*point.y(), 5., epsilon = 1e-9);
+        let tangent = path.tangent_at_length(35., tolerance).unwrap();
+        assert_abs_diff_eq!(*tangent.y(), -1., epsilon = 1e-9);
+        assert_eq!(path.point_at_length(100., tolerance), Some(Point::origin()));
+
+        let polylines = path.flattened(tolerance);
+        assert_eq!(polylines.len(), 1);
+        assert_eq!(polylines[0].len(), 5);
+        assert_eq!(polylines[0][4], Point::origin());
+    }
+
+    #[test]
+    fn arc_is_exact() {
+        use approx::assert_abs_diff_eq;
+        use core::f64::consts::PI;
+
+        // radii are scaled up to reach end point
+        let path: Path<f64> = "M0 0 A0.5 0.5 0 0 1 2 0".parse().unwrap();
+        let tolerance = Positive::new(1e-9).unwrap();
+        assert_abs_diff_eq!(path.length(tolerance), PI, epsilon = 1e-7);
+        let mut count = 0;
+        path.for_each_curve(|curve| {
+            count += 1;
+            for i in 0..=10 {
+                let p = curve.eval(i as f64 / 10.);
+                assert_abs_diff_eq!(p.distance((1., 0.).into()), 1., epsilon = 1e-12);
+            }
+        });
+        assert_eq!(count, 2);
+        let rect = path.bounding_rect().unwrap();
+        assert_abs_diff_eq!(rect.top(), -1., epsilon = 1e-12);
+        assert_abs_diff_eq!(rect.bottom(), 0., epsilon = 1e-12);
+        assert_abs_diff_eq!(rect.right(), 2., epsilon = 1e-12);
+
+        let large: Path<f64> = "M0 0 A1 1 0 1 0 1 1".parse().unwrap();
+        assert_abs_diff_eq!(large.length(tolerance), 1.5 * PI, epsilon = 1e-7);
+    }
+
+    #[test]
+    fn transform_keeps_arcs() {
+        use crate::math::{Matrix, Vector};
+        use approx::assert_abs_diff_eq;
+
+        let path: Path<f64> = "M0 0 A1 1 0 0 1 2 0 L2 3".parse().unwrap();
+        let matrix = &Matrix::rotate_about(Point::origin(), Angle::from_degrees(30.))
+            * &Matrix::scale(2., 1.);
+        let transformed = path.transformed(&matrix);
+        assert!(matches!(
+            transformed.segments()[1],
+            PathSegment::ArcTo { sweep: true, .. }
+        ));
+
+        let tolerance = Positive::new(1e-9).unwrap();
+        let mut expected = alloc::vec::Vec::new();
+        path.for_each_curve(|curve| {
+            expected.extend((0..=8).map(|i| &matrix * &curve.eval(i as f64 / 8.)))
+        });
+        let mut actual = alloc::vec::Vec::new();
+        transformed
+            .for_each_curve(|curve| actual.extend((0..=8).map(|i| curve.eval(i as f64 / 8.))));
+        for point in &expected[..18] {
+            let distance = actual[..18]
+                .iter()
+                .map(|p| p.distance(*point))
+                .fold(f64::MAX, f64::min);
+            assert!(distance < 0.2);
+        }
+        // every sample of transformed arc lies on transformed ellipse
+        let inverse = &Matrix::scale(0.5, 1.)
+            * &Matrix::rotate_about(Point::origin(), Angle::from_degrees(-30.));
+        for point in &actual[..18] {
+            let p = &inverse * point;
+            assert_abs_diff_eq!(p.distance((1., 0.).into()), 1., epsilon = 1e-9);
+        }
+
+        let mirrored = path.transformed(&Matrix::scale(1., -1.));
+        assert!(matches!(
+            mirrored.segments()[1],
+            PathSegment::ArcTo { sweep: false, .. }
+        ));
+        let shifted = path.transformed(&Matrix::translate(Vector::from((1., 1.))));
+        let rect = shifted.bounding_rect().unwrap();
+        assert_abs_diff_eq!(rect.top(), 0., epsilon = 1e-9);
+        assert_abs_diff_eq!(rect.bottom(), 4., epsilon = 1e-9);
+        assert_abs_diff_eq!(
+            shifted.length(tolerance),
+            path.length(tolerance),
+            epsilon = 1e-6
+        );
+    }
+}
@@ -0,0 +1,322 @@
+use super::{Scalar, max, segment_distance_sqr, segment_parameter};
+use crate::math::{NonNeg, Point, from_u32, lerp, ratio};
+use alloc::{collections::BinaryHeap, vec::Vec};
+use core::cmp::Ordering;
+
+pub fn polyline_length<T: Scalar>(points: &[Point<T>]) -> T {
+    points
+        .windows(2)
+        .fold(T::zero(), |length, pair| length + (pair[1] - pair[0]).len())
+}
+
+/// Segment index, parameter on that segment and point of polyline closest to `point`.
+/// Single point polyline gives index 0. `None` for empty polyline
+pub fn closest_point_on_polyline<T: Scalar>(
+    points: &[Point<T>],
+    point: Point<T>,
+) -> Option<(usize, T, Point<T>)> {
+    if points.len() == 1 {
+        return Some((0, T::zero(), points[0]));
+    }
+    points
+        .windows(2)
+        .enumerate()
+        .map(|(i, pair)| {
+            let t = segment_parameter(point, pair[0], pair[1]);
+            let closest = lerp(pair[0], pair[1], t);
+            ((i, t, closest), (point - closest).len_sqr())
+        })
+        .fold(None, |best: Option<(_, T)>, candidate| match best {
+            Some(best) if best.1 <= candidate.1 => Some(best),
+            _ => Some(candidate),
+        })
+        .map(|(closest, _)| closest)
+}
+
+/// Ramer–Douglas–Peucker simplification: keeps end points and every point deviating more than `epsilon` from simplified polyline
+pub fn simplify_rdp<T: Scalar>(points: &[Point<T>], epsilon: NonNeg<T>) -> Vec<Point<T>> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+    let epsilon_sqr = epsilon.into_inner().sq();
+    let mut keep = alloc::vec![false; points.len()];
+    let last = points.len() - 1;
+    (keep[0], keep[last]) = (true, true);
+    let mut ranges = alloc::vec![(0, last)];
+    while let Some((first, last)) = ranges.pop() {
+        let farthest = (first + 1..last)
+            .map(|i| {
+                (
+                    i,
+                    segment_distance_sqr(points[i], points[first], points[last]),
+                )
+            })
+            .fold(None, |best: Option<(usize, T)>, candidate| match best {
+                Some(best) if best.1 >= candidate.1 => Some(best),
+                _ => Some(candidate),
+            });
+        if let Some((i, distance_sqr)) = farthest {
+            if distance_sqr > epsilon_sqr {
+                keep[i] = true;
+                ranges.extend([(first, i), (i, last)]);
+            }
+        }
+    }
+    points
+        .iter()
+        .zip(keep)
+        .filter_map(|(point, keep)| keep.then_some(*point))
+        .collect()
+}
+
+/// Removal candidate ordered so that `BinaryHeap` pops smallest area first
+struct Candidate<T> {
+    area: T,
+    index: usize,
+}
+
+impl<T: PartialOrd> PartialEq for Candidate<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: PartialOrd> Eq for Candidate<T> {}
+
+impl<T: PartialOrd> PartialOrd for Candidate<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: PartialOrd> Ord for Candidate<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .area
+            .partial_cmp(&self.area)
+            .unwrap_or(Ordering::Equal)
+            .then(other.index.cmp(&self.index))
+    }
+}
+
+/// Visvalingam–Whyatt simplification: repeatedly removes point forming triangle of smallest area with its neighbours
+/// until every remaining one has area at least `min_area`. End points are kept
+pub fn simplify_visvalingam<T: Scalar>(points: &[Point<T>], min_area: NonNeg<T>) -> Vec<Point<T>> {
+    let count = points.len();
+    if count < 3 {
+        return points.to_vec();
+    }
+    let min_area = min_area.into_inner();
+    let mut previous: Vec<usize> = (0..count).map(|i| i.saturating_sub(1)).collect();
+    let mut next: Vec<usize> = (1..=count).collect();
+    let mut removed = alloc::vec![false; count];
+    let area = |a: usize, b: usize, c: usize| {
+        ((points[b] - points[a]).cross(points[c] - points[a]) / T::two()).abs()
+    };
+    let mut areas: Vec<T> = (0..count)
+        .map(|i| {
+            if i == 0 || i == count - 1 {
+                T::zero()
+            } else {
+                area(i - 1, i, i + 1)
+            }
+        })
+        .collect();
+    let mut heap: BinaryHeap<Candidate<T>> = (1..count - 1)
+        .map(|index| Candidate {
+            area: areas[index],
+            index,
+        })
+        .collect();
+    while let Some(Candidate {
+        area: smallest,
+        index,
+    }) = heap.pop()
+    {
+        // skip entries made stale by neighbour removal
+        if removed[index] || smallest != areas[index] {
+            continue;
+        }
+        if smallest >= min_area {
+            break;
+        }
+        removed[index] = true;
+        let (before, after) = (previous[index], next[index]);
+        next[before] = after;
+        previous[after] = before;
+        for neighbour in [before, after] {
+            if neighbour != 0 && neighbour != count - 1 {
+                // area never decreases below removed one, so removal order follows significance
+                areas[neighbour] = max(
+                    area(previous[neighbour], neighbour, next[neighbour]),
+                    smallest,
+                );
+                heap.push(Candidate {
+                    area: areas[neighbour],
+                    index: neighbour,
+                });
+            }
+        }
+    }
+    points
+        .iter()
+        .zip(removed)
+        .filter_map(|(point, removed)| (!removed).then_some(*point))
+        .collect()
+}
+
+/// `count` points evenly spaced by arc length, including both end points
+pub fn resample_polyline<T: Scalar>(points: &[Point<T>], count: usize) -> Vec<Point<T>> {
+    let (Some(first), Some(last)) = (points.first(), points.last()) else {
+        return Vec::new();
+    };
+    if count < 2 || points.len() < 2 {
+        return alloc::vec![*first; count];
+    }
+    let step = polyline_length(points) / from_u32(count as u32 - 1);
+    let mut result = Vec::with_capacity(count);
+    result.push(*first);
+    let (mut segment, mut travelled) = (0, T::zero());
+    let mut target = T::zero();
+    for _ in 1..count - 1 {
+        target = target + step;
+        let mut length = (points[segment + 1] - points[segment]).len();
+        while segment + 2 < points.len() && travelled + length < target {
+            travelled = travelled + length;
+            segment += 1;
+            length = (points[segment + 1] - points[segment]).len();
+        }
+        let t = if length > T::zero() {
+            (target - travelled) / length
+        } else {
+            T::zero()
+        };
+        result.push(lerp(points[segment], points[segment + 1], t));
+    }
+    result.push(*last);
+    result
+}
+
+/// Chaikin corner cutting applied `iterations` times. Every iteration replaces segment with its 1/4 and 3/4 points.
+/// End points of open polyline are kept, closed one is treated as cycle
+pub fn chaikin_smooth<T: Scalar>(
+    points: &[Point<T>],
+    closed: bool,
+    iterations: u32,
+) -> Vec<Point<T>> {
+    let mut result = points.to_vec();
+    let (quarter, three_quarters) = (ratio::<T>(1, 4), ratio::<T>(3, 4));
+    for _ in 0..iterations {
+        let count = result.len();
+        if count < 3 {
+            break;
+        }
+        let segments = if closed { count } else { count - 1 };
+        let mut smoothed = Vec::with_capacity(2 * segments + 2);
+        if !closed {
+            smoothed.push(result[0]);
+        }
+        for i in 0..segments {
+            let (a, b) = (result[i], result[(i + 1) % count]);
+            if closed || i > 0 {
+                smoothed.push(lerp(a, b, quarter));
+            }
+            if closed || i + 1 < segments {
+                smoothed.push(lerp(a, b, three_quarters));
+            }
+        }
+        if !closed {
+            smoothed.push(result[count - 1]);
+        }
+        result = smoothed;
+    }
+    result
+}
+
+#[cfg(test)]
+#[cfg(any(feature = "std", feature = "libm"))]
+mod tests {
+    use super::{
+        chaikin_smooth, closest_point_on_polyline, polyline_length, resample_polyline,
+        simplify_rdp, simplify_visvalingam,
+    };
+    use crate::math::{NonNeg, Point};
+    use alloc::vec::Vec;
+
+    fn points(coordinates: &[(f64, f64)]) -> Vec<Point<f64>> {
+        coordinates.iter().map(|p| Point::from(*p)).collect()
+    }
+
+    #[test]
+    fn length_closest_and_resample() {
+        use approx::assert_abs_diff_eq;
+
+        let line = points(&[(0., 0.), (3., 4.), (3., 10.)]);
+        assert_eq!(polyline_length(&line), 11.);
+        assert_eq!(
+            closest_point_on_polyline(&line, (5., 7.).into()),
+            Some((1, 0.5, Point::from((3., 7.))))
+        );
+        assert_eq!(
+            closest_point_on_polyline(&line, (-1., -1.).into()),
+            Some((0, 0., Point::origin()))
+        );
+        assert_eq!(closest_point_on_polyline::<f64>(&[], Point::origin()), None);
+
+        let resampled = resample_polyline(&line, 12);
+        assert_eq!(resampled.len(), 12);
+        for pair in resampled.windows(2) {
+            assert_abs_diff_eq!(pair[0].distance(pair[1]), 1., epsilon = 1e-9);
+        }
+        assert_eq!(resampled[11], Point::from((3., 10.)));
+        assert_eq!(resample_polyline(&line, 1), points(&[(0., 0.)]));
+    }
+
+    #[test]
+    fn simplification() {
+        let noisy = points(&[
+            (0., 0.),
+            (1., 0.1),
+            (2., -0.1),
+            (3., 5.),
+            (4., 6.),
+            (5., 7.1),
+            (6., 8.),
+            (7., 9.),
+        ]);
+        let tolerance = NonNeg::new(0.5).unwrap();
+        assert_eq!(
+            simplify_rdp(&noisy, tolerance),
+            points(&[(0., 0.), (2., -0.1), (3., 5.), (7., 9.)])
+        );
+        assert_eq!(simplify_rdp(&noisy, NonNeg::new(0.).unwrap()).len(), 8);
+
+        let simplified = simplify_visvalingam(&noisy, tolerance);
+        assert_eq!(
+            simplified,
+            points(&[(0., 0.), (2., -0.1), (3., 5.), (7., 9.)])
+        );
+        assert_eq!(
+            simplify_visvalingam(&noisy, NonNeg::new(100.).unwrap()),
+            points(&[(0., 0.), (7., 9.)])
+        );
+    }
+
+    #[test]
+    fn chaikin() {
+        let corner = points(&[(0., 0.), (4., 0.), (4., 4.)]);
+        assert_eq!(
+            chaikin_smooth(&corner, false, 1),
+            points(&[(0., 0.), (3., 0.), (4., 1.), (4., 4.)])
+        );
+        let square = points(&[(0., 0.), (4., 0.), (4., 4.), (0., 4.)]);
+        let smoothed = chaikin_smooth(&square, true, 2);
+        assert_eq!(smoothed.len(), 16);
+        // corners are cut off
+        assert!(
+            smoothed
+                .iter()
+                .all(|p| (p.x() - 2.).abs() + (p.y() - 2.).abs() < 4.)
+        );
+    }
+}
@@ -0,0 +1,709 @@
+use crate::math::{Point, Size, Vector};
+use alloc::vec::Vec;
+
+fn point(x: i32, y: i32) -> Point<i32> {
+    Point::from((x, y))
+}
+
+/// Cells of line between two cells with single cell per step along major axis (integer DDA), both ends included
+#[derive(Debug, Clone)]
+pub struct BresenhamLine {
+    current: Point<i32>,
+    end: Point<i32>,
+    /// 64 bit so that far apart cells do not overflow
+    delta: (i64, i64),
+    step: (i32, i32),
+    error: i64,
+    done: bool,
+}
+
+impl BresenhamLine {
+    pub fn new(from: Point<i32>, to: Point<i32>) -> Self {
+        let dx = *to.x() as i64 - *from.x() as i64;
+        let dy = *to.y() as i64 - *from.y() as i64;
+        Self {
+            current: from,
+            end: to,
+            delta: (dx.abs(), -dy.abs()),
+            step: (dx.signum() as i32, dy.signum() as i32),
+            error: dx.abs() - dy.abs(),
+            done: false,
+        }
+    }
+}
+
+impl Iterator for BresenhamLine {
+    type Item = Point<i32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let result = self.current;
+        if result == self.end {
+            self.done = true;
+            return Some(result);
+        }
+        let (mut x, mut y) = result.into();
+        let doubled = 2 * self.error;
+        if doubled >= self.delta.1 {
+            self.error += self.delta.1;
+            x += self.step.0;
+        }
+        if doubled <= self.delta.0 {
+            self.error += self.delta.0;
+            y += self.step.1;
+        }
+        self.current = point(x, y);
+        Some(result)
+    }
+}
+
+/// Every cell touched by segment between centers of two cells.
+/// Where segment passes exactly through cell corner both side cells are included
+#[derive(Debug, Clone)]
+pub struct SupercoverLine {
+    current: Point<i32>,
+    steps: (i64, i64),
+    taken: (i64, i64),
+    sign: (i32, i32),
+    /// cells queued by corner crossing
+    pending: [Point<i32>; 2],
+    pending_len: usize,
+    started: bool,
+}
+
+impl SupercoverLine {
+    pub fn new(from: Point<i32>, to: Point<i32>) -> Self {
+        let dx = *to.x() as i64 - *from.x() as i64;
+        let dy = *to.y() as i64 - *from.y() as i64;
+        Self {
+            current: from,
+            steps: (dx.abs(), dy.abs()),
+            taken: (0, 0),
+            sign: (dx.signum() as i32, dy.signum() as i32),
+            pending: [from; 2],
+            pending_len: 0,
+            started: false,
+        }
+    }
+}
+
+impl Iterator for SupercoverLine {
+    type Item = Point<i32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.started = true;
+            return Some(self.current);
+        }
+        if self.pending_len > 0 {
+            self.pending_len -= 1;
+            return Some(self.pending[self.pending_len]);
+        }
+        let ((nx, ny), (ix, iy)) = (self.steps, self.taken);
+        if ix >= nx && iy >= ny {
+            return None;
+        }
+        let (x, y) = self.current.into();
+        // compare crossing of next vertical and horizontal cell border
+        let decision = (1 + 2 * ix as i128) * ny as i128 - (1 + 2 * iy as i128) * nx as i128;
+        if decision == 0 {
+            self.taken = (ix + 1, iy + 1);
+            self.current = point(x + self.sign.0, y + self.sign.1);
+            self.pending = [self.current, point(x, y + self.sign.1)];
+            self.pending_len = 2;
+            return Some(point(x + self.sign.0, y));
+        } else if decision < 0 {
+            self.taken.0 += 1;
+            self.current = point(x + self.sign.0, y);
+        } else {
+            self.taken.1 += 1;
+            self.current = point(x, y + self.sign.1);
+        }
+        Some(self.current)
+    }
+}
+
+/// Index of first of up to `N` symmetric points which is not repeated among earlier ones, starting at `from`
+fn next_distinct<const N: usize>(points: &[Point<i32>; N], from: usize) -> Option<usize> {
+    (from..N).find(|&i| !points[..i].contains(&points[i]))
+}
+
+/// Outline of circle rasterized with midpoint algorithm, every cell once
+#[derive(Debug, Clone)]
+pub struct MidpointCircle {
+    center: Point<i32>,
+    x: i32,
+    y: i32,
+    error: i32,
+    octant: usize,
+}
+
+impl MidpointCircle {
+    pub fn new(center: Point<i32>, radius: u32) -> Self {
+        let radius = radius as i32;
+        Self {
+            center,
+            x: radius,
+            y: 0,
+            error: 1 - radius,
+            octant: 0,
+        }
+    }
+
+    fn octants(&self) -> [Point<i32>; 8] {
+        let (cx, cy) = self.center.into();
+        let (x, y) = (self.x, self.y);
+        [
+            point(cx + x, cy + y),
+            point(cx + y, cy + x),
+            point(cx - y, cy + x),
+            point(cx - x, cy + y),
+            point(cx - x, cy - y),
+            point(cx - y, cy - x),
+            point(cx + y, cy - x),
+            point(cx + x, cy - y),
+        ]
+    }
+}
+
+impl Iterator for MidpointCircle {
+    type Item = Point<i32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.x < self.y {
+                return None;
+            }
+            let points = self.octants();
+            if let Some(i) = next_distinct(&points, self.octant) {
+                self.octant = i + 1;
+                return Some(points[i]);
+            }
+            self.octant = 0;
+            self.y += 1;
+            if self.error < 0 {
+                self.error += 2 * self.y + 1;
+            } else {
+                self.x -= 1;
+                self.error += 2 * (self.y - self.x) + 1;
+            }
+        }
+    }
+}
+
+/// Outline of axis aligned ellipse rasterized with midpoint algorithm, every cell once
+#[derive(Debug, Clone)]
+pub struct MidpointEllipse {
+    center: Point<i32>,
+    /// horizontal radius
+    a: i64,
+    /// squared radii
+    a2: i64,
+    b2: i64,
+    x: i64,
+    y: i64,
+    /// decision variable scaled by 4 to stay integer
+    decision: i64,
+    /// first region has slope above -1, second one below
+    second_region: bool,
+    quadrant: usize,
+}
+
+impl MidpointEllipse {
+    pub fn new(center: Point<i32>, radii: Size<u32>) -> Self {
+        let (a, b) = radii.into();
+        let (a, b) = (a as i64, b as i64);
+        let (a2, b2) = (a * a, b * b);
+        let mut ellipse = Self {
+            center,
+            a,
+            a2,
+            b2,
+            x: 0,
+            y: b,
+            decision: 4 * b2 - 4 * a2 * b + a2,
+            second_region: false,
+            quadrant: 0,
+        };
+        // zero width ellipse has no first region, otherwise x would step outside of it
+        if a == 0 {
+            ellipse.enter_second_region();
+        }
+        ellipse
+    }
+
+    fn enter_second_region(&mut self) {
+        let (x, y) = (self.x, self.y);
+        self.second_region = true;
+        self.decision = self.b2 * (2 * x + 1) * (2 * x + 1) + 4 * self.a2 * (y - 1) * (y - 1)
+            - 4 * self.a2 * self.b2;
+    }
+
+    fn quadrants(&self) -> [Point<i32>; 4] {
+        let (cx, cy) = self.center.into();
+        let (x, y) = (self.x as i32, self.y as i32);
+        [
+            point(cx + x, cy + y),
+            point(cx - x, cy + y),
+            point(cx - x, cy - y),
+            point(cx + x, cy - y),
+        ]
+    }
+
+    fn advance(&mut self) {
+        let (a2, b2) = (self.a2, self.b2);
+        // flat ellipse may reach y = 0 in first region, rest of horizontal extent is a straight run
+        if self.y == 0 {
+            if self.x < self.a {
+                self.x += 1;
+            } else {
+                self.y = -1;
+            }
+        } else if !self.second_region {
+            if self.decision < 0 {
+                self.x += 1;
+                self.decision += 4 * b2 * (2 * self.x + 1);
+            } else {
+                self.x += 1;
+                self.y -= 1;
+                self.decision += 4 * (b2 * (2 * self.x + 1) - 2 * a2 * self.y);
+            }
+            if b2 * self.x >= a2 * self.y {
+                self.enter_second_region();
+            }
+        } else {
+            if self.decision > 0 {
+                self.y -= 1;
+                self.decision += 4 * a2 * (1 - 2 * self.y);
+            } else {
+                self.y -= 1;
+                self.x += 1;
+                self.decision += 4 * (2 * b2 * self.x + a2 * (1 - 2 * self.y));
+            }
+        }
+    }
+}
+
+impl Iterator for MidpointEllipse {
+    type Item = Point<i32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.y < 0 {
+                return None;
+            }
+            let points = self.quadrants();
+            if let Some(i) = next_distinct(&points, self.quadrant) {
+                self.quadrant = i + 1;
+                return Some(points[i]);
+            }
+            self.quadrant = 0;
+            self.advance();
+        }
+    }
+}
+
+/// Cells with centers inside polygon (even-odd rule), row by row from top.
+/// Polygon is closed implicitly. Crossings are searched on every row instead of kept in edge table, so nothing is allocated
+#[derive(Debug, Clone)]
+pub struct PolygonFill<'a> {
+    polygon: &'a [Point<i32>],
+    y: i32,
+    end_y: i32,
+    /// last consumed crossing as (first covered x, edge index)
+    cursor: Option<(i32, usize)>,
+    span: (i32, i32),
+}
+
+impl<'a> PolygonFill<'a> {
+    pub fn new(polygon: &'a [Point<i32>]) -> Self {
+        let ys = polygon.iter().map(|p| *p.y());
+        let (y, end_y) = (ys.clone().min().unwrap_or(0), ys.max().unwrap_or(0));
+        Self {
+            polygon,
+            y,
+            end_y,
+            cursor: None,
+            span: (0, 0),
+        }
+    }
+
+    /// First cell at or right of crossing of edge with center line of current row
+    fn crossing(&self, edge: usize) -> Option<i32> {
+        let a = self.polygon[edge];
+        let b = self.polygon[(edge + 1) % self.polygon.len()];
+        let (a, b) = if a.y() <= b.y() { (a, b) } else { (b, a) };
+        let (x0, y0, x1, y1) = (
+            *a.x() as i128,
+            *a.y() as i128,
+            *b.x() as i128,
+            *b.y() as i128,
+        );
+        let y = self.y as i128;
+        if !(y0 <= y && y < y1) {
+            return None;
+        }
+        // crossing at row center y + 1/2 is numerator / denominator
+        let denominator = 2 * (y1 - y0);
+        let numerator = x0 * denominator + (x1 - x0) * (2 * (y - y0) + 1);
+        // smallest x with x + 1/2 >= crossing
+        let (top, bottom) = (2 * numerator - denominator, 2 * denominator);
+        Some(-((-top).div_euclid(bottom)) as i32)
+    }
+
+    /// Next crossing of current row after cursor in (x, edge) order
+    fn next_crossing(&self) -> Option<(i32, usize)> {
+        (0..self.polygon.len())
+            .filter_map(|edge| self.crossing(edge).map(|x| (x, edge)))
+            .filter(|crossing| self.cursor.is_none_or(|cursor| *crossing > cursor))
+            .min()
+    }
+}
+
+impl Iterator for PolygonFill<'_> {
+    type Item = Point<i32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.span.0 < self.span.1 {
+                self.span.0 += 1;
+                return Some(point(self.span.0 - 1, self.y));
+            }
+            if self.y >= self.end_y {
+                return None;
+            }
+            // spans lie between pairs of consecutive crossings
+            match self.next_crossing() {
+                Some(start) => {
+                    self.cursor = Some(start);
+                    let end = self.next_crossing();
+                    self.cursor = end;
+                    self.span = (start.0, end.map_or(start.0, |end| end.0));
+                    if end.is_none() {
+                        (self.y, self.cursor) = (self.y + 1, None);
+                    }
+                }
+                None => (self.y, self.cursor) = (self.y + 1, None),
+            }
+        }
+    }
+}
+
+/// Cells of unit grid crossed by ray in order (Amanatides–Woo traversal), from cell containing origin
+/// until parameter reaches `max_t` (point `origin + direction * max_t`)
+#[derive(Debug, Clone)]
+pub struct GridTraversal {
+    cell: Point<i32>,
+    step: (i32, i32),
+    /// parameter at which next vertical and horizontal border is crossed
+    t_max: (f64, f64),
+    /// parameter distance between borders
+    t_delta: (f64, f64),
+    max_t: f64,
+    done: bool,
+}
+
+fn floor(value: f64) -> i32 {
+    let truncated = value as i32;
+    if (truncated as f64) > value {
+        truncated - 1
+    } else {
+        truncated
+    }
+}
+
+impl GridTraversal {
+    pub fn new<T: Copy + Into<f64>>(origin: Point<T>, direction: Vector<T>, max_t: T) -> Self {
+        let (ox, oy) = ((*origin.x()).into(), (*origin.y()).into());
+        let (dx, dy): (f64, f64) = ((*direction.x()).into(), (*direction.y()).into());
+        let cell = point(floor(ox), floor(oy));
+        let axis = |o: f64, d: f64, cell: i32| {
+            if d > 0. {
+                (1, (cell as f64 + 1. - o) / d, 1. / d)
+            } else if d < 0. {
+                (-1, (cell as f64 - o) / d, -1. / d)
+            } else {
+                (0, f64::INFINITY, f64::INFINITY)
+            }
+        };
+        let (sx, tx, dtx) = axis(ox, dx, *cell.x());
+        let (sy, ty, dty) = axis(oy, dy, *cell.y());
+        Self {
+            cell,
+            step: (sx, sy),
+            t_max: (tx, ty),
+            t_delta: (dtx, dty),
+            max_t: max_t.into(),
+            done: false,
+        }
+    }
+}
+
+impl Iterator for GridTraversal {
+    type Item = Point<i32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let result = self.cell;
+        let (x, y) = result.into();
+        if self.t_max.0 < self.t_max.1 {
+            self.done = self.t_max.0 > self.max_t;
+            self.cell = point(x + self.step.0, y);
+            self.t_max.0 += self.t_delta.0;
+        } else {
+            self.done = self.t_max.1 > self.max_t;
+            self.cell = point(x, y + self.step.1);
+            self.t_max.1 += self.t_delta.1;
+        }
+        Some(result)
+    }
+}
+
+/**
+ * @brief flood_fill - scanline fill of 4-connected area of grid `0..size` containing `start`.
+ *  Unlike iterators of this module it allocates: seeds of unvisited runs are kept on a heap stack
+ *  (at most one seed per run of cells in neighbour rows)
+ * @param inside - whether cell belongs to area. Must become false for cells passed to `fill`
+ * @param fill - called once for every cell of area
+ */
+pub fn flood_fill<F, G>(size: Size<u32>, start: Point<i32>, mut inside: F, mut fill: G)
+where
+    F: FnMut(Point<i32>) -> bool,
+    G: FnMut(Point<i32>),
+{
+    let (w, h) = size.into();
+    let (w, h) = (
+        i32::try_from(w).unwrap_or(i32::MAX),
+        i32::try_from(h).unwrap_or(i32::MAX),
+    );
+    let mut check = |x: i32, y: i32| x >= 0 && y >= 0 && x < w && y < h && inside(point(x, y));
+    let mut stack: Vec<Point<i32>> = alloc::vec![start];
+    while let Some(seed) = stack.pop() {
+        let (x, y) = seed.into();
+        if !check(x, y) {
+            continue;
+        }
+        let (mut left, mut right) = (x, x);
+        while check(left - 1, y) {
+            left -= 1
+        }
+        while check(right + 1, y) {
+            right += 1
+        }
+        for x in left..=right {
+            fill(point(x, y))
+        }
+        // one seed per run of inside cells in neighbour rows
+        for y in [y - 1, y + 1] {
+            let mut in_run = false;
+            for x in left..=right {
+                let inside = check(x, y);
+                if inside && !in_run {
+                    stack.push(point(x, y))
+                }
+                in_run = inside;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        BresenhamLine, GridTraversal, MidpointCircle, MidpointEllipse, PolygonFill, SupercoverLine,
+        flood_fill,
+    };
+    use crate::math::{Point, Size, Vector};
+    use alloc::vec::Vec;
+
+    fn points(coordinates: &[(i32, i32)]) -> Vec<Point<i32>> {
+        coordinates.iter().map(|p| Point::from(*p)).collect()
+    }
+
+    fn has_duplicates(points: &[Point<i32>]) -> bool {
+        (0..points.len()).any(|i| points[..i].contains(&points[i]))
+    }
+
+    /// Every cell has at least two 4-connected or diagonal neighbours, so outline has no gaps
+    fn is_closed_outline(outline: &[Point<i32>]) -> bool {
+        outline.iter().all(|p| {
+            outline
+                .iter()
+                .filter(|q| (q.x() - p.x()).abs() <= 1 && (q.y() - p.y()).abs() <= 1 && *q != p)
+                .count()
+                >= 2
+        })
+    }
+
+    #[test]
+    fn lines() {
+        let line: Vec<_> = BresenhamLine::new((0, 0).into(), (5, -2).into()).collect();
+        assert_eq!(
+            line,
+            points(&[(0, 0), (1, 0), (2, -1), (3, -1), (4, -2), (5, -2)])
+        );
+        assert_eq!(BresenhamLine::new((3, 3).into(), (3, 3).into()).count(), 1);
+
+        let cover: Vec<_> = SupercoverLine::new((0, 0).into(), (2, 2).into()).collect();
+        assert_eq!(
+            cover,
+            points(&[(0, 0), (1, 0), (0, 1), (1, 1), (2, 1), (1, 2), (2, 2)])
+        );
+        let cover: Vec<_> = SupercoverLine::new((0, 0).into(), (-3, 1).into()).collect();
+        // passes exactly through corner at (-1, 1)
+        assert_eq!(
+            cover,
+            points(&[(0, 0), (-1, 0), (-2, 0), (-1, 1), (-2, 1), (-3, 1)])
+        );
+    }
+
+    #[test]
+    fn circles() {
+        let circle: Vec<_> = MidpointCircle::new((0, 0).into(), 1).collect();
+        assert_eq!(circle, points(&[(1, 0), (0, 1), (-1, 0), (0, -1)]));
+        assert_eq!(MidpointCircle::new((2, 2).into(), 0).count(), 1);
+
+        let circle: Vec<_> = MidpointCircle::new((10, -10).into(), 20).collect();
+        assert!(!has_duplicates(&circle));
+        assert!(circle.iter().all(|p| {
+            let (x, y) = ((p.x() - 10) as f64, (p.y() + 10) as f64);
+            ((x * x + y * y) - 400.).abs() < 2. * 20.
+        }));
+
+        let ellipse: Vec<_> = MidpointEllipse::new((0, 0).into(), Size::from((8, 3))).collect();
+        assert!(!has_duplicates(&ellipse));
+        for p in points(&[(8, 0), (-8, 0), (0, 3), (0, -3)]) {
+            assert!(ellipse.contains(&p));
+        }
+        assert!(ellipse.iter().all(|p| {
+            let (x, y) = (*p.x() as f64 / 8., *p.y() as f64 / 3.);
+            (x * x + y * y - 1.).abs() < 0.4
+        }));
+        assert!(is_closed_outline(&ellipse));
+    }
+
+    #[test]
+    fn flat_ellipses() {
+        for (a, b) in [(20, 1), (100, 3), (7, 2)] {
+            let ellipse: Vec<_> = MidpointEllipse::new((0, 0).into(), Size::from((a, b))).collect();
+            assert!(!has_duplicates(&ellipse));
+            let (a, b) = (a as i32, b as i32);
+            for p in points(&[(a, 0), (-a, 0), (0, b), (0, -b)]) {
+                assert!(ellipse.contains(&p), "{a}x{b} misses {p:?}");
+            }
+            assert!(ellipse.iter().all(|p| p.x().abs() <= a && p.y().abs() <= b));
+            // quadrant is a staircase without gaps, tips are one cell wide
+            let mut quadrant: Vec<_> = ellipse
+                .iter()
+                .filter(|p| *p.x() >= 0 && *p.y() >= 0)
+                .map(|p| (*p.x(), *p.y()))
+                .collect();
+            quadrant.sort();
+            assert!(
+                quadrant
+                    .windows(2)
+                    .all(|w| w[1].0 == w[0].0 + 1 && (w[0].1 - w[1].1) <= 1 && w[1].1 <= w[0].1)
+            );
+        }
+
+        let line: Vec<_> = MidpointEllipse::new((1, 1).into(), Size::from((8, 0))).collect();
+        assert_eq!(line.len(), 17);
+        assert!((-7..=9).all(|x| line.contains(&Point::from((x, 1)))));
+
+        let line: Vec<_> = MidpointEllipse::new((0, 0).into(), Size::from((0, 3))).collect();
+        assert_eq!(line.len(), 7);
+        assert!((-3..=3).all(|y| line.contains(&Point::from((0, y)))));
+    }
+
+    #[test]
+    fn far_apart_lines() {
+        let (from, to) = (Point::from((i32::MIN, -5)), Point::from((i32::MAX, 5)));
+        let mut line = BresenhamLine::new(from, to);
+        assert_eq!(line.next(), Some(from));
+        assert_eq!(line.next(), Some(Point::from((i32::MIN + 1, -5))));
+        let mut cover = SupercoverLine::new(from, to);
+        assert_eq!(cover.next(), Some(from));
+        assert_eq!(cover.next(), Some(Point::from((i32::MIN + 1, -5))));
+
+        let vertical: Vec<_> =
+            BresenhamLine::new((0, i32::MAX - 2).into(), (0, i32::MAX).into()).collect();
+        assert_eq!(
+            vertical,
+            points(&[(0, i32::MAX - 2), (0, i32::MAX - 1), (0, i32::MAX)])
+        );
+    }
+
+    #[test]
+    fn polygon_fill() {
+        let rect = points(&[(0, 0), (4, 0), (4, 3), (0, 3)]);
+        let cells: Vec<_> = PolygonFill::new(&rect).collect();
+        assert_eq!(cells.len(), 12);
+        assert_eq!(cells[0], Point::from((0, 0)));
+        assert_eq!(cells[11], Point::from((3, 2)));
+
+        // centers on right edge are excluded
+        let triangle = points(&[(0, 0), (4, 4), (0, 4)]);
+        let cells: Vec<_> = PolygonFill::new(&triangle).collect();
+        assert_eq!(
+            cells,
+            points(&[(0, 1), (0, 2), (1, 2), (0, 3), (1, 3), (2, 3)])
+        );
+
+        // hole by even-odd rule
+        let frame = points(&[
+            (0, 0),
+            (3, 0),
+            (3, 3),
+            (0, 3),
+            (0, 0),
+            (1, 1),
+            (1, 2),
+            (2, 2),
+            (2, 1),
+            (1, 1),
+        ]);
+        let cells: Vec<_> = PolygonFill::new(&frame).collect();
+        assert_eq!(cells.len(), 8);
+        assert!(!cells.contains(&Point::from((1, 1))));
+        assert_eq!(PolygonFill::new(&[]).count(), 0);
+    }
+
+    #[test]
+    fn traversal_and_flood_fill() {
+        let cells: Vec<_> =
+            GridTraversal::new(Point::from((0.5, 0.5)), Vector::from((1., 0.5)), 3.).collect();
+        assert_eq!(
+            cells,
+            points(&[(0, 0), (1, 0), (1, 1), (2, 1), (3, 1), (3, 2)])
+        );
+        let cells: Vec<_> =
+            GridTraversal::new(Point::from((-0.5f32, 0.5)), Vector::from((0., -1.)), 2.).collect();
+        assert_eq!(cells, points(&[(-1, 0), (-1, -1), (-1, -2)]));
+
+        // wall at x = 2 with gap at y = 3
+        let mut grid = [[true; 5]; 5];
+        for (y, row) in grid.iter_mut().enumerate() {
+            if y != 3 {
+                row[2] = false
+            }
+        }
+        grid[3][4] = false;
+        let mut filled = Vec::new();
+        let cells = core::cell::RefCell::new(grid);
+        flood_fill(
+            Size::from((5, 5)),
+            (0, 0).into(),
+            |p| cells.borrow()[*p.y() as usize][*p.x() as usize],
+            |p| {
+                cells.borrow_mut()[*p.y() as usize][*p.x() as usize] = false;
+                filled.push(p)
+            },
+        );
+        assert_eq!(filled.len(), 20);
+        assert!(!has_duplicates(&filled));
+    }
+}
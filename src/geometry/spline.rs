@@ -0,0 +1,713 @@
+use super::{Scalar, arc_length, t_at_length};
+use crate::math::{Abs, DeltaAngle, Displace, Positive, Vector, Vector3, from_u32};
+use alloc::vec::Vec;
+use core::ops::{Add, Mul, Sub};
+
+/// Length of a delta (derivative) value, e.g. `Vector::len` or absolute value of scalar
+pub trait Magnitude<T> {
+    fn magnitude(&self) -> T;
+}
+
+macro_rules! impl_magnitude {
+    ( $($tp: ty),+ ) => {
+        $(
+            impl Magnitude<$tp> for $tp {
+                fn magnitude(&self) -> $tp { Abs::abs(*self) }
+            }
+        )+
+    };
+}
+
+impl_magnitude! { f32, f64 }
+
+impl<T: Scalar> Magnitude<T> for Vector<T> {
+    fn magnitude(&self) -> T {
+        self.len()
+    }
+}
+
+impl<T: Scalar> Magnitude<T> for Vector3<T> {
+    fn magnitude(&self) -> T {
+        self.len()
+    }
+}
+
+impl<T: Scalar> Magnitude<T> for DeltaAngle<T> {
+    fn magnitude(&self) -> T {
+        self.into_inner().abs()
+    }
+}
+
+/// Derivative type of spline values
+pub type Delta<V> = <V as Displace>::Delta;
+
+/// Curve over parameter range `domain()` with values in `Point<T>`, `Vector<T>`, scalars or other `Displace` types.
+/// Parameter outside of domain is clamped
+pub trait Spline<T: Scalar> {
+    type Value: Displace;
+
+    fn domain(&self) -> (T, T);
+
+    fn eval(&self, t: T) -> Self::Value;
+
+    fn derivative(&self, t: T) -> Delta<Self::Value>;
+
+    fn arc_length(&self, tolerance: Positive<T>) -> T
+    where
+        Delta<Self::Value>: Magnitude<T>,
+    {
+        let (_, end) = self.domain();
+        self.length_at(end, tolerance)
+    }
+
+    /// Arc length from domain start to `t`
+    fn length_at(&self, t: T, tolerance: Positive<T>) -> T
+    where
+        Delta<Self::Value>: Magnitude<T>,
+    {
+        let (start, _) = self.domain();
+        arc_length(|t| self.derivative(t).magnitude(), start, t, tolerance)
+    }
+
+    /// Parameter at which arc length from domain start reaches `length` (arc-length reparameterization)
+    fn t_at_length(&self, length: T, tolerance: Positive<T>) -> T
+    where
+        Delta<Self::Value>: Magnitude<T>,
+    {
+        t_at_length(
+            |t| self.derivative(t).magnitude(),
+            self.domain(),
+            length,
+            tolerance,
+        )
+    }
+}
+
+fn clamp<T: Scalar>(t: T, (start, end): (T, T)) -> T {
+    if t < start {
+        start
+    } else if t > end {
+        end
+    } else {
+        t
+    }
+}
+
+/// Segment index and local parameter for splines with segments of unit length
+fn locate<T: Scalar>(t: T, segments: usize) -> (usize, T) {
+    let t = clamp(t, (T::zero(), from_u32(segments as u32)));
+    let (mut low, mut high) = (0, segments);
+    while high - low > 1 {
+        let mid = (low + high) / 2;
+        if from_u32::<T>(mid as u32) <= t {
+            low = mid
+        } else {
+            high = mid
+        }
+    }
+    (low, t - from_u32(low as u32))
+}
+
+fn zero_delta<V: Displace + Clone>(v: &V) -> V::Delta {
+    v.clone().displacement(v.clone())
+}
+
+/// Cubic Hermite segment from `p0` to `p1` with tangents `m0`, `m1` at local parameter `s` in `0..=1`
+fn hermite<V, T>(p0: &V, m0: &V::Delta, p1: &V, m1: &V::Delta, s: T) -> V
+where
+    V: Displace + Clone,
+    V::Delta: Clone + Add<Output = V::Delta> + Mul<T, Output = V::Delta>,
+    T: Scalar,
+{
+    let (s2, s3) = (s * s, s * s * s);
+    let three = from_u32::<T>(3);
+    let h10 = s3 - T::two() * s2 + s;
+    let h01 = three * s2 - T::two() * s3;
+    let h11 = s3 - s2;
+    p0.clone()
+        .displaced(m0.clone() * h10 + p1.clone().displacement(p0.clone()) * h01 + m1.clone() * h11)
+}
+
+fn hermite_derivative<V, T>(p0: &V, m0: &V::Delta, p1: &V, m1: &V::Delta, s: T) -> V::Delta
+where
+    V: Displace + Clone,
+    V::Delta: Clone + Add<Output = V::Delta> + Mul<T, Output = V::Delta>,
+    T: Scalar,
+{
+    let three = from_u32::<T>(3);
+    let s2 = s * s;
+    let h10 = three * s2 - from_u32::<T>(4) * s + T::one();
+    let h01 = from_u32::<T>(6) * (s - s2);
+    let h11 = three * s2 - T::two() * s;
+    m0.clone() * h10 + p1.clone().displacement(p0.clone()) * h01 + m1.clone() * h11
+}
+
+/// Cubic Hermite spline through `points` with explicit `tangents` (derivatives) at them.
+/// Segment between `points[i]` and `points[i + 1]` corresponds to parameter `i..i + 1`
+#[derive(Debug, Clone, Copy)]
+pub struct HermiteSpline<'a, V: Displace> {
+    points: &'a [V],
+    tangents: &'a [V::Delta],
+}
+
+impl<'a, V: Displace> HermiteSpline<'a, V> {
+    /// Returns `None` if there are less than two points or tangents count differs
+    pub fn new(points: &'a [V], tangents: &'a [V::Delta]) -> Option<Self> {
+        if points.len() >= 2 && points.len() == tangents.len() {
+            Some(Self { points, tangents })
+        } else {
+            None
+        }
+    }
+
+    pub fn points(&self) -> &'a [V] {
+        self.points
+    }
+
+    pub fn tangents(&self) -> &'a [V::Delta] {
+        self.tangents
+    }
+}
+
+impl<V, T> Spline<T> for HermiteSpline<'_, V>
+where
+    V: Displace + Clone,
+    V::Delta: Clone + Add<Output = V::Delta> + Mul<T, Output = V::Delta>,
+    T: Scalar,
+{
+    type Value = V;
+
+    fn domain(&self) -> (T, T) {
+        (T::zero(), from_u32(self.points.len() as u32 - 1))
+    }
+
+    fn eval(&self, t: T) -> V {
+        let (i, s) = locate(t, self.points.len() - 1);
+        let (p, m) = (self.points, self.tangents);
+        hermite(&p[i], &m[i], &p[i + 1], &m[i + 1], s)
+    }
+
+    fn derivative(&self, t: T) -> V::Delta {
+        let (i, s) = locate(t, self.points.len() - 1);
+        let (p, m) = (self.points, self.tangents);
+        hermite_derivative(&p[i], &m[i], &p[i + 1], &m[i + 1], s)
+    }
+}
+
+/// Centripetal Catmull–Rom spline passing through `points`.
+/// Knot spacing is square root of distance between points which avoids cusps and self-intersections within segment.
+/// Missing neighbours of end points are mirrored. Segment `i` corresponds to parameter `i..i + 1`,
+/// so speed may change at points while tangent direction stays continuous
+#[derive(Debug, Clone, Copy)]
+pub struct CatmullRom<'a, V> {
+    points: &'a [V],
+}
+
+impl<'a, V> CatmullRom<'a, V> {
+    /// Returns `None` if there are less than two points
+    pub fn new(points: &'a [V]) -> Option<Self> {
+        if points.len() >= 2 {
+            Some(Self { points })
+        } else {
+            None
+        }
+    }
+
+    pub fn points(&self) -> &'a [V] {
+        self.points
+    }
+
+    /// End points and tangents of Hermite form of segment `i`
+    fn segment<T>(&self, i: usize) -> (V, V::Delta, V, V::Delta)
+    where
+        V: Displace + Clone,
+        V::Delta: Clone
+            + Add<Output = V::Delta>
+            + Sub<Output = V::Delta>
+            + Mul<T, Output = V::Delta>
+            + Magnitude<T>,
+        T: Scalar,
+    {
+        let points = self.points;
+        let mirror = |p: &V, neighbour: &V| {
+            p.clone()
+                .displaced(p.clone().displacement(neighbour.clone()))
+        };
+        let p1 = points[i].clone();
+        let p2 = points[i + 1].clone();
+        let p0 = if i > 0 {
+            points[i - 1].clone()
+        } else {
+            mirror(&p1, &p2)
+        };
+        let p3 = if i + 2 < points.len() {
+            points[i + 2].clone()
+        } else {
+            mirror(&p2, &p1)
+        };
+
+        let spacing = |a: &V, b: &V| b.clone().displacement(a.clone()).magnitude().sqrt();
+        let (d01, d12, d23) = (spacing(&p0, &p1), spacing(&p1, &p2), spacing(&p2, &p3));
+        let slope = |a: &V, b: &V, d: T| {
+            if d > T::zero() {
+                b.clone().displacement(a.clone()) * (T::one() / d)
+            } else {
+                zero_delta(a)
+            }
+        };
+        let m1 = (slope(&p0, &p1, d01) - slope(&p0, &p2, d01 + d12) + slope(&p1, &p2, d12)) * d12;
+        let m2 = (slope(&p1, &p2, d12) - slope(&p1, &p3, d12 + d23) + slope(&p2, &p3, d23)) * d12;
+        (p1, m1, p2, m2)
+    }
+}
+
+impl<V, T> Spline<T> for CatmullRom<'_, V>
+where
+    V: Displace + Clone,
+    V::Delta: Clone
+        + Add<Output = V::Delta>
+        + Sub<Output = V::Delta>
+        + Mul<T, Output = V::Delta>
+        + Magnitude<T>,
+    T: Scalar,
+{
+    type Value = V;
+
+    fn domain(&self) -> (T, T) {
+        (T::zero(), from_u32(self.points.len() as u32 - 1))
+    }
+
+    fn eval(&self, t: T) -> V {
+        let (i, s) = locate(t, self.points.len() - 1);
+        let (p1, m1, p2, m2) = self.segment(i);
+        hermite(&p1, &m1, &p2, &m2, s)
+    }
+
+    fn derivative(&self, t: T) -> V::Delta {
+        let (i, s) = locate(t, self.points.len() - 1);
+        let (p1, m1, p2, m2) = self.segment(i);
+        hermite_derivative(&p1, &m1, &p2, &m2, s)
+    }
+}
+
+/// Knot vector of `BSpline`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Knots<'a, T> {
+    /// `0, 1, 2, ...`: curve does not reach end control points
+    Uniform,
+    /// Uniform with `degree + 1` repeated knots at both ends: curve starts and ends at end control points
+    ClampedUniform,
+    /// Non-decreasing sequence of `points.len() + degree + 1` knots
+    Custom(&'a [T]),
+}
+
+/// B-spline of arbitrary degree evaluated with Cox–de Boor recursion (no allocation).
+/// Domain is `knot(degree)..knot(points.len())`
+#[derive(Debug, Clone, Copy)]
+pub struct BSpline<'a, V, T> {
+    points: &'a [V],
+    knots: Knots<'a, T>,
+    degree: usize,
+}
+
+impl<'a, V, T: Scalar> BSpline<'a, V, T> {
+    /// Returns `None` if there are not more points than `degree` or custom knots are invalid
+    pub fn new(points: &'a [V], degree: usize, knots: Knots<'a, T>) -> Option<Self> {
+        if points.len() <= degree {
+            return None;
+        }
+        if let Knots::Custom(knots) = knots {
+            if knots.len() != points.len() + degree + 1
+                || knots.windows(2).any(|w| w[1] < w[0])
+                || knots[degree] >= knots[points.len()]
+            {
+                return None;
+            }
+        }
+        Some(Self {
+            points,
+            knots,
+            degree,
+        })
+    }
+
+    pub fn points(&self) -> &'a [V] {
+        self.points
+    }
+
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    pub fn knots(&self) -> Knots<'a, T> {
+        self.knots
+    }
+
+    pub fn knot(&self, i: usize) -> T {
+        match self.knots {
+            Knots::Uniform => from_u32(i as u32),
+            Knots::ClampedUniform => {
+                let last = self.points.len() - self.degree;
+                from_u32(i.saturating_sub(self.degree).min(last) as u32)
+            }
+            Knots::Custom(knots) => knots[i],
+        }
+    }
+
+    /// Index `k` of knot span `knot(k)..knot(k + 1)` containing `t`
+    fn span(&self, t: T) -> usize {
+        let (mut low, mut high) = (self.degree, self.points.len());
+        while high - low > 1 {
+            let mid = (low + high) / 2;
+            if self.knot(mid) <= t {
+                low = mid
+            } else {
+                high = mid
+            }
+        }
+        low
+    }
+
+    /// Basis function `N(i, p)` at `t` lying in span `k`
+    fn basis(&self, i: usize, p: usize, t: T, k: usize) -> T {
+        if p == 0 {
+            return if i == k { T::one() } else { T::zero() };
+        }
+        let ratio = |numerator: T, denominator: T| {
+            if denominator != T::zero() {
+                numerator / denominator
+            } else {
+                T::zero()
+            }
+        };
+        let left = ratio(t - self.knot(i), self.knot(i + p) - self.knot(i));
+        let right = ratio(
+            self.knot(i + p + 1) - t,
+            self.knot(i + p + 1) - self.knot(i + 1),
+        );
+        let left = if left != T::zero() {
+            left * self.basis(i, p - 1, t, k)
+        } else {
+            T::zero()
+        };
+        let right = if right != T::zero() {
+            right * self.basis(i + 1, p - 1, t, k)
+        } else {
+            T::zero()
+        };
+        left + right
+    }
+
+    fn basis_derivative(&self, i: usize, t: T, k: usize) -> T {
+        let p = self.degree;
+        if p == 0 {
+            return T::zero();
+        }
+        let term = |i: usize| {
+            let denominator = self.knot(i + p) - self.knot(i);
+            if denominator != T::zero() {
+                self.basis(i, p - 1, t, k) / denominator
+            } else {
+                T::zero()
+            }
+        };
+        from_u32::<T>(p as u32) * (term(i) - term(i + 1))
+    }
+
+    /// Affine combination of control points around span `k` with `weight(j)`.
+    /// Weights of value sum up to one and of derivative to zero so the first point is used as origin
+    fn combine<W: Fn(usize) -> T>(&self, k: usize, weight: W) -> (V, V::Delta)
+    where
+        V: Displace + Clone,
+        V::Delta: Add<Output = V::Delta> + Mul<T, Output = V::Delta>,
+    {
+        let base = self.points[k - self.degree].clone();
+        let delta = (k - self.degree + 1..=k).fold(zero_delta(&base), |acc, j| {
+            acc + self.points[j].clone().displacement(base.clone()) * weight(j)
+        });
+        (base, delta)
+    }
+}
+
+impl<V, T> Spline<T> for BSpline<'_, V, T>
+where
+    V: Displace + Clone,
+    V::Delta: Add<Output = V::Delta> + Mul<T, Output = V::Delta>,
+    T: Scalar,
+{
+    type Value = V;
+
+    fn domain(&self) -> (T, T) {
+        (self.knot(self.degree), self.knot(self.points.len()))
+    }
+
+    fn eval(&self, t: T) -> V {
+        let t = clamp(t, self.domain());
+        let k = self.span(t);
+        let (base, delta) = self.combine(k, |j| self.basis(j, self.degree, t, k));
+        base.displaced(delta)
+    }
+
+    fn derivative(&self, t: T) -> V::Delta {
+        let t = clamp(t, self.domain());
+        let k = self.span(t);
+        self.combine(k, |j| self.basis_derivative(j, t, k)).1
+    }
+}
+
+/// Interpolating cubic spline of 1D data `y(x)` with continuous second derivative
+#[derive(Debug, Clone, PartialEq)]
+pub struct CubicSpline<'a, T> {
+    xs: &'a [T],
+    ys: &'a [T],
+    /// second derivatives at `xs`
+    moments: Vec<T>,
+}
+
+impl<'a, T: Scalar> CubicSpline<'a, T> {
+    /// Spline with zero second derivative at ends.
+    /// Returns `None` if there are less than 2 points, lengths differ or `xs` are not strictly increasing
+    pub fn natural(xs: &'a [T], ys: &'a [T]) -> Option<Self> {
+        Self::solve(xs, ys, None)
+    }
+
+    /// Spline with given first derivatives at ends.
+    /// Returns `None` if there are less than 2 points, lengths differ or `xs` are not strictly increasing
+    pub fn clamped(xs: &'a [T], ys: &'a [T], start_slope: T, end_slope: T) -> Option<Self> {
+        Self::solve(xs, ys, Some((start_slope, end_slope)))
+    }
+
+    pub fn xs(&self) -> &'a [T] {
+        self.xs
+    }
+
+    pub fn ys(&self) -> &'a [T] {
+        self.ys
+    }
+
+    /// Tridiagonal system for second derivatives solved with Thomas algorithm
+    fn solve(xs: &'a [T], ys: &'a [T], slopes: Option<(T, T)>) -> Option<Self> {
+        let n = xs.len();
+        if n < 2 || ys.len() != n || xs.windows(2).any(|w| w[1] <= w[0]) {
+            return None;
+        }
+        let six = from_u32::<T>(6);
+        let h = |i: usize| xs[i + 1] - xs[i];
+        let slope = |i: usize| (ys[i + 1] - ys[i]) / h(i);
+
+        let mut sub = alloc::vec![T::zero(); n];
+        let mut diag = alloc::vec![T::one(); n];
+        let mut sup = alloc::vec![T::zero(); n];
+        let mut rhs = alloc::vec![T::zero(); n];
+        for i in 1..n - 1 {
+            sub[i] = h(i - 1);
+            diag[i] = T::two() * (h(i - 1) + h(i));
+            sup[i] = h(i);
+            rhs[i] = six * (slope(i) - slope(i - 1));
+        }
+        if let Some((start, end)) = slopes {
+            diag[0] = T::two() * h(0);
+            sup[0] = h(0);
+            rhs[0] = six * (slope(0) - start);
+            sub[n - 1] = h(n - 2);
+            diag[n - 1] = T::two() * h(n - 2);
+            rhs[n - 1] = six * (end - slope(n - 2));
+        }
+
+        for i in 1..n {
+            let w = sub[i] / diag[i - 1];
+            diag[i] = diag[i] - w * sup[i - 1];
+            rhs[i] = rhs[i] - w * rhs[i - 1];
+        }
+        let mut moments = alloc::vec![T::zero(); n];
+        moments[n - 1] = rhs[n - 1] / diag[n - 1];
+        for i in (0..n - 1).rev() {
+            moments[i] = (rhs[i] - sup[i] * moments[i + 1]) / diag[i];
+        }
+        Some(Self { xs, ys, moments })
+    }
+
+    /// Interval `i` and weights of its ends at `x`
+    fn interval(&self, x: T) -> (usize, T, T, T) {
+        let n = self.xs.len();
+        let x = clamp(x, (self.xs[0], self.xs[n - 1]));
+        let i = self.xs[1..n - 1].partition_point(|v| *v <= x);
+        let h = self.xs[i + 1] - self.xs[i];
+        (i, (self.xs[i + 1] - x) / h, (x - self.xs[i]) / h, h)
+    }
+
+    pub fn value(&self, x: T) -> T {
+        let (i, a, b, h) = self.interval(x);
+        let m = &self.moments;
+        a * self.ys[i]
+            + b * self.ys[i + 1]
+            + ((a * a * a - a) * m[i] + (b * b * b - b) * m[i + 1]) * h * h / from_u32(6)
+    }
+
+    pub fn slope(&self, x: T) -> T {
+        let (i, a, b, h) = self.interval(x);
+        let m = &self.moments;
+        let three = from_u32::<T>(3);
+        (self.ys[i + 1] - self.ys[i]) / h
+            + ((three * b * b - T::one()) * m[i + 1] - (three * a * a - T::one()) * m[i]) * h
+                / from_u32(6)
+    }
+
+    /// Speed along graph of `y(x)`: `√(1 + y'²)`
+    fn graph_speed(&self, x: T) -> T {
+        (T::one() + self.slope(x).sq()).sqrt()
+    }
+}
+
+/// Arc lengths are measured along graph of `y(x)`, not as total variation of `y`
+impl<T> Spline<T> for CubicSpline<'_, T>
+where
+    T: Scalar + Displace<Delta = T>,
+{
+    type Value = T;
+
+    fn domain(&self) -> (T, T) {
+        (self.xs[0], self.xs[self.xs.len() - 1])
+    }
+
+    fn eval(&self, t: T) -> T {
+        self.value(t)
+    }
+
+    fn derivative(&self, t: T) -> T {
+        self.slope(t)
+    }
+
+    fn length_at(&self, t: T, tolerance: Positive<T>) -> T {
+        let (start, _) = self.domain();
+        arc_length(|x| self.graph_speed(x), start, t, tolerance)
+    }
+
+    fn t_at_length(&self, length: T, tolerance: Positive<T>) -> T {
+        t_at_length(|x| self.graph_speed(x), self.domain(), length, tolerance)
+    }
+}
+
+#[cfg(test)]
+#[cfg(any(feature = "std", feature = "libm"))]
+mod tests {
+    use super::{BSpline, CatmullRom, CubicSpline, HermiteSpline, Knots, Spline};
+    use crate::math::{Point, Positive, Vector};
+    use alloc::vec::Vec;
+    use approx::assert_abs_diff_eq;
+
+    fn p(x: f64, y: f64) -> Point<f64> {
+        Point::from((x, y))
+    }
+
+    fn tolerance() -> Positive<f64> {
+        Positive::new(1e-9).unwrap()
+    }
+
+    #[test]
+    fn hermite() {
+        let spline = HermiteSpline::new(&[0., 1., 1.], &[0., 0., 2.]).unwrap();
+        assert_abs_diff_eq!(spline.eval(0.5), 0.5);
+        assert_abs_diff_eq!(spline.derivative(0.5), 1.5);
+        assert_abs_diff_eq!(spline.derivative(2.), 2.);
+        assert_abs_diff_eq!(spline.eval(5.), 1.);
+        assert!(HermiteSpline::new(&[0., 1.], &[0.]).is_none());
+    }
+
+    #[test]
+    fn catmull_rom() {
+        let points = [p(0., 0.), p(1., 2.), p(3., 2.), p(4., 0.), p(4., 0.)];
+        let spline = CatmullRom::new(&points).unwrap();
+        assert_eq!(spline.domain(), (0., 4.));
+        for (i, point) in points.iter().enumerate() {
+            let value = spline.eval(i as f64);
+            assert_abs_diff_eq!(*value.x(), *point.x(), epsilon = 1e-12);
+            assert_abs_diff_eq!(*value.y(), *point.y(), epsilon = 1e-12);
+        }
+        // tangent direction is continuous across knots (magnitude is not because segments are normalized to unit parameter)
+        let (left, right) = (
+            spline.derivative(1. - 1e-9).norm(),
+            spline.derivative(1.).norm(),
+        );
+        assert_abs_diff_eq!(*left.x(), *right.x(), epsilon = 1e-6);
+        assert_abs_diff_eq!(*left.y(), *right.y(), epsilon = 1e-6);
+        // repeated point does not produce NaN
+        assert!(spline.eval(3.5).x().is_finite());
+
+        let line = [p(0., 0.), p(1., 0.), p(2., 0.), p(3., 0.)];
+        let spline = CatmullRom::new(&line).unwrap();
+        assert_abs_diff_eq!(spline.arc_length(tolerance()), 3., epsilon = 1e-9);
+        assert_abs_diff_eq!(spline.t_at_length(1.5, tolerance()), 1.5, epsilon = 1e-9);
+
+        let vectors = [Vector::from((0., 1.)), Vector::from((1., 1.))];
+        let spline = CatmullRom::new(&vectors).unwrap();
+        assert_eq!(spline.eval(0.5), Vector::from((0.5, 1.)));
+    }
+
+    #[test]
+    fn b_spline() {
+        let points = [p(0., 0.), p(6., 6.), p(12., 0.), p(18., 6.)];
+        let uniform = BSpline::new(&points, 3, Knots::Uniform).unwrap();
+        assert_eq!(uniform.domain(), (3., 4.));
+        let start = uniform.eval(3.);
+        assert_abs_diff_eq!(*start.x(), 6., epsilon = 1e-12);
+        assert_abs_diff_eq!(*start.y(), 4., epsilon = 1e-12);
+        let derivative = uniform.derivative(3.);
+        assert_abs_diff_eq!(*derivative.x(), 6., epsilon = 1e-12);
+        assert_abs_diff_eq!(*derivative.y(), 0., epsilon = 1e-12);
+
+        let clamped = BSpline::new(&points, 2, Knots::ClampedUniform).unwrap();
+        let knots = [0., 0., 0., 1., 2., 2., 2.];
+        let custom = BSpline::new(&points, 2, Knots::Custom(&knots)).unwrap();
+        assert_eq!(clamped.domain(), (0., 2.));
+        assert_eq!(clamped.eval(0.), points[0]);
+        assert_eq!(clamped.eval(2.), points[3]);
+        for t in [0.3, 1., 1.7] {
+            assert_eq!(clamped.eval(t), custom.eval(t));
+        }
+        let h = 1e-6;
+        let numeric = (clamped.eval(0.5 + h) - clamped.eval(0.5 - h)) / (2. * h);
+        assert_abs_diff_eq!(*clamped.derivative(0.5).x(), *numeric.x(), epsilon = 1e-6);
+        assert_abs_diff_eq!(*clamped.derivative(0.5).y(), *numeric.y(), epsilon = 1e-6);
+
+        assert!(BSpline::new(&points, 4, Knots::<f64>::Uniform).is_none());
+        assert!(BSpline::new(&points, 2, Knots::Custom(&[0., 1., 0., 1., 2., 3., 4.])).is_none());
+    }
+
+    #[test]
+    fn cubic_spline() {
+        let xs = [0., 1., 2., 3.];
+        let ys = [1., 3., 5., 7.];
+        let line = CubicSpline::natural(&xs, &ys).unwrap();
+        assert_abs_diff_eq!(line.value(1.5), 4., epsilon = 1e-12);
+        assert_abs_diff_eq!(line.slope(2.5), 2., epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            line.arc_length(tolerance()),
+            3. * 5f64.sqrt(),
+            epsilon = 1e-9
+        );
+
+        // clamped spline reproduces cubic polynomial
+        let cubes = xs.map(|x| x * x * x);
+        let cube = CubicSpline::clamped(&xs, &cubes, 0., 27.).unwrap();
+        assert_abs_diff_eq!(cube.value(1.5), 3.375, epsilon = 1e-12);
+        assert_abs_diff_eq!(cube.slope(1.5), 6.75, epsilon = 1e-12);
+        // ∫√(1 + 9x⁴) dx over 0..3
+        assert_abs_diff_eq!(cube.arc_length(tolerance()), 27.658081929, epsilon = 1e-6);
+        let length = cube.length_at(2., tolerance());
+        assert_abs_diff_eq!(cube.t_at_length(length, tolerance()), 2., epsilon = 1e-9);
+
+        // runtime-length keyframes
+        let keys: Vec<f64> = (0..10).map(f64::from).collect();
+        let values: Vec<f64> = keys.iter().map(|x| x % 2.).collect();
+        let natural = CubicSpline::natural(&keys, &values).unwrap();
+        let h = 1e-4;
+        let second = |x: f64| (natural.slope(x + h) - natural.slope(x - h)) / (2. * h);
+        assert_abs_diff_eq!(second(h), 0., epsilon = 1e-3);
+        assert_abs_diff_eq!(second(9. - h), 0., epsilon = 1e-3);
+        assert_abs_diff_eq!(natural.value(5.), 1., epsilon = 1e-12);
+
+        assert!(CubicSpline::natural(&[0., 0.], &[1., 2.]).is_none());
+        assert!(CubicSpline::natural(&[0., 1.], &[1.]).is_none());
+    }
+}
@@ -0,0 +1,528 @@
+use super::{Path, TrigScalar};
+use crate::math::{Angle, Complex, Point, Positive, Vector, from_u32};
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Limit of segments approximating round join or cap
+const MAX_ARC_STEPS: u32 = 64;
+
+/// Shape of outer corner between stroked segments
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LineJoin<T> {
+    /// Sharp corner, beveled where ratio of miter length to stroke width exceeds `limit`
+    Miter {
+        limit: T,
+    },
+    Round,
+    Bevel,
+}
+
+/// Shape of open polyline ends
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LineCap {
+    /// Stroke ends exactly at end point
+    Butt,
+    /// Half circle around end point
+    Round,
+    /// Stroke extended by half width beyond end point
+    Square,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StrokeStyle<T> {
+    pub width: Positive<T>,
+    pub join: LineJoin<T>,
+    pub cap: LineCap,
+}
+
+impl<T: TrigScalar> StrokeStyle<T> {
+    /// Stroke with SVG defaults: miter join with limit 4 and butt caps
+    pub fn new(width: Positive<T>) -> Self {
+        Self {
+            width,
+            join: LineJoin::Miter { limit: from_u32(4) },
+            cap: LineCap::Butt,
+        }
+    }
+
+    /**
+     * @brief stroke_polyline - outline of polyline stroked with this style
+     * @param closed - whether last point connects back to first one. Closed polyline has joins everywhere and no caps
+     * @param tolerance - maximum deviation of round joins and caps from true circle
+     * @return polygons to be filled with nonzero rule: one for open polyline, outer and inner ring for closed one
+     */
+    pub fn stroke_polyline(
+        &self,
+        points: &[Point<T>],
+        closed: bool,
+        tolerance: Positive<T>,
+    ) -> Vec<Vec<Point<T>>> {
+        let half = self.width.into_inner() / T::two();
+        let points = distinct(points, closed);
+        let outline = Outline {
+            offset: half,
+            join: self.join,
+            tolerance: tolerance.into_inner(),
+            through_center: true,
+        };
+        let reversed: Vec<Point<T>> = points.iter().rev().copied().collect();
+        match points.len() {
+            0 => Vec::new(),
+            1 => {
+                let center = points[0];
+                let corner = |x: T, y: T| center + Vector::from((x * half, y * half));
+                let (one, minus_one) = (T::one(), -T::one());
+                match self.cap {
+                    LineCap::Butt => Vec::new(),
+                    LineCap::Square => alloc::vec![alloc::vec![
+                        corner(minus_one, minus_one),
+                        corner(one, minus_one),
+                        corner(one, one),
+                        corner(minus_one, one),
+                    ]],
+                    LineCap::Round => {
+                        let start = Vector::from((half, T::zero()));
+                        let mut polygon = alloc::vec![center + start];
+                        outline.arc(center, start, T::two() * T::pi(), &mut polygon);
+                        alloc::vec![polygon]
+                    }
+                }
+            }
+            _ if closed => alloc::vec![outline.side(&points, true), outline.side(&reversed, true)],
+            count => {
+                let mut polygon = outline.side(&points, false);
+                self.cap(
+                    &outline,
+                    points[count - 1],
+                    points[count - 1] - points[count - 2],
+                    &mut polygon,
+                );
+                polygon.extend(outline.side(&reversed, false));
+                self.cap(&outline, points[0], points[0] - points[1], &mut polygon);
+                alloc::vec![polygon]
+            }
+        }
+    }
+
+    /// Points of cap at `end` strictly between left and right side of stroke
+    fn cap(
+        &self,
+        outline: &Outline<T>,
+        end: Point<T>,
+        direction: Vector<T>,
+        out: &mut Vec<Point<T>>,
+    ) {
+        let rotor = direction.rotor();
+        let left = Vector::from((T::zero(), outline.offset)) * rotor;
+        let forward = Vector::from((outline.offset, T::zero())) * rotor;
+        match self.cap {
+            LineCap::Butt => {}
+            LineCap::Square => out.extend([end + left + forward, end - left + forward]),
+            LineCap::Round => outline.arc(end, left, -T::pi(), out),
+        }
+    }
+}
+
+/// Dash pattern: lengths of alternating dashes and gaps. Odd pattern is repeated twice as in SVG
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dashes<'a, T> {
+    pattern: &'a [T],
+    offset: T,
+}
+
+impl<'a, T: TrigScalar> Dashes<'a, T> {
+    /// Pattern starting `offset` into it. `None` if pattern has negative lengths or no positive one
+    pub fn new(pattern: &'a [T], offset: T) -> Option<Self> {
+        if pattern.iter().any(|length| *length < T::zero())
+            || !pattern.iter().any(|length| *length > T::zero())
+        {
+            return None;
+        }
+        Some(Self { pattern, offset })
+    }
+
+    pub fn pattern(&self) -> &'a [T] {
+        self.pattern
+    }
+
+    pub fn offset(&self) -> T {
+        self.offset
+    }
+
+    fn length(&self, index: usize) -> T {
+        self.pattern[index % self.pattern.len()]
+    }
+
+    /// Pieces of polyline covered by dashes
+    pub fn apply(&self, points: &[Point<T>]) -> Vec<Vec<Point<T>>> {
+        let count = if self.pattern.len() % 2 == 1 {
+            2 * self.pattern.len()
+        } else {
+            self.pattern.len()
+        };
+        let period = (0..count).fold(T::zero(), |sum, i| sum + self.length(i));
+        let mut index = 0;
+        let mut position = self.offset.rem_euclid(period);
+        // zero length dash at current position is kept
+        while position > self.length(index)
+            || (position == self.length(index) && position > T::zero())
+        {
+            position = position - self.length(index);
+            index = (index + 1) % count;
+        }
+        let mut remaining = self.length(index) - position;
+
+        let mut dashes = Vec::new();
+        let mut dash = Vec::new();
+        if index % 2 == 0 {
+            if let Some(first) = points.first() {
+                dash.push(*first)
+            }
+        }
+        for pair in points.windows(2) {
+            let (mut from, to) = (pair[0], pair[1]);
+            let mut length = (to - from).len();
+            while length > remaining {
+                from = from + (to - from) * (remaining / length);
+                length = length - remaining;
+                // ends dash or starts next one
+                dash.push(from);
+                if index % 2 == 0 {
+                    dashes.push(core::mem::take(&mut dash))
+                }
+                index = (index + 1) % count;
+                remaining = self.length(index);
+            }
+            remaining = remaining - length;
+            if index % 2 == 0 {
+                dash.push(to)
+            }
+        }
+        if index % 2 == 0 && dash.len() > 1 {
+            dashes.push(dash)
+        }
+        dashes
+    }
+}
+
+/**
+ * @brief offset_polygon - polygon grown by `distance` or shrunk by negative one, independent of orientation
+ *  Self-intersections appearing when shrinking past narrow parts are not removed.
+ * @param join - shape of convex corners when growing (concave ones when shrinking)
+ * @param tolerance - maximum deviation of round joins from true circle
+ */
+pub fn offset_polygon<T: TrigScalar>(
+    points: &[Point<T>],
+    distance: T,
+    join: LineJoin<T>,
+    tolerance: Positive<T>,
+) -> Vec<Point<T>> {
+    let points = distinct(points, true);
+    if points.len() < 3 {
+        return points;
+    }
+    let origin = Point::origin();
+    let twice_area = (0..points.len()).fold(T::zero(), |sum, i| {
+        let next = points[(i + 1) % points.len()];
+        sum + (points[i] - origin).cross(next - origin)
+    });
+    // left side of polygon with positive area is its inside
+    let offset = if twice_area > T::zero() {
+        -distance
+    } else {
+        distance
+    };
+    Outline {
+        offset,
+        join,
+        tolerance: tolerance.into_inner(),
+        through_center: false,
+    }
+    .side(&points, true)
+}
+
+impl<T: TrigScalar> Path<T> {
+    /// Fill polygons of stroke outline (nonzero rule). Subpaths are flattened with `tolerance` and dashed first if `dashes` given
+    pub fn stroked(
+        &self,
+        style: &StrokeStyle<T>,
+        dashes: Option<&Dashes<T>>,
+        tolerance: Positive<T>,
+    ) -> Vec<Vec<Point<T>>> {
+        let mut polygons = Vec::new();
+        for (polyline, closed) in self.flattened_subpaths(tolerance) {
+            match dashes {
+                Some(dashes) => {
+                    for dash in dashes.apply(&polyline) {
+                        polygons.extend(style.stroke_polyline(&dash, false, tolerance))
+                    }
+                }
+                None => polygons.extend(style.stroke_polyline(&polyline, closed, tolerance)),
+            }
+        }
+        polygons
+    }
+}
+
+/// Polyline without repeated consecutive points (and without closing point for closed one)
+fn distinct<T: TrigScalar>(points: &[Point<T>], closed: bool) -> Vec<Point<T>> {
+    let mut result: Vec<Point<T>> = Vec::with_capacity(points.len());
+    for point in points {
+        if result.last() != Some(point) {
+            result.push(*point)
+        }
+    }
+    if closed && result.len() > 1 && result.first() == result.last() {
+        result.pop();
+    }
+    result
+}
+
+/// Offset of polyline to one side
+struct Outline<T> {
+    /// signed distance to the left of polyline direction
+    offset: T,
+    join: LineJoin<T>,
+    tolerance: T,
+    /// inner corners go through polyline vertex (robust for stroking) instead of intersection of offset lines
+    through_center: bool,
+}
+
+impl<T: TrigScalar> Outline<T> {
+    fn normal(&self, direction: Vector<T>) -> Vector<T> {
+        Vector::from((T::zero(), self.offset)) * direction.rotor()
+    }
+
+    /// Offset polyline of distinct points
+    fn side(&self, points: &[Point<T>], closed: bool) -> Vec<Point<T>> {
+        let count = points.len();
+        let direction = |i: usize| points[(i + 1) % count] - points[i];
+        let mut out = Vec::new();
+        let vertices = if closed {
+            0..count
+        } else {
+            out.push(points[0] + self.normal(direction(0)));
+            1..count - 1
+        };
+        for i in vertices {
+            self.join(
+                points[i],
+                direction((i + count - 1) % count),
+                direction(i),
+                &mut out,
+            )
+        }
+        if !closed {
+            out.push(points[count - 1] + self.normal(direction(count - 2)))
+        }
+        out
+    }
+
+    fn join(&self, vertex: Point<T>, before: Vector<T>, after: Vector<T>, out: &mut Vec<Point<T>>) {
+        let (n0, n1) = (self.normal(before), self.normal(after));
+        let (a, b) = (vertex + n0, vertex + n1);
+        let cross = before.cross(after);
+        if cross == T::zero() && before.dot(after) > T::zero() {
+            out.push(a);
+            return;
+        }
+        // cosine of half angle between normals, miter length is `offset / cos_half`
+        let cos_half = ((T::one() + n0.dot(n1) / self.offset.sq()) / T::two()).sqrt();
+        let miter = || vertex + (n0 + n1).norm() * (self.offset.abs() / cos_half);
+        if cross * self.offset > T::zero() {
+            if self.through_center {
+                out.extend([a, vertex, b])
+            } else if cos_half > T::zero() {
+                out.push(miter())
+            }
+            return;
+        }
+        match self.join {
+            LineJoin::Miter { limit } if cos_half > T::zero() && T::one() <= limit * cos_half => {
+                out.push(miter())
+            }
+            LineJoin::Round => {
+                let angle = n0.cross(n1).abs().atan2(n0.dot(n1)).radians();
+                let turn = if cross == T::zero() {
+                    -self.offset
+                } else {
+                    cross
+                };
+                out.push(a);
+                self.arc(
+                    vertex,
+                    n0,
+                    if turn < T::zero() { -angle } else { angle },
+                    out,
+                );
+                out.push(b);
+            }
+            _ => out.extend([a, b]),
+        }
+    }
+
+    /// Points of circular arc around `center` starting at `center + from` and turning by `angle`, excluding both ends
+    fn arc(&self, center: Point<T>, from: Vector<T>, angle: T, out: &mut Vec<Point<T>>) {
+        let radius = from.len();
+        // largest step keeping chord within tolerance: 2·acos(1 - tolerance / radius)
+        let max_step = if self.tolerance < radius {
+            let cos = T::one() - self.tolerance / radius;
+            T::two() * (T::one() - cos.sq()).sqrt().atan2(cos).radians()
+        } else {
+            T::pi()
+        };
+        let max_step = if max_step > T::pi() / T::two() {
+            T::pi() / T::two()
+        } else {
+            max_step
+        };
+        let mut steps = 1;
+        while steps < MAX_ARC_STEPS && angle.abs() > max_step * from_u32(steps) {
+            steps += 1
+        }
+        let rotor = Complex::from_polar(T::one(), Angle::from_radians(angle / from_u32(steps)));
+        let mut radius = from;
+        for _ in 1..steps {
+            radius = radius * rotor;
+            out.push(center + radius)
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(any(feature = "std", feature = "libm"))]
+mod tests {
+    use super::{Dashes, LineCap, LineJoin, StrokeStyle, offset_polygon};
+    use crate::math::{Point, Positive, Rect};
+    use alloc::vec::Vec;
+
+    fn points(coordinates: &[(f64, f64)]) -> Vec<Point<f64>> {
+        coordinates.iter().map(|p| Point::from(*p)).collect()
+    }
+
+    fn bounds(points: &[Point<f64>]) -> [f64; 4] {
+        let rect = Rect::aabb_from_points(points.iter().copied()).unwrap();
+        [rect.left(), rect.right(), rect.top(), rect.bottom()]
+    }
+
+    fn twice_area(points: &[Point<f64>]) -> f64 {
+        (0..points.len())
+            .map(|i| {
+                let (a, b) = (points[i], points[(i + 1) % points.len()]);
+                a.x() * b.y() - a.y() * b.x()
+            })
+            .sum()
+    }
+
+    #[test]
+    fn caps_and_joins() {
+        use approx::assert_abs_diff_eq;
+
+        let tolerance = Positive::new(0.01).unwrap();
+        let mut style = StrokeStyle::new(Positive::new(2.).unwrap());
+        let line = points(&[(0., 0.), (10., 0.)]);
+        let polygons = style.stroke_polyline(&line, false, tolerance);
+        assert_eq!(polygons.len(), 1);
+        assert_eq!(
+            polygons[0],
+            points(&[(0., 1.), (10., 1.), (10., -1.), (0., -1.)])
+        );
+
+        style.cap = LineCap::Square;
+        let polygon = &style.stroke_polyline(&line, false, tolerance)[0];
+        assert_abs_diff_eq!(bounds(polygon)[..], [-1., 11., -1., 1.][..], epsilon = 1e-9);
+
+        style.cap = LineCap::Round;
+        let polygon = &style.stroke_polyline(&line, false, tolerance)[0];
+        assert!(polygon.len() > 10);
+        assert_abs_diff_eq!(bounds(polygon)[1], 11., epsilon = 0.01);
+        assert!(
+            polygon
+                .iter()
+                .all(|p| p.distance((0., 0.).into()) <= 1. + 1e-9
+                    || p.distance((10., 0.).into()) <= 1. + 1e-9)
+        );
+
+        let corner = points(&[(0., 0.), (10., 0.), (10., 10.)]);
+        let miter = style.stroke_polyline(&corner, false, tolerance)[0].clone();
+        style.join = LineJoin::Bevel;
+        let bevel = &style.stroke_polyline(&corner, false, tolerance)[0];
+        assert!(!bevel.iter().any(|p| p.x() > &10. && p.y() < &-0.5));
+        assert!(miter.iter().any(|p| p.x() > &10. && p.y() < &-0.5));
+
+        style.join = LineJoin::Miter { limit: 2. };
+        let polygon = &style.stroke_polyline(&corner, false, tolerance)[0];
+        assert!(polygon.iter().any(|p| p.distance((11., -1.).into()) < 1e-9));
+        style.join = LineJoin::Miter { limit: 1.2 };
+        let polygon = &style.stroke_polyline(&corner, false, tolerance)[0];
+        assert!(!polygon.iter().any(|p| p.distance((11., -1.).into()) < 1e-9));
+    }
+
+    #[test]
+    fn closed_stroke_and_offset() {
+        use approx::assert_abs_diff_eq;
+
+        let tolerance = Positive::new(0.01).unwrap();
+        let square = points(&[(0., 0.), (10., 0.), (10., 10.), (0., 10.), (0., 0.)]);
+        let style = StrokeStyle::new(Positive::new(2.).unwrap());
+        let rings = style.stroke_polyline(&square, true, tolerance);
+        assert_eq!(rings.len(), 2);
+        assert_abs_diff_eq!(twice_area(&rings[1]).abs(), 2. * 144., epsilon = 1e-9);
+        // inner ring goes through corners of polyline
+        assert!(rings[0].contains(&Point::from((10., 0.))));
+        assert!(twice_area(&rings[0]) * twice_area(&rings[1]) < 0.);
+
+        let join = LineJoin::Miter { limit: 4. };
+        let grown = offset_polygon(&square, 1., join, tolerance);
+        assert_abs_diff_eq!(bounds(&grown)[..], [-1., 11., -1., 11.][..], epsilon = 1e-9);
+        let reversed: Vec<_> = square.iter().rev().copied().collect();
+        let shrunk = offset_polygon(&reversed, -1., join, tolerance);
+        assert_abs_diff_eq!(bounds(&shrunk)[..], [1., 9., 1., 9.][..], epsilon = 1e-9);
+        assert_eq!(shrunk.len(), 4);
+
+        let round = offset_polygon(&square, 1., LineJoin::Round, tolerance);
+        assert!(round.len() > 8);
+        assert!(round.iter().all(|p| {
+            let inside = Rect::from_lrtb_unchecked(0., 10., 0., 10.).closest_point(*p);
+            (p.distance(inside) - 1.).abs() < 0.01
+        }));
+    }
+
+    #[test]
+    fn dashing() {
+        let line = points(&[(0., 0.), (5., 0.), (10., 0.)]);
+        let x_ranges = |dashes: Dashes<f64>| -> Vec<(f64, f64)> {
+            dashes
+                .apply(&line)
+                .iter()
+                .map(|dash| (*dash[0].x(), *dash[dash.len() - 1].x()))
+                .collect()
+        };
+        let pattern = [2., 1.];
+        assert_eq!(
+            x_ranges(Dashes::new(&pattern, 0.).unwrap()),
+            [(0., 2.), (3., 5.), (6., 8.), (9., 10.)]
+        );
+        assert_eq!(
+            x_ranges(Dashes::new(&pattern, 1.).unwrap()),
+            [(0., 1.), (2., 4.), (5., 7.), (8., 10.)]
+        );
+        assert_eq!(
+            x_ranges(Dashes::new(&[3.], -1.).unwrap()),
+            [(1., 4.), (7., 10.)]
+        );
+        assert!(Dashes::new(&[0., 0.], 0.).is_none());
+        assert!(Dashes::new(&[1., -1.], 0.).is_none());
+
+        let path = "M0 0 H9".parse::<crate::geometry::Path<f64>>().unwrap();
+        let mut style = StrokeStyle::new(Positive::new(1.).unwrap());
+        style.cap = LineCap::Round;
+        let dots = Dashes::new(&[0., 2.], 0.).unwrap();
+        let polygons = path.stroked(&style, Some(&dots), Positive::new(0.01).unwrap());
+        assert_eq!(polygons.len(), 5);
+    }
+}
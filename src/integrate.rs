@@ -0,0 +1,341 @@
+use crate::math::{One, Positive, Powf, Two, Zero, from_u32, ratio};
+use core::ops::{Add, Div, Mul, Sub};
+
+/// Element of a vector space over scalar `T`, e.g. `T` itself, `Vector<T>` or `Vector3<T>`
+pub trait State<T>: Clone + Add<Output = Self> + Mul<T, Output = Self> {}
+
+impl<S, T> State<T> for S where S: Clone + Add<Output = S> + Mul<T, Output = S> {}
+
+/// Explicit (forward) Euler method: `y + f(t, y)·dt`
+pub fn euler<S, T, F>(state: S, t: T, dt: T, mut f: F) -> S
+where
+    S: State<T>,
+    F: FnMut(T, &S) -> S,
+{
+    let k = f(t, &state);
+    state + k * dt
+}
+
+/// Semi-implicit (symplectic) Euler method: velocity is updated first and new velocity moves position.
+/// Position may be an affine type like `Point<T>` moved by velocity `Vector<T>`.
+pub fn semi_implicit_euler<P, V, T, F>(
+    position: P,
+    velocity: V,
+    dt: T,
+    mut acceleration: F,
+) -> (P, V)
+where
+    P: Add<V, Output = P>,
+    V: State<T>,
+    T: Clone,
+    F: FnMut(&P, &V) -> V,
+{
+    let velocity = velocity.clone() + acceleration(&position, &velocity) * dt.clone();
+    let position = position + velocity.clone() * dt;
+    (position, velocity)
+}
+
+/**
+ * @brief velocity_verlet - velocity Verlet method for position dependent acceleration
+ *  x' = x + v·dt + a·dt²/2
+ *  v' = v + (a + a(x'))·dt/2
+ * @param acceleration - acceleration at current position (returned from previous step)
+ * @return new position, velocity and acceleration
+ */
+pub fn velocity_verlet<P, V, T, F>(
+    position: P,
+    velocity: V,
+    acceleration: V,
+    dt: T,
+    mut f: F,
+) -> (P, V, V)
+where
+    P: Add<V, Output = P>,
+    V: State<T>,
+    T: Clone + Two + Mul<Output = T> + Div<Output = T>,
+    F: FnMut(&P) -> V,
+{
+    let half_dt = dt.clone() / T::two();
+    let position =
+        position + velocity.clone() * dt.clone() + acceleration.clone() * (dt * half_dt.clone());
+    let new_acceleration = f(&position);
+    let velocity = velocity + (acceleration + new_acceleration.clone()) * half_dt;
+    (position, velocity, new_acceleration)
+}
+
+/// Classic fourth order Runge–Kutta method
+pub fn rk4<S, T, F>(state: S, t: T, dt: T, mut f: F) -> S
+where
+    S: State<T>,
+    T: Clone + Zero + One + Two + Add<Output = T> + Mul<Output = T> + Div<Output = T>,
+    F: FnMut(T, &S) -> S,
+{
+    let half_dt = dt.clone() / T::two();
+    let k1 = f(t.clone(), &state);
+    let k2 = f(
+        t.clone() + half_dt.clone(),
+        &(state.clone() + k1.clone() * half_dt.clone()),
+    );
+    let k3 = f(
+        t.clone() + half_dt.clone(),
+        &(state.clone() + k2.clone() * half_dt),
+    );
+    let k4 = f(t + dt.clone(), &(state.clone() + k3.clone() * dt.clone()));
+    state + (k1 + (k2 + k3) * T::two() + k4) * (dt / from_u32(6))
+}
+
+/// Result of a single adaptive step
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdaptiveStep<S, T> {
+    /// Fifth order solution at `t + dt`
+    pub state: S,
+    /// Estimated local error measured by user supplied norm
+    pub error: T,
+    /// Whether error is within tolerance (or step is already minimal). Never true for NaN or infinite error
+    pub accepted: bool,
+    /// Suggested size of next step (or of retry if step is rejected)
+    pub next_dt: T,
+}
+
+/// Dormand–Prince embedded Runge–Kutta 5(4) method with step size control
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rk45<T> {
+    tolerance: T,
+    min_dt: T,
+    max_dt: T,
+}
+
+impl<T> Rk45<T> {
+    pub fn new(tolerance: Positive<T>, min_dt: Positive<T>, max_dt: Positive<T>) -> Self {
+        Self {
+            tolerance: tolerance.into_inner(),
+            min_dt: min_dt.into_inner(),
+            max_dt: max_dt.into_inner(),
+        }
+    }
+
+    pub fn tolerance(&self) -> &T {
+        &self.tolerance
+    }
+
+    /**
+     * @brief step - try single step of size `dt`
+     * @param norm - magnitude of error state (e.g. `Vector::len` or `Abs::abs`)
+     */
+    pub fn step<S, F, N>(&self, state: &S, t: T, dt: T, mut f: F, mut norm: N) -> AdaptiveStep<S, T>
+    where
+        S: State<T>,
+        T: Clone
+            + Zero
+            + One
+            + Two
+            + PartialOrd
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>
+            + Powf<Output = T>,
+        F: FnMut(T, &S) -> S,
+        N: FnMut(&S) -> T,
+    {
+        let r = ratio::<T>;
+        let at = |c: T| t.clone() + dt.clone() * c;
+        let y = |k: S| state.clone() + k * dt.clone();
+
+        let k1 = f(t.clone(), state);
+        let k2 = f(at(r(1, 5)), &y(k1.clone() * r(1, 5)));
+        let k3 = f(
+            at(r(3, 10)),
+            &y(k1.clone() * r(3, 40) + k2.clone() * r(9, 40)),
+        );
+        let k4 = f(
+            at(r(4, 5)),
+            &y(k1.clone() * r(44, 45) + k2.clone() * r(-56, 15) + k3.clone() * r(32, 9)),
+        );
+        let k5 = f(
+            at(r(8, 9)),
+            &y(k1.clone() * r(19372, 6561)
+                + k2.clone() * r(-25360, 2187)
+                + k3.clone() * r(64448, 6561)
+                + k4.clone() * r(-212, 729)),
+        );
+        let k6 = f(
+            at(T::one()),
+            &y(k1.clone() * r(9017, 3168)
+                + k2 * r(-355, 33)
+                + k3.clone() * r(46732, 5247)
+                + k4.clone() * r(49, 176)
+                + k5.clone() * r(-5103, 18656)),
+        );
+        let next = y(k1.clone() * r(35, 384)
+            + k3.clone() * r(500, 1113)
+            + k4.clone() * r(125, 192)
+            + k5.clone() * r(-2187, 6784)
+            + k6.clone() * r(11, 84));
+        let k7 = f(at(T::one()), &next);
+
+        // difference between fifth and fourth order solutions
+        let error = norm(
+            &((k1 * r(71, 57600)
+                + k3 * r(-71, 16695)
+                + k4 * r(71, 1920)
+                + k5 * r(-17253, 339200)
+                + k6 * r(22, 525)
+                + k7 * r(-1, 40))
+                * dt.clone()),
+        );
+
+        // false for NaN and infinities
+        let finite = error.clone() - error.clone() == T::zero();
+        let max_factor = from_u32::<T>(5);
+        let factor = if !finite {
+            r(1, 5)
+        } else if error > T::zero() {
+            let factor = r(9, 10) * (self.tolerance.clone() / error.clone()).powf(r(1, 5));
+            if factor < r(1, 5) {
+                r(1, 5)
+            } else if factor > max_factor {
+                max_factor
+            } else {
+                factor
+            }
+        } else {
+            max_factor
+        };
+        let next_dt = dt.clone() * factor;
+        let next_dt = if next_dt < self.min_dt {
+            self.min_dt.clone()
+        } else if next_dt > self.max_dt {
+            self.max_dt.clone()
+        } else {
+            next_dt
+        };
+
+        AdaptiveStep {
+            state: next,
+            accepted: finite && (error <= self.tolerance || dt <= self.min_dt),
+            error,
+            next_dt,
+        }
+    }
+
+    /// Integrate from `t0` to `t1` starting with step `dt`. Returns final state and suggested step for continuation
+    /// or None if error is not finite even with `min_dt` step (e.g. `f` returns NaN)
+    pub fn integrate<S, F, N>(
+        &self,
+        state: S,
+        t0: T,
+        t1: T,
+        dt: T,
+        mut f: F,
+        mut norm: N,
+    ) -> Option<(S, T)>
+    where
+        S: State<T>,
+        T: Clone
+            + Zero
+            + One
+            + Two
+            + PartialOrd
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>
+            + Powf<Output = T>,
+        F: FnMut(T, &S) -> S,
+        N: FnMut(&S) -> T,
+    {
+        let mut state = state;
+        let mut t = t0;
+        let mut dt = dt;
+        while t < t1 {
+            let remaining = t1.clone() - t.clone();
+            let step_dt = if dt > remaining {
+                remaining
+            } else {
+                dt.clone()
+            };
+            let step = self.step(&state, t.clone(), step_dt.clone(), &mut f, &mut norm);
+            if step.accepted {
+                state = step.state;
+                t = t + step_dt;
+            } else if step_dt <= self.min_dt {
+                return None;
+            }
+            dt = step.next_dt;
+        }
+        Some((state, dt))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{euler, rk4, semi_implicit_euler, velocity_verlet};
+    use crate::math::{Point, Vector};
+    use approx::assert_abs_diff_eq;
+
+    const E_INV: f64 = 0.36787944117144233;
+
+    fn energy(p: Point<f64>, v: Vector<f64>) -> f64 {
+        ((p - Point::origin()).len_sqr() + v.len_sqr()) / 2.
+    }
+
+    fn spring(p: &Point<f64>) -> Vector<f64> {
+        Point::origin() - *p
+    }
+
+    #[test]
+    fn first_order() {
+        let (mut a, mut b) = (1., 1.);
+        for i in 0..10 {
+            let t = i as f64 * 0.1;
+            a = euler(a, t, 0.1, |_, y: &f64| -y);
+            b = rk4(b, t, 0.1, |_, y: &f64| -y);
+        }
+        assert_abs_diff_eq!(a, 0.9_f64.powi(10), epsilon = 1e-12);
+        assert_abs_diff_eq!(b, E_INV, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn second_order_conserves_energy() {
+        let (mut p0, mut v0) = (Point::from((1., 0.)), Vector::from((0., 0.5)));
+        let (mut p1, mut v1, mut a1) = (p0, v0, spring(&p0));
+        let initial = energy(p0, v0);
+        for _ in 0..10000 {
+            (p0, v0) = semi_implicit_euler(p0, v0, 0.01, |p, _| spring(p));
+            (p1, v1, a1) = velocity_verlet(p1, v1, a1, 0.01, spring);
+        }
+        assert_abs_diff_eq!(energy(p0, v0), initial, epsilon = 1e-2);
+        assert_abs_diff_eq!(energy(p1, v1), initial, epsilon = 1e-4);
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn adaptive() {
+        use super::Rk45;
+        use crate::math::Positive;
+
+        let rk45 = Rk45::new(
+            Positive::new(1e-10).unwrap(),
+            Positive::new(1e-6).unwrap(),
+            Positive::new(1.).unwrap(),
+        );
+        let rejected = rk45.step(&1., 0., 1., |_, y: &f64| -y, |e: &f64| e.abs());
+        assert!(!rejected.accepted);
+        assert!(rejected.next_dt < 1.);
+
+        let (y, _) = rk45
+            .integrate(1., 0., 2., 0.5, |_, y: &f64| -y, |e: &f64| e.abs())
+            .unwrap();
+        assert_abs_diff_eq!(y, E_INV * E_INV, epsilon = 1e-9);
+
+        let nan = rk45.step(&1., 0., 1., |_, _: &f64| f64::NAN, |e: &f64| e.abs());
+        assert!(!nan.accepted);
+        assert_eq!(nan.next_dt, 0.2);
+        let stiff = |_, y: &f64| if *y < 0.5 { f64::INFINITY } else { -y };
+        assert!(
+            rk45.integrate(1., 0., 2., 0.5, stiff, |e: &f64| e.abs())
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use crate::math::{Point, Rect, Size, Vector};
+use euclid::{Point2D, Rect as EuclidRect, Size2D, Vector2D};
+
+impl<T, U> From<Point<T>> for Point2D<T, U> {
+    fn from(value: Point<T>) -> Self {
+        let (x, y) = value.into();
+        Point2D::new(x, y)
+    }
+}
+
+impl<T, U> From<Point2D<T, U>> for Point<T> {
+    fn from(value: Point2D<T, U>) -> Self {
+        (value.x, value.y).into()
+    }
+}
+
+impl<T, U> From<Vector<T>> for Vector2D<T, U> {
+    fn from(value: Vector<T>) -> Self {
+        let (x, y) = value.into();
+        Vector2D::new(x, y)
+    }
+}
+
+impl<T, U> From<Vector2D<T, U>> for Vector<T> {
+    fn from(value: Vector2D<T, U>) -> Self {
+        (value.x, value.y).into()
+    }
+}
+
+impl<T, U> From<Size<T>> for Size2D<T, U> {
+    fn from(value: Size<T>) -> Self {
+        let (w, h) = value.into();
+        Size2D::new(w, h)
+    }
+}
+
+impl<T, U> From<Size2D<T, U>> for Size<T> {
+    fn from(value: Size2D<T, U>) -> Self {
+        (value.width, value.height).into()
+    }
+}
+
+impl<T, U> From<Rect<T>> for EuclidRect<T, U> {
+    fn from(value: Rect<T>) -> Self {
+        let (x, y, w, h) = value.into();
+        EuclidRect::new(Point2D::new(x, y), Size2D::new(w, h))
+    }
+}
+
+impl<T, U> From<EuclidRect<T, U>> for Rect<T> {
+    fn from(value: EuclidRect<T, U>) -> Self {
+        (value.origin.into(), value.size.into()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::{Point, Rect, Size, Vector};
+    use euclid::default::{Point2D, Rect as EuclidRect, Size2D, Vector2D};
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Point2D::from(Point::from((1, 2))), Point2D::new(1, 2));
+        assert_eq!(Point::from(Point2D::new(1., 2.)), Point::from((1., 2.)));
+        assert_eq!(
+            Vector::from(Vector2D::from(Vector::from((3, -4)))),
+            Vector::from((3, -4))
+        );
+        assert_eq!(Size2D::from(Size::from((5u32, 6))), Size2D::new(5, 6));
+
+        let rect = EuclidRect::from(Rect::from((1, 2, 3, 4)));
+        assert_eq!(rect, EuclidRect::new(Point2D::new(1, 2), Size2D::new(3, 4)));
+        assert_eq!(<(i32, i32, i32, i32)>::from(Rect::from(rect)), (1, 2, 3, 4));
+    }
+}
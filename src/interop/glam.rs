@@ -0,0 +1,101 @@
+use crate::math::{Matrix, Point, Size, Vector};
+use glam::{DMat3, DVec2, IVec2, Mat3, UVec2, Vec2};
+
+macro_rules! impl_vec2_conversions {
+    ( $($vec: ident: $tp: ty),+ ) => {
+        $(
+            impl From<Point<$tp>> for $vec {
+                fn from(value: Point<$tp>) -> Self {
+                    let (x, y) = value.into();
+                    Self::new(x, y)
+                }
+            }
+
+            impl From<$vec> for Point<$tp> {
+                fn from(value: $vec) -> Self {
+                    (value.x, value.y).into()
+                }
+            }
+
+            impl From<Vector<$tp>> for $vec {
+                fn from(value: Vector<$tp>) -> Self {
+                    let (x, y) = value.into();
+                    Self::new(x, y)
+                }
+            }
+
+            impl From<$vec> for Vector<$tp> {
+                fn from(value: $vec) -> Self {
+                    (value.x, value.y).into()
+                }
+            }
+
+            /// Width and height as x and y
+            impl From<Size<$tp>> for $vec {
+                fn from(value: Size<$tp>) -> Self {
+                    let (w, h) = value.into();
+                    Self::new(w, h)
+                }
+            }
+
+            impl From<$vec> for Size<$tp> {
+                fn from(value: $vec) -> Self {
+                    (value.x, value.y).into()
+                }
+            }
+        )+
+    };
+}
+
+impl_vec2_conversions! { Vec2: f32, DVec2: f64, IVec2: i32, UVec2: u32 }
+
+macro_rules! impl_mat3_conversions {
+    ( $($mat: ident: $tp: ty),+ ) => {
+        $(
+            impl From<Matrix<$tp>> for $mat {
+                fn from(value: Matrix<$tp>) -> Self {
+                    // row-major elements read as columns give transposed matrix
+                    $mat::from_cols_array(&value.into()).transpose()
+                }
+            }
+
+            impl From<$mat> for Matrix<$tp> {
+                fn from(value: $mat) -> Self {
+                    value.transpose().to_cols_array().into()
+                }
+            }
+        )+
+    };
+}
+
+impl_mat3_conversions! { Mat3: f32, DMat3: f64 }
+
+#[cfg(test)]
+mod tests {
+    use crate::math::{Matrix, Point, Size, Vector};
+    use glam::{DMat3, DVec2, IVec2, Mat3, UVec2, Vec2};
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Vec2::from(Point::from((1f32, 2.))), Vec2::new(1., 2.));
+        assert_eq!(Point::from(IVec2::new(-1, 2)), Point::from((-1, 2)));
+        assert_eq!(
+            Vector::from(DVec2::from(Vector::from((3., 4.)))),
+            Vector::from((3., 4.))
+        );
+        assert_eq!(UVec2::from(Size::from((5u32, 6))), UVec2::new(5, 6));
+
+        let matrix = Matrix::translate((7., 8.).into()) * &Matrix::scale(2., 3.);
+        let mat = DMat3::from(matrix);
+        let point = Point::from((1., 1.));
+        assert_eq!(
+            Point::from(mat.transform_point2(point.into())),
+            &matrix * &point
+        );
+        assert_eq!(
+            <[f64; 9]>::from(Matrix::from(mat)),
+            <[f64; 9]>::from(matrix)
+        );
+        assert_eq!(Mat3::from(Matrix::<f32>::identity()), Mat3::IDENTITY);
+    }
+}
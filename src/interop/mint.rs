@@ -0,0 +1,113 @@
+use crate::math::{Matrix, Point, Size, Vector};
+use mint::{ColumnMatrix3, IntoMint, Point2, RowMatrix3, Vector2};
+
+impl<T> From<Point<T>> for Point2<T> {
+    fn from(value: Point<T>) -> Self {
+        let (x, y) = value.into();
+        Self { x, y }
+    }
+}
+
+impl<T> From<Point2<T>> for Point<T> {
+    fn from(value: Point2<T>) -> Self {
+        (value.x, value.y).into()
+    }
+}
+
+impl<T> IntoMint for Point<T> {
+    type MintType = Point2<T>;
+}
+
+impl<T> From<Vector<T>> for Vector2<T> {
+    fn from(value: Vector<T>) -> Self {
+        let (x, y) = value.into();
+        Self { x, y }
+    }
+}
+
+impl<T> From<Vector2<T>> for Vector<T> {
+    fn from(value: Vector2<T>) -> Self {
+        (value.x, value.y).into()
+    }
+}
+
+impl<T> IntoMint for Vector<T> {
+    type MintType = Vector2<T>;
+}
+
+/// Width and height as x and y
+impl<T> From<Size<T>> for Vector2<T> {
+    fn from(value: Size<T>) -> Self {
+        let (x, y) = value.into();
+        Self { x, y }
+    }
+}
+
+impl<T> From<Vector2<T>> for Size<T> {
+    fn from(value: Vector2<T>) -> Self {
+        (value.x, value.y).into()
+    }
+}
+
+impl<T> From<Matrix<T>> for RowMatrix3<T>
+where
+    T: Clone,
+{
+    fn from(value: Matrix<T>) -> Self {
+        <[T; 9]>::from(value).into()
+    }
+}
+
+impl<T> From<RowMatrix3<T>> for Matrix<T> {
+    fn from(value: RowMatrix3<T>) -> Self {
+        <[T; 9]>::from(value).into()
+    }
+}
+
+impl<T> From<Matrix<T>> for ColumnMatrix3<T>
+where
+    T: Clone,
+{
+    fn from(value: Matrix<T>) -> Self {
+        RowMatrix3::from(value).into()
+    }
+}
+
+impl<T> From<ColumnMatrix3<T>> for Matrix<T> {
+    fn from(value: ColumnMatrix3<T>) -> Self {
+        RowMatrix3::from(value).into()
+    }
+}
+
+impl<T: Clone> IntoMint for Matrix<T> {
+    type MintType = RowMatrix3<T>;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::{Matrix, Point, Size, Vector};
+    use mint::{ColumnMatrix3, Point2, RowMatrix3, Vector2};
+
+    #[test]
+    fn conversions() {
+        let point: Point2<i32> = Point::from((1, 2)).into();
+        assert_eq!(point, Point2 { x: 1, y: 2 });
+        assert_eq!(Point::from(point), Point::from((1, 2)));
+
+        let vector: Vector2<f64> = Vector::from((3., 4.)).into();
+        assert_eq!(vector, Vector2 { x: 3., y: 4. });
+        assert_eq!(Vector::from(vector), Vector::from((3., 4.)));
+        let size: (u32, u32) = Size::from(Vector2::from(Size::from((5u32, 6)))).into();
+        assert_eq!(size, (5, 6));
+
+        let matrix = Matrix::translate((7., 8.).into());
+        let rows = RowMatrix3::from(matrix);
+        assert_eq!((rows.x.z, rows.y.z), (7., 8.));
+        let columns = ColumnMatrix3::from(matrix);
+        assert_eq!((columns.z.x, columns.z.y), (7., 8.));
+        assert_eq!(
+            <[f64; 9]>::from(Matrix::from(columns)),
+            <[f64; 9]>::from(matrix)
+        );
+    }
+}
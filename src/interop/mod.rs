@@ -0,0 +1,8 @@
+#[cfg(feature = "euclid")]
+mod euclid;
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
@@ -0,0 +1,131 @@
+use crate::math::{Complex, Matrix, Point, Size, Vector};
+use nalgebra::{Matrix3, Point2, RealField, Scalar, UnitComplex, Vector2};
+
+impl<T: Scalar> From<Point<T>> for Point2<T> {
+    fn from(value: Point<T>) -> Self {
+        let (x, y) = value.into();
+        Self::new(x, y)
+    }
+}
+
+impl<T: Scalar> From<Point2<T>> for Point<T> {
+    fn from(value: Point2<T>) -> Self {
+        let [x, y] = value.coords.into();
+        (x, y).into()
+    }
+}
+
+impl<T: Scalar> From<Vector<T>> for Vector2<T> {
+    fn from(value: Vector<T>) -> Self {
+        let (x, y) = value.into();
+        Self::new(x, y)
+    }
+}
+
+impl<T: Scalar> From<Vector2<T>> for Vector<T> {
+    fn from(value: Vector2<T>) -> Self {
+        let [x, y] = value.into();
+        (x, y).into()
+    }
+}
+
+/// Width and height as x and y
+impl<T: Scalar> From<Size<T>> for Vector2<T> {
+    fn from(value: Size<T>) -> Self {
+        let (w, h) = value.into();
+        Self::new(w, h)
+    }
+}
+
+impl<T: Scalar> From<Vector2<T>> for Size<T> {
+    fn from(value: Vector2<T>) -> Self {
+        let [w, h] = value.into();
+        (w, h).into()
+    }
+}
+
+impl<T: Scalar> From<Matrix<T>> for Matrix3<T> {
+    fn from(value: Matrix<T>) -> Self {
+        let elements = <[T; 9]>::from(value);
+        Matrix3::from_fn(|row, column| elements[row * 3 + column].clone())
+    }
+}
+
+impl<T: Scalar> From<Matrix3<T>> for Matrix<T> {
+    fn from(value: Matrix3<T>) -> Self {
+        core::array::from_fn(|i| value[(i / 3, i % 3)].clone()).into()
+    }
+}
+
+impl<T> From<Complex<T>> for nalgebra::Complex<T> {
+    fn from(value: Complex<T>) -> Self {
+        let (re, im) = value.into_cartesian().into();
+        Self { re, im }
+    }
+}
+
+impl<T> From<nalgebra::Complex<T>> for Complex<T> {
+    fn from(value: nalgebra::Complex<T>) -> Self {
+        Complex::from_cartesian(value.re, value.im)
+    }
+}
+
+/// Normalizes the rotor
+impl<T: RealField> From<Complex<T>> for UnitComplex<T> {
+    fn from(value: Complex<T>) -> Self {
+        UnitComplex::from_complex(value.into())
+    }
+}
+
+impl<T> From<UnitComplex<T>> for Complex<T> {
+    fn from(value: UnitComplex<T>) -> Self {
+        value.into_inner().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::{Complex, Matrix, Point, Size, Vector};
+    use nalgebra::{Matrix3, Point2, Vector2};
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Point2::from(Point::from((1, 2))), Point2::new(1, 2));
+        assert_eq!(Point::from(Point2::new(1., 2.)), Point::from((1., 2.)));
+        assert_eq!(
+            Vector::from(Vector2::from(Vector::from((3, -4)))),
+            Vector::from((3, -4))
+        );
+        let size: (u32, u32) = Size::from(Vector2::from(Size::from((5u32, 6)))).into();
+        assert_eq!(size, (5, 6));
+
+        let matrix = Matrix::translate((7., 8.).into());
+        let matrix3 = Matrix3::from(matrix);
+        assert_eq!((matrix3[(0, 2)], matrix3[(1, 2)]), (7., 8.));
+        assert_eq!(
+            <[f64; 9]>::from(Matrix::from(matrix3)),
+            <[f64; 9]>::from(matrix)
+        );
+
+        let complex = nalgebra::Complex::from(Complex::from_cartesian(1., 2.));
+        assert_eq!((complex.re, complex.im), (1., 2.));
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn real_field() {
+        use approx::assert_abs_diff_eq;
+        use nalgebra::UnitComplex;
+
+        let matrix = Matrix::translate((7., 8.).into());
+        let point = Point::from((1., 2.));
+        assert_eq!(
+            Point::from(Matrix3::from(matrix).transform_point(&point.into())),
+            &matrix * &point
+        );
+
+        let rotor = UnitComplex::from(Complex::from_cartesian(3., 4.));
+        let (re, im) = Complex::from(rotor).into_cartesian().into();
+        assert_abs_diff_eq!([re, im].as_slice(), [0.6, 0.8].as_slice(), epsilon = 1e-12);
+    }
+}
@@ -7,9 +7,16 @@ compile_error!("Features 'std' and 'libm' are mutually exclusive.");
 #[cfg(feature = "std")]
 extern crate std;
 
+extern crate alloc;
+
 mod math;
 pub use math::*;
 
+mod interop;
+
 pub mod camera;
+pub mod camera3;
+pub mod geometry;
+pub mod integrate;
 pub mod physics;
 pub mod range;
@@ -2,7 +2,7 @@ use super::{Abs, Cos, DegToRad, IsNeg, NonNeg, Pi, RadToDeg, RemEuclid, Sin, Two
 use crate::range::Range;
 use core::{
     fmt::Display,
-    ops::{Add, AddAssign, Div, Mul, Rem, Sub},
+    ops::{Add, AddAssign, Div, Mul, Neg, Rem, Sub},
 };
 
 #[cfg(feature = "serde")]
@@ -39,6 +39,11 @@ impl<T> Angle<T> {
         normalize_delta_radians(self.0).rad_to_deg()
     }
 
+    /// Radians without normalization
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+
     pub fn cos(self) -> <T as Cos>::Output
     where
         T: Cos,
@@ -138,6 +143,11 @@ impl<T> DeltaAngle<T> {
     {
         normalize_delta_radians(self.value).rad_to_deg()
     }
+
+    /// Radians without normalization (e.g. angular velocity may exceed full turn)
+    pub fn into_inner(self) -> T {
+        self.value
+    }
 }
 
 impl<U: Display, T: RadToDeg<Output = U> + Clone> Display for DeltaAngle<T> {
@@ -208,6 +218,43 @@ where
     }
 }
 
+impl<T, U> Add<DeltaAngle<U>> for DeltaAngle<T>
+where
+    T: Add<U>,
+{
+    type Output = DeltaAngle<<T as Add<U>>::Output>;
+
+    fn add(self, rhs: DeltaAngle<U>) -> Self::Output {
+        Self::Output {
+            value: self.value + rhs.value,
+        }
+    }
+}
+
+impl<T, U> Sub<DeltaAngle<U>> for DeltaAngle<T>
+where
+    T: Sub<U>,
+{
+    type Output = DeltaAngle<<T as Sub<U>>::Output>;
+
+    fn sub(self, rhs: DeltaAngle<U>) -> Self::Output {
+        Self::Output {
+            value: self.value - rhs.value,
+        }
+    }
+}
+
+impl<T> Neg for DeltaAngle<T>
+where
+    T: Neg,
+{
+    type Output = DeltaAngle<<T as Neg>::Output>;
+
+    fn neg(self) -> Self::Output {
+        Self::Output { value: -self.value }
+    }
+}
+
 impl<T, U> Mul<U> for DeltaAngle<T>
 where
     T: Mul<U>,
@@ -0,0 +1,322 @@
+use super::{Point, Rect, Size, Vector};
+use core::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+/// Error of checked numeric conversion
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CastError {
+    /// Value is out of range of target type
+    Overflow,
+    NaN,
+}
+
+impl Display for CastError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            CastError::Overflow => f.write_str("value is out of range of target type"),
+            CastError::NaN => f.write_str("value is NaN"),
+        }
+    }
+}
+
+impl Error for CastError {}
+
+/// Conversion between primitive numbers.
+/// Floats are truncated towards zero when converted into integers unless stated otherwise
+pub trait Cast<U> {
+    /// Same as `as` operator: integers wrap, floats saturate and NaN becomes zero
+    fn cast(self) -> U;
+    /// Error if value does not fit into target type or is NaN. Loss of precision is not an error
+    fn try_cast(self) -> Result<U, CastError>;
+    /// Out of range values are clamped to closest representable one, NaN becomes zero for integers
+    fn saturating_cast(self) -> U;
+    /// Same as `saturating_cast` but floats are rounded to nearest integer (halves away from zero)
+    fn round_cast(self) -> U;
+}
+
+/// Nearest integer value, halves rounded away from zero
+fn round(value: f64) -> f64 {
+    // such large values have no fractional part
+    if value.is_nan() || value.abs() >= 4_503_599_627_370_496. {
+        return value;
+    }
+    let truncated = value as i64 as f64;
+    let fraction = value - truncated;
+    if fraction >= 0.5 {
+        truncated + 1.
+    } else if fraction <= -0.5 {
+        truncated - 1.
+    } else {
+        truncated
+    }
+}
+
+macro_rules! impl_int_to_int {
+    ( $($from: ty),+ => $to: tt ) => {
+        $( impl_int_to_int!(@one $from => $to); )+
+    };
+    ( @one $from: ty => [$($to: ty),+] ) => {
+        $(
+            impl Cast<$to> for $from {
+                fn cast(self) -> $to {
+                    self as $to
+                }
+
+                fn try_cast(self) -> Result<$to, CastError> {
+                    <$to>::try_from(self).map_err(|_| CastError::Overflow)
+                }
+
+                fn saturating_cast(self) -> $to {
+                    <$to>::try_from(self).unwrap_or(if self > 0 { <$to>::MAX } else { <$to>::MIN })
+                }
+
+                fn round_cast(self) -> $to {
+                    self.saturating_cast()
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_int_to_float {
+    ( $($from: ty),+ => $to: tt ) => {
+        $( impl_int_to_float!(@one $from => $to); )+
+    };
+    ( @one $from: ty => [$($to: ty),+] ) => {
+        $(
+            impl Cast<$to> for $from {
+                fn cast(self) -> $to {
+                    self as $to
+                }
+
+                fn try_cast(self) -> Result<$to, CastError> {
+                    Ok(self as $to)
+                }
+
+                fn saturating_cast(self) -> $to {
+                    self as $to
+                }
+
+                fn round_cast(self) -> $to {
+                    self as $to
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_float_to_int {
+    ( $($from: ty),+ => $to: tt ) => {
+        $( impl_float_to_int!(@one $from => $to); )+
+    };
+    ( @one $from: ty => [$($to: ty),+] ) => {
+        $(
+            impl Cast<$to> for $from {
+                fn cast(self) -> $to {
+                    self as $to
+                }
+
+                fn try_cast(self) -> Result<$to, CastError> {
+                    if self.is_nan() {
+                        return Err(CastError::NaN);
+                    }
+                    // bounds are powers of two so they are exact (or infinite) in any float type
+                    let min = <$to>::MIN as $from;
+                    let max_exclusive = (<$to>::MAX / 2 + 1) as $from * 2.;
+                    if (self > min - 1. || self >= min) && self < max_exclusive {
+                        Ok(self as $to)
+                    } else {
+                        Err(CastError::Overflow)
+                    }
+                }
+
+                fn saturating_cast(self) -> $to {
+                    self as $to
+                }
+
+                fn round_cast(self) -> $to {
+                    round(self as f64) as $to
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_float_to_float {
+    ( $($from: ty),+ => $to: tt ) => {
+        $( impl_float_to_float!(@one $from => $to); )+
+    };
+    ( @one $from: ty => [$($to: ty),+] ) => {
+        $(
+            impl Cast<$to> for $from {
+                fn cast(self) -> $to {
+                    self as $to
+                }
+
+                fn try_cast(self) -> Result<$to, CastError> {
+                    let result = self as $to;
+                    if self.is_nan() {
+                        Err(CastError::NaN)
+                    } else if self.is_finite() && result.is_infinite() {
+                        Err(CastError::Overflow)
+                    } else {
+                        Ok(result)
+                    }
+                }
+
+                fn saturating_cast(self) -> $to {
+                    let result = self as $to;
+                    if self.is_finite() && result.is_infinite() {
+                        if self > 0. { <$to>::MAX } else { <$to>::MIN }
+                    } else {
+                        result
+                    }
+                }
+
+                fn round_cast(self) -> $to {
+                    self.saturating_cast()
+                }
+            }
+        )+
+    };
+}
+
+impl_int_to_int! {
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+        => [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize]
+}
+
+impl_int_to_float! {
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize => [f32, f64]
+}
+
+impl_float_to_int! {
+    f32, f64 => [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize]
+}
+
+impl_float_to_float! { f32, f64 => [f32, f64] }
+
+macro_rules! impl_componentwise_cast {
+    ( $($tp: ident ($($component: ident),+)),+ ) => {
+        $(
+            impl<T> $tp<T> {
+                /// Componentwise `Cast::cast`
+                pub fn cast<U>(self) -> $tp<U>
+                where
+                    T: Cast<U>,
+                {
+                    let ($($component),+) = self.into();
+                    ($($component.cast()),+).into()
+                }
+
+                /// Componentwise `Cast::try_cast`
+                pub fn try_cast<U>(self) -> Result<$tp<U>, CastError>
+                where
+                    T: Cast<U>,
+                {
+                    let ($($component),+) = self.into();
+                    Ok(($($component.try_cast()?),+).into())
+                }
+
+                /// Componentwise `Cast::saturating_cast`
+                pub fn saturating_cast<U>(self) -> $tp<U>
+                where
+                    T: Cast<U>,
+                {
+                    let ($($component),+) = self.into();
+                    ($($component.saturating_cast()),+).into()
+                }
+
+                /// Componentwise `Cast::round_cast`
+                pub fn round_cast<U>(self) -> $tp<U>
+                where
+                    T: Cast<U>,
+                {
+                    let ($($component),+) = self.into();
+                    ($($component.round_cast()),+).into()
+                }
+            }
+        )+
+    };
+}
+
+impl_componentwise_cast! { Point(x, y), Vector(x, y), Size(w, h), Rect(x, y, w, h) }
+
+#[cfg(test)]
+mod tests {
+    use super::{Cast, CastError};
+    use crate::math::{Matrix, Point, Rect, Size, Vector};
+
+    #[test]
+    fn primitives() {
+        assert_eq!(Cast::<u8>::cast(300i32), 44);
+        assert_eq!(Cast::<u8>::try_cast(300i32), Err(CastError::Overflow));
+        assert_eq!(Cast::<u8>::try_cast(-1i32), Err(CastError::Overflow));
+        assert_eq!(Cast::<u8>::saturating_cast(300i32), 255);
+        assert_eq!(Cast::<u8>::saturating_cast(-5i64), 0);
+        assert_eq!(Cast::<i8>::saturating_cast(u128::MAX), 127);
+        assert_eq!(Cast::<u64>::try_cast(7u8), Ok(7));
+
+        assert_eq!(Cast::<i32>::cast(-2.7f64), -2);
+        assert_eq!(Cast::<i32>::round_cast(-2.5f64), -3);
+        assert_eq!(Cast::<i32>::round_cast(2.4f32), 2);
+        assert_eq!(Cast::<u8>::round_cast(255.6f64), 255);
+        assert_eq!(Cast::<u8>::try_cast(255.9f64), Ok(255));
+        assert_eq!(Cast::<u8>::try_cast(256f64), Err(CastError::Overflow));
+        assert_eq!(Cast::<u8>::try_cast(-0.5f64), Ok(0));
+        assert_eq!(Cast::<i8>::try_cast(-128.5f64), Ok(-128));
+        assert_eq!(Cast::<i8>::try_cast(-129f32), Err(CastError::Overflow));
+        assert_eq!(Cast::<i64>::try_cast(i64::MIN as f64), Ok(i64::MIN));
+        assert_eq!(
+            Cast::<i64>::try_cast(i64::MAX as f64),
+            Err(CastError::Overflow)
+        );
+        assert_eq!(
+            Cast::<u64>::try_cast(u64::MAX as f32),
+            Err(CastError::Overflow)
+        );
+        assert_eq!(Cast::<u128>::try_cast(f32::MAX), Ok(f32::MAX as u128));
+        assert_eq!(Cast::<i32>::try_cast(f64::NAN), Err(CastError::NaN));
+        assert_eq!(Cast::<i32>::saturating_cast(f64::NAN), 0);
+        assert_eq!(Cast::<i32>::saturating_cast(f64::INFINITY), i32::MAX);
+
+        assert_eq!(Cast::<f32>::try_cast(1e300f64), Err(CastError::Overflow));
+        assert_eq!(Cast::<f32>::saturating_cast(-1e300f64), f32::MIN);
+        assert_eq!(Cast::<f32>::try_cast(f64::INFINITY), Ok(f32::INFINITY));
+        assert_eq!(Cast::<f64>::try_cast(f32::NAN), Err(CastError::NaN));
+        assert_eq!(Cast::<f32>::cast(u128::MAX), u128::MAX as f32);
+    }
+
+    #[test]
+    fn geometry() {
+        let point = Point::from((1.6, -2.5));
+        assert_eq!(point.cast::<i32>(), Point::from((1, -2)));
+        assert_eq!(point.round_cast::<i32>(), Point::from((2, -3)));
+        assert_eq!(point.try_cast::<u32>(), Err(CastError::Overflow));
+        assert_eq!(point.saturating_cast::<u32>(), Point::from((1, 0)));
+        assert_eq!(
+            Vector::from((300, 5)).saturating_cast::<u8>(),
+            Vector::from((255, 5))
+        );
+        let size: (f32, f32) = Size::from((3u32, 4)).cast::<f32>().into();
+        assert_eq!(size, (3., 4.));
+        let rect: (i16, i16, i16, i16) =
+            Rect::from((0.5, 1.5, 10., 20.)).round_cast::<i16>().into();
+        assert_eq!(rect, (1, 2, 10, 20));
+
+        assert_eq!(Vector::from(Point::from((1, 2))), Vector::from((1, 2)));
+        assert_eq!(Point::from(Vector::from([3, 4])), Point::from([3, 4]));
+        assert_eq!(<[i32; 2]>::from(Vector::from((5, 6)).yx()), [6, 5]);
+        assert_eq!(Point::from((1, 2)).yx(), Point::from((2, 1)));
+        assert_eq!(<(u8, u8)>::from(Size::from([1, 2]).hw()), (2, 1));
+
+        let matrix = Matrix::translate((0.4, -1.6).into());
+        assert_eq!(
+            <[i32; 9]>::from(matrix.round_cast()),
+            [1, 0, 0, 0, 1, -2, 0, 0, 1]
+        );
+        assert_eq!(matrix.try_cast::<u8>().err(), Some(CastError::Overflow));
+    }
+}
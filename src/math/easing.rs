@@ -0,0 +1,483 @@
+use super::{Abs, Cos, Interpolate, One, Pi, Powf, Sin, Sqrt, Two, Zero, from_u32, ratio};
+use core::ops::{Add, Div, Mul, Sub};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Scalar suitable for easing functions: `f32` and `f64` with either `std` or `libm` feature
+pub trait EaseScalar:
+    Clone
+    + PartialOrd
+    + Zero
+    + One
+    + Two
+    + Pi
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Abs<Output = Self>
+    + Sin<Output = Self>
+    + Cos<Output = Self>
+    + Sqrt<Output = Self>
+    + Powf<Output = Self>
+{
+}
+
+impl<T> EaseScalar for T where
+    T: Clone
+        + PartialOrd
+        + Zero
+        + One
+        + Two
+        + Pi
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Abs<Output = T>
+        + Sin<Output = T>
+        + Cos<Output = T>
+        + Sqrt<Output = T>
+        + Powf<Output = T>
+{
+}
+
+/// Shape of easing curve (see https://easings.net)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EaseCurve {
+    Quad,
+    Cubic,
+    Quart,
+    Quint,
+    Sine,
+    Expo,
+    Circ,
+    Back,
+    Elastic,
+    Bounce,
+}
+
+impl EaseCurve {
+    /// Accelerating ("in") form of curve, `0 → 0` and `1 → 1`
+    pub fn ease_in<T: EaseScalar>(self, t: T) -> T {
+        let pow = |n: u32| (1..n).fold(t.clone(), |acc, _| acc * t.clone());
+        match self {
+            EaseCurve::Quad => pow(2),
+            EaseCurve::Cubic => pow(3),
+            EaseCurve::Quart => pow(4),
+            EaseCurve::Quint => pow(5),
+            EaseCurve::Sine => T::one() - (t * T::pi() / T::two()).cos(),
+            EaseCurve::Expo => {
+                if t <= T::zero() {
+                    T::zero()
+                } else {
+                    T::two().powf(from_u32::<T>(10) * (t - T::one()))
+                }
+            }
+            EaseCurve::Circ => T::one() - (T::one() - pow(2)).sqrt(),
+            EaseCurve::Back => back_in(t, ratio(170158, 100000)),
+            EaseCurve::Elastic => {
+                if t <= T::zero() || t >= T::one() {
+                    t
+                } else {
+                    let ten = from_u32::<T>(10);
+                    let c4 = T::two() * T::pi() / from_u32(3);
+                    T::zero()
+                        - T::two().powf(ten.clone() * t.clone() - ten.clone())
+                            * ((ten * t - ratio(43, 4)) * c4).sin()
+                }
+            }
+            EaseCurve::Bounce => T::one() - bounce_out(T::one() - t),
+        }
+    }
+
+    /// Decelerating ("out") form of curve: `1 - ease_in(1 - t)`
+    pub fn ease_out<T: EaseScalar>(self, t: T) -> T {
+        T::one() - self.ease_in(T::one() - t)
+    }
+
+    /// `ease_in` on first half and `ease_out` on second half.
+    /// `Back` and `Elastic` use their own in-out constants as on https://easings.net
+    pub fn ease_in_out<T: EaseScalar>(self, t: T) -> T {
+        let two = T::two();
+        let half = T::one() / T::two();
+        match self {
+            EaseCurve::Back => {
+                let c2 = ratio::<T>(170158, 100000) * ratio(1525, 1000);
+                if t < half {
+                    back_in(two.clone() * t, c2) / two
+                } else {
+                    T::one() - back_in(two.clone() - two.clone() * t, c2) / two
+                }
+            }
+            EaseCurve::Elastic => {
+                if t <= T::zero() || t >= T::one() {
+                    return t;
+                }
+                let twenty = from_u32::<T>(20);
+                let ten = from_u32::<T>(10);
+                let c5 = T::two() * T::pi() / ratio(9, 2);
+                let sin = ((twenty.clone() * t.clone() - ratio(89, 8)) * c5).sin();
+                if t < half {
+                    T::zero() - T::two().powf(twenty * t - ten) * sin / two
+                } else {
+                    T::two().powf(ten - twenty * t) * sin / two + T::one()
+                }
+            }
+            _ => {
+                if t < half {
+                    self.ease_in(two.clone() * t) / two
+                } else {
+                    T::one() - self.ease_in(two.clone() - two.clone() * t) / two
+                }
+            }
+        }
+    }
+}
+
+/// `(c + 1)·t³ - c·t²`, overshooting below zero by amount controlled with `c`
+fn back_in<T: EaseScalar>(t: T, c: T) -> T {
+    let t2 = t.clone() * t.clone();
+    (c.clone() + T::one()) * t2.clone() * t - c * t2
+}
+
+fn bounce_out<T: EaseScalar>(t: T) -> T {
+    let n1 = ratio::<T>(121, 16);
+    let d1 = ratio::<T>(11, 4);
+    let r = |n, d| ratio::<T>(n, d);
+    if t < T::one() / d1.clone() {
+        n1 * t.clone() * t
+    } else if t < T::two() / d1.clone() {
+        let t = t - r(3, 2) / d1;
+        n1 * t.clone() * t + r(3, 4)
+    } else if t < r(5, 2) / d1.clone() {
+        let t = t - r(9, 4) / d1;
+        n1 * t.clone() * t + r(15, 16)
+    } else {
+        let t = t - r(21, 8) / d1;
+        n1 * t.clone() * t + r(63, 64)
+    }
+}
+
+/// CSS `cubic-bezier(x1, y1, x2, y2)` timing function: curve from `(0, 0)` to `(1, 1)` with two control points
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        try_from = "RawCubicBezierTiming<T>",
+        bound(deserialize = "T: Deserialize<'de> + Zero + One + PartialOrd")
+    )
+)]
+pub struct CubicBezierTiming<T> {
+    x1: T,
+    y1: T,
+    x2: T,
+    y2: T,
+}
+
+/// Unvalidated `CubicBezierTiming` fields, deserialized before the range check of `CubicBezierTiming::new`
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RawCubicBezierTiming<T> {
+    x1: T,
+    y1: T,
+    x2: T,
+    y2: T,
+}
+
+#[cfg(feature = "serde")]
+impl<T> TryFrom<RawCubicBezierTiming<T>> for CubicBezierTiming<T>
+where
+    T: Zero + One + PartialOrd,
+{
+    type Error = &'static str;
+
+    fn try_from(value: RawCubicBezierTiming<T>) -> Result<Self, Self::Error> {
+        Self::new(value.x1, value.y1, value.x2, value.y2).ok_or("x1 and x2 must be in range 0..=1")
+    }
+}
+
+impl<T> CubicBezierTiming<T> {
+    /// Returns `None` if `x1` or `x2` is out of range `0..=1` (curve would not be a function of time)
+    pub fn new(x1: T, y1: T, x2: T, y2: T) -> Option<Self>
+    where
+        T: Zero + One + PartialOrd,
+    {
+        let valid = |x: &T| *x >= T::zero() && *x <= T::one();
+        if valid(&x1) && valid(&x2) {
+            Some(Self { x1, y1, x2, y2 })
+        } else {
+            None
+        }
+    }
+
+    /// CSS `ease`
+    pub fn css_ease() -> Self
+    where
+        T: EaseScalar,
+    {
+        Self::from_ratios(25, 10, 25, 100)
+    }
+
+    /// CSS `ease-in`
+    pub fn css_ease_in() -> Self
+    where
+        T: EaseScalar,
+    {
+        Self::from_ratios(42, 0, 100, 100)
+    }
+
+    /// CSS `ease-out`
+    pub fn css_ease_out() -> Self
+    where
+        T: EaseScalar,
+    {
+        Self::from_ratios(0, 0, 58, 100)
+    }
+
+    /// CSS `ease-in-out`
+    pub fn css_ease_in_out() -> Self
+    where
+        T: EaseScalar,
+    {
+        Self::from_ratios(42, 0, 58, 100)
+    }
+
+    fn from_ratios(x1: i32, y1: i32, x2: i32, y2: i32) -> Self
+    where
+        T: EaseScalar,
+    {
+        Self {
+            x1: ratio(x1, 100),
+            y1: ratio(y1, 100),
+            x2: ratio(x2, 100),
+            y2: ratio(y2, 100),
+        }
+    }
+
+    pub fn x1(&self) -> &T {
+        &self.x1
+    }
+
+    pub fn y1(&self) -> &T {
+        &self.y1
+    }
+
+    pub fn x2(&self) -> &T {
+        &self.x2
+    }
+
+    pub fn y2(&self) -> &T {
+        &self.y2
+    }
+
+    /// Progress at time `t` in range `0..=1`
+    pub fn ease(&self, t: T) -> T
+    where
+        T: EaseScalar,
+    {
+        if t <= T::zero() || t >= T::one() {
+            return t;
+        }
+        let s = self.solve_x(t);
+        bezier(self.y1.clone(), self.y2.clone(), s)
+    }
+
+    /// Curve parameter `s` such that `x(s) = x`.
+    /// Newton–Raphson converges in a few iterations on most curves, bisection is fallback for flat derivative.
+    fn solve_x(&self, x: T) -> T
+    where
+        T: EaseScalar,
+    {
+        let epsilon = ratio::<T>(1, 10_000_000);
+        let x_at = |s: T| bezier(self.x1.clone(), self.x2.clone(), s) - x.clone();
+
+        let mut s = x.clone();
+        for _ in 0..8 {
+            let error = x_at(s.clone());
+            if error.clone().abs() < epsilon {
+                return s;
+            }
+            let derivative = bezier_derivative(self.x1.clone(), self.x2.clone(), s.clone());
+            if derivative.clone().abs() < epsilon {
+                break;
+            }
+            s = s - error / derivative;
+        }
+
+        let (mut low, mut high) = (T::zero(), T::one());
+        s = x.clone();
+        for _ in 0..64 {
+            let error = x_at(s.clone());
+            if error.clone().abs() < epsilon {
+                break;
+            }
+            if error > T::zero() {
+                high = s.clone();
+            } else {
+                low = s.clone();
+            }
+            s = (low.clone() + high.clone()) / T::two();
+        }
+        s
+    }
+}
+
+/// One coordinate of cubic Bezier with end points `0` and `1`
+fn bezier<T: EaseScalar>(p1: T, p2: T, s: T) -> T {
+    let three = from_u32::<T>(3);
+    let u = T::one() - s.clone();
+    three.clone() * u.clone() * u.clone() * s.clone() * p1
+        + three * u * s.clone() * s.clone() * p2
+        + s.clone() * s.clone() * s
+}
+
+fn bezier_derivative<T: EaseScalar>(p1: T, p2: T, s: T) -> T {
+    let three = from_u32::<T>(3);
+    let six = from_u32::<T>(6);
+    let u = T::one() - s.clone();
+    three.clone() * u.clone() * u.clone() * p1.clone()
+        + six * u.clone() * s.clone() * (p2.clone() - p1)
+        + three * s.clone() * s * (T::one() - p2)
+}
+
+/// Easing function mapping linear progress `0..=1` to eased progress
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(deserialize = "T: Deserialize<'de> + Zero + One + PartialOrd"))
+)]
+pub enum Easing<T> {
+    Linear,
+    In(EaseCurve),
+    Out(EaseCurve),
+    InOut(EaseCurve),
+    CubicBezier(CubicBezierTiming<T>),
+}
+
+impl<T: EaseScalar> Easing<T> {
+    pub fn ease(&self, t: T) -> T {
+        match self {
+            Easing::Linear => t,
+            Easing::In(curve) => curve.ease_in(t),
+            Easing::Out(curve) => curve.ease_out(t),
+            Easing::InOut(curve) => curve.ease_in_out(t),
+            Easing::CubicBezier(timing) => timing.ease(t),
+        }
+    }
+
+    /// Blend `a` and `b` with eased `t`
+    pub fn interpolate<V: Interpolate<T>>(&self, a: V, b: V, t: T) -> V {
+        V::interpolate(a, b, self.ease(t))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn end_points() {
+        use super::{EaseCurve, Easing};
+        use approx::assert_abs_diff_eq;
+
+        let curves = [
+            EaseCurve::Quad,
+            EaseCurve::Cubic,
+            EaseCurve::Quart,
+            EaseCurve::Quint,
+            EaseCurve::Sine,
+            EaseCurve::Expo,
+            EaseCurve::Circ,
+            EaseCurve::Back,
+            EaseCurve::Elastic,
+            EaseCurve::Bounce,
+        ];
+        for curve in curves {
+            for easing in [Easing::In(curve), Easing::Out(curve), Easing::InOut(curve)] {
+                assert_abs_diff_eq!(easing.ease(0_f64), 0., epsilon = 1e-3);
+                assert_abs_diff_eq!(easing.ease(1_f64), 1., epsilon = 1e-3);
+            }
+            assert_abs_diff_eq!(Easing::InOut(curve).ease(0.5_f64), 0.5, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn known_values() {
+        use super::EaseCurve;
+        use approx::assert_abs_diff_eq;
+
+        assert_abs_diff_eq!(EaseCurve::Quad.ease_in(0.5_f64), 0.25);
+        assert_abs_diff_eq!(EaseCurve::Cubic.ease_out(0.5_f64), 0.875);
+        assert_abs_diff_eq!(EaseCurve::Quart.ease_in_out(0.25_f64), 0.03125);
+        assert_abs_diff_eq!(
+            EaseCurve::Sine.ease_in(1. / 3_f64),
+            0.1339746,
+            epsilon = 1e-6
+        );
+        assert_abs_diff_eq!(EaseCurve::Expo.ease_in(0.5_f64), 0.03125);
+        assert_abs_diff_eq!(EaseCurve::Bounce.ease_out(0.5_f64), 0.765625);
+        assert!(EaseCurve::Back.ease_in(0.2_f64) < 0.);
+        assert!(EaseCurve::Elastic.ease_out(0.2_f64) > 1.);
+
+        // values from https://easings.net easeInOutBack and easeInOutElastic
+        for (t, back, elastic) in [
+            (0.25_f64, -0.09968184375, 0.011969444423734044),
+            (0.4, 0.089925792, -0.11746157759823853),
+            (0.75, 1.09968184375, 0.988030555576266),
+            (0.9, 1.037518552, 0.9996608434029943),
+        ] {
+            assert_abs_diff_eq!(EaseCurve::Back.ease_in_out(t), back, epsilon = 1e-9);
+            assert_abs_diff_eq!(EaseCurve::Elastic.ease_in_out(t), elastic, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn cubic_bezier() {
+        use super::CubicBezierTiming;
+        use approx::assert_abs_diff_eq;
+
+        assert!(CubicBezierTiming::new(1.5, 0., 0.5, 1.).is_none());
+
+        let linear = CubicBezierTiming::new(0.25, 0.25, 0.75, 0.75).unwrap();
+        assert_abs_diff_eq!(linear.ease(0.3_f64), 0.3, epsilon = 1e-6);
+
+        // values from browser `cubic-bezier(0.25, 0.1, 0.25, 1)`
+        let ease = CubicBezierTiming::<f64>::css_ease();
+        assert_abs_diff_eq!(ease.ease(0.25), 0.4085, epsilon = 1e-3);
+        assert_abs_diff_eq!(ease.ease(0.5), 0.8024, epsilon = 1e-3);
+
+        // steep start where derivative of x vanishes
+        let steep = CubicBezierTiming::new(0., 1., 0., 1.).unwrap();
+        for i in 0..=10 {
+            let t = i as f64 / 10.;
+            let y = steep.ease(t);
+            assert!((0. ..=1.).contains(&y));
+        }
+        let ease_in_out = CubicBezierTiming::<f64>::css_ease_in_out();
+        assert_abs_diff_eq!(ease_in_out.ease(0.5), 0.5, epsilon = 1e-6);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn cubic_bezier_serde() {
+        use super::CubicBezierTiming;
+
+        let timing = CubicBezierTiming::new(0.25, 0.1, 0.25, 1.).unwrap();
+        let json = serde_json::to_string(&timing).unwrap();
+        assert_eq!(json, r#"{"x1":0.25,"y1":0.1,"x2":0.25,"y2":1.0}"#);
+        assert_eq!(
+            serde_json::from_str::<CubicBezierTiming<f64>>(&json).unwrap(),
+            timing
+        );
+        assert!(
+            serde_json::from_str::<CubicBezierTiming<f64>>(r#"{"x1":1.5,"y1":0,"x2":0.5,"y2":1}"#)
+                .is_err()
+        );
+    }
+}
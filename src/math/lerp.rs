@@ -1,5 +1,5 @@
-use crate::math::One;
-use core::ops::{Add, Mul, Sub};
+use crate::math::{Exp, One};
+use core::ops::{Add, Mul, Neg, Sub};
 
 pub fn lerp<V, T>(a: V, b: V, t: T) -> V
 where
@@ -23,6 +23,32 @@ where
     a * (T::one() - t.clone()) + b * t
 }
 
+/// Lerp parameter covering part of remaining distance after time `dt` when it decays as `exp(-rate * dt)`:
+/// `1 - exp(-rate * dt)`. Applying it twice with `dt / 2` gives the same result as once with `dt`
+pub fn lerp_factor_dt<T>(rate: T, dt: T) -> T
+where
+    T: One + Sub<Output = T> + Mul<Output = T> + Neg<Output = T> + Exp<Output = T>,
+{
+    T::one() - (-(rate * dt)).exp()
+}
+
+/// Blending between two values of the same type.
+/// Every type with scalar multiplication and addition is blended with `lerp2`, other types (e.g. `Matrix`) may provide their own way.
+pub trait Interpolate<T> {
+    fn interpolate(a: Self, b: Self, t: T) -> Self;
+}
+
+impl<V, T> Interpolate<T> for V
+where
+    V: Mul<T, Output = V>,
+    V: Add<Output = V>,
+    T: Clone + One + Sub<Output = T>,
+{
+    fn interpolate(a: Self, b: Self, t: T) -> Self {
+        lerp2(a, b, t)
+    }
+}
+
 pub struct LerpIntegrator<V, T> {
     t: T,
     prev: Option<V>,
@@ -35,13 +61,58 @@ impl<V, T> LerpIntegrator<V, T> {
 
     pub fn proceed(&mut self, v: V) -> &V
     where
-        V: Mul<T, Output = V>,
-        V: Add<Output = V>,
-        V: Clone,
-        T: Clone + One + Sub<Output = T>,
+        V: Interpolate<T> + Clone,
+        T: Clone,
     {
         let prev = self.prev.get_or_insert(v.clone());
-        *prev = lerp2(prev.clone(), v, self.t.clone());
+        *prev = V::interpolate(prev.clone(), v, self.t.clone());
         prev
     }
+
+    /// Frame-rate independent version of `proceed`.
+    /// Here `t` is a decay rate: after time `dt` the remaining distance to `v` is multiplied by `exp(-t * dt)`,
+    /// so calling it twice with `dt / 2` gives the same result as calling once with `dt`.
+    pub fn proceed_dt(&mut self, v: V, dt: T) -> &V
+    where
+        V: Interpolate<T> + Clone,
+        T: Clone + One + Sub<Output = T> + Mul<Output = T> + Neg<Output = T> + Exp<Output = T>,
+    {
+        let factor = lerp_factor_dt(self.t.clone(), dt);
+        let prev = self.prev.get_or_insert(v.clone());
+        *prev = V::interpolate(prev.clone(), v, factor);
+        prev
+    }
+
+    pub fn value(&self) -> Option<&V> {
+        self.prev.as_ref()
+    }
+
+    pub fn reset(&mut self) {
+        self.prev = None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn proceed_dt_is_frame_rate_independent() {
+        use super::LerpIntegrator;
+        use approx::assert_abs_diff_eq;
+
+        let mut coarse = LerpIntegrator::new(3.);
+        let mut fine = LerpIntegrator::new(3.);
+        coarse.proceed_dt(0., 0.);
+        fine.proceed_dt(0., 0.);
+
+        coarse.proceed_dt(10., 0.5);
+        for _ in 0..5 {
+            fine.proceed_dt(10., 0.1);
+        }
+        assert_abs_diff_eq!(
+            *coarse.value().unwrap(),
+            *fine.value().unwrap(),
+            epsilon = 1e-9
+        );
+    }
 }
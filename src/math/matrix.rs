@@ -1,4 +1,7 @@
-use super::{Complex, One, Point, Rect, Size, Two, Vector, Zero};
+use super::{
+    Abs, Angle, Atan2, Cast, CastError, Complex, Cos, Interpolate, One, Pi, Point, Rect, RemEuclid,
+    Sin, Size, Sq, Sqrt, Two, Vector, Zero, lerp2,
+};
 use core::{
     fmt::Debug,
     ops::{Add, Div, Mul, Neg, Not, Sub},
@@ -11,6 +14,35 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Matrix<T>([T; 9]);
 
+/// Aspect ratio policy of `Matrix::from_rect_to_rect`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FitMode {
+    /// uniform scale, whole source is visible inside destination
+    Fit,
+    /// uniform scale, source covers whole destination
+    Fill,
+    /// independent scale per axis, source matches destination exactly
+    Stretch,
+}
+
+/// Affine part of a `Matrix` split into components.
+/// Recomposed matrix applies them in order: scale, shear, rotation, translation.
+#[derive(Debug, Clone, Copy)]
+pub struct Decomposition<T> {
+    pub translation: Vector<T>,
+    /// unit rotor
+    pub rotation: Complex<T>,
+    pub scale: Size<T>,
+    /// horizontal shear applied after scale
+    pub shear: T,
+}
+
+fn homogeneous<T: One>(p: Point<T>) -> [T; 3] {
+    let (x, y) = p.into();
+    [x, y, T::one()]
+}
+
 pub mod indices {
     /// horizontal scale factor
     pub const SCALE_X: usize = 0;
@@ -134,6 +166,254 @@ impl<T> Matrix<T> {
         ])
     }
 
+    /**
+     * @brief rotate_about - create matrix rotating around a pivot point
+     * @param pivot - center of rotation
+     * @param angle - rotation angle
+     * @return Matrix with rotation around pivot
+     */
+    pub fn rotate_about(pivot: Point<T>, angle: Angle<T>) -> Self
+    where
+        T: Zero
+            + One
+            + Clone
+            + Neg<Output = T>
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Cos<Output = T>
+            + Sin<Output = T>,
+    {
+        Self::about(pivot, Self::rotate(Complex::from_polar(T::one(), angle)))
+    }
+
+    /**
+     * @brief scale_about - create matrix scaling relative to a pivot point
+     * @param pivot - point which stays in place
+     * @param scale - horizontal and vertical scale factors
+     * @return Matrix with scale around pivot
+     */
+    pub fn scale_about(pivot: Point<T>, scale: Size<T>) -> Self
+    where
+        T: Zero + One + Clone + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+    {
+        let (x, y) = scale.into();
+        Self::about(pivot, Self::scale(x, y))
+    }
+
+    /**
+     * @brief shear - create shear matrix
+     *  | 1  x  0 |
+     *  | y  1  0 |
+     *  | 0  0  1 |
+     * @param x - horizontal shear factor
+     * @param y - vertical shear factor
+     * @return Matrix with shear
+     */
+    pub fn shear(x: T, y: T) -> Self
+    where
+        T: Zero + One,
+    {
+        Self([
+            T::one(),
+            x,
+            T::zero(),
+            y,
+            T::one(),
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            T::one(),
+        ])
+    }
+
+    /**
+     * @brief reflect_across - create matrix mirroring across a line
+     *             1    | dx²-dy²   2dxdy    0 |
+     *  | R | = ------- |  2dxdy   dy²-dx²   0 |  (conjugated with translation to `point`)
+     *          dx²+dy² |    0        0      1 |
+     * @param point - any point on the line
+     * @param direction - direction of the line (does not have to be normalized)
+     * @return Matrix with reflection or None if direction is zero
+     */
+    pub fn reflect_across(point: Point<T>, direction: Vector<T>) -> Option<Self>
+    where
+        T: Zero
+            + One
+            + Clone
+            + PartialEq
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>,
+    {
+        let (dx, dy) = direction.into();
+        let len_sq = dx.clone() * dx.clone() + dy.clone() * dy.clone();
+        if len_sq == T::zero() {
+            return None;
+        }
+        let cos = (dx.clone() * dx.clone() - dy.clone() * dy.clone()) / len_sq.clone();
+        let sin = (dx.clone() * dy.clone() + dx * dy) / len_sq;
+        let reflection = Self([
+            cos.clone(),
+            sin.clone(),
+            T::zero(),
+            sin,
+            T::zero() - cos,
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            T::one(),
+        ]);
+        Some(Self::about(point, reflection))
+    }
+
+    /**
+     * @brief from_rect_to_rect - create matrix mapping one rect onto another
+     * @param src - source rect
+     * @param dst - destination rect
+     * @param mode - how to treat aspect ratio mismatch (see `FitMode`)
+     * @return Matrix mapping src into dst or None if src has zero width or height
+     */
+    pub fn from_rect_to_rect(src: Rect<T>, dst: Rect<T>, mode: FitMode) -> Option<Self>
+    where
+        T: Zero
+            + One
+            + Two
+            + Clone
+            + PartialOrd
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>,
+    {
+        if *src.w() == T::zero() || *src.h() == T::zero() {
+            return None;
+        }
+        let sx = dst.w().clone() / src.w().clone();
+        let sy = dst.h().clone() / src.h().clone();
+        let (sx, sy) = match mode {
+            FitMode::Fit => {
+                let s = if sx < sy { sx } else { sy };
+                (s.clone(), s)
+            }
+            FitMode::Fill => {
+                let s = if sx > sy { sx } else { sy };
+                (s.clone(), s)
+            }
+            FitMode::Stretch => (sx, sy),
+        };
+        Some(
+            &(&Self::translate(dst.center() - Point::origin()) * &Self::scale(sx, sy))
+                * &Self::translate(Point::origin() - src.center()),
+        )
+    }
+
+    /**
+     * @brief from_points - create affine matrix from three point correspondences
+     * @param src - three non-collinear source points
+     * @param dst - points `src` must be mapped to
+     * @return Matrix such as `M * src[i] = dst[i]` or None if source points are collinear
+     */
+    pub fn from_points(src: [Point<T>; 3], dst: [Point<T>; 3]) -> Option<Self>
+    where
+        T: Clone
+            + Zero
+            + One
+            + PartialEq
+            + Default
+            + Mul<Output = T>
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Div<Output = T>
+            + Neg<Output = T>,
+    {
+        let [s0, s1, s2] = src.map(homogeneous);
+        let [d0, d1, d2] = dst.map(homogeneous);
+        let inv_src = (!&Self::from_columns(s0, s1, s2))?;
+        Some(Self::from_columns(d0, d1, d2) * &inv_src)
+    }
+
+    /**
+     * @brief homography_from_quads - create projective matrix from four point correspondences
+     * @param src - four source points, no three of which are collinear
+     * @param dst - points `src` must be mapped to
+     * @return Matrix such as `M * src[i] = dst[i]` or None if any of quads is degenerate
+     */
+    pub fn homography_from_quads(src: [Point<T>; 4], dst: [Point<T>; 4]) -> Option<Self>
+    where
+        T: Clone
+            + Zero
+            + One
+            + PartialEq
+            + Default
+            + Mul<Output = T>
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Div<Output = T>
+            + Neg<Output = T>,
+    {
+        let src_basis = Self::quad_basis(src)?;
+        let dst_basis = Self::quad_basis(dst)?;
+        Some(dst_basis * &(!&src_basis)?)
+    }
+
+    /**
+     * @brief quad_basis - projective matrix mapping unit basis (and (1, 1, 1)) to quad points
+     */
+    fn quad_basis(quad: [Point<T>; 4]) -> Option<Self>
+    where
+        T: Clone
+            + Zero
+            + One
+            + PartialEq
+            + Default
+            + Mul<Output = T>
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Div<Output = T>
+            + Neg<Output = T>,
+    {
+        let [p0, p1, p2, p3] = quad.map(homogeneous);
+        let m = Self::from_columns(p0, p1, p2);
+        let [l0, l1, l2] = (!&m)? * &p3;
+        // zero weight means `p3` is collinear with two other points
+        if [&l0, &l1, &l2].iter().any(|l| **l == T::zero()) {
+            return None;
+        }
+        Some(
+            m * &Self([
+                l0,
+                T::zero(),
+                T::zero(),
+                T::zero(),
+                l1,
+                T::zero(),
+                T::zero(),
+                T::zero(),
+                l2,
+            ]),
+        )
+    }
+
+    fn from_columns(c0: [T; 3], c1: [T; 3], c2: [T; 3]) -> Self {
+        let [a, d, g] = c0;
+        let [b, e, h] = c1;
+        let [c, f, i] = c2;
+        Self([a, b, c, d, e, f, g, h, i])
+    }
+
+    /**
+     * @brief about - conjugate transformation with translation so that it is applied around pivot
+     */
+    fn about(pivot: Point<T>, m: Self) -> Self
+    where
+        T: Zero + One + Clone + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+    {
+        &(&Self::translate(pivot.clone() - Point::origin()) * &m)
+            * &Self::translate(Point::origin() - pivot)
+    }
+
     pub fn scale_x(&self) -> &T {
         &self.0[indices::SCALE_X]
     }
@@ -203,7 +483,7 @@ impl<T> Matrix<T> {
         T: One + Add<Output = T> + Sub<Output = T> + Div<Output = T> + Clone + PartialOrd,
         &'a Matrix<T>: Mul<[T; 3], Output = [T; 3]>,
     {
-        return Rect::aabb_from_points(
+        Rect::aabb_from_points(
             [
                 self.apply_affine_to_point(&rhs.left_top()),
                 self.apply_affine_to_point(&rhs.right_top()),
@@ -212,7 +492,7 @@ impl<T> Matrix<T> {
             ]
             .into_iter(),
         )
-        .unwrap();
+        .unwrap()
     }
 
     /*
@@ -274,7 +554,7 @@ impl<T> Matrix<T> {
     where
         T: Clone,
     {
-        return Self([
+        Self([
             self.a().clone(),
             self.d().clone(),
             self.g().clone(),
@@ -284,7 +564,7 @@ impl<T> Matrix<T> {
             self.c().clone(),
             self.f().clone(),
             self.i().clone(),
-        ]);
+        ])
     }
 
     fn minor<const I: usize, const J: usize>(&self) -> [T; 4]
@@ -304,7 +584,7 @@ impl<T> Matrix<T> {
                 }
             }
         }
-        return result;
+        result
     }
 
     /**
@@ -315,7 +595,7 @@ impl<T> Matrix<T> {
     where
         T: Clone + Mul<Output = T> + Sub<Output = T>,
     {
-        return data[0].clone() * data[3].clone() - data[1].clone() * data[2].clone();
+        data[0].clone() * data[3].clone() - data[1].clone() * data[2].clone()
     }
 
     /**
@@ -326,40 +606,177 @@ impl<T> Matrix<T> {
     where
         T: Clone + Mul<Output = T> + Add<Output = T> + Sub<Output = T>,
     {
-        return self.a().clone() * self.e().clone() * self.i().clone()
+        self.a().clone() * self.e().clone() * self.i().clone()
             + self.b().clone() * self.f().clone() * self.g().clone()
             + self.c().clone() * self.d().clone() * self.h().clone()
             - self.c().clone() * self.e().clone() * self.g().clone()
             - self.b().clone() * self.d().clone() * self.i().clone()
-            - self.a().clone() * self.f().clone() * self.h().clone();
+            - self.a().clone() * self.f().clone() * self.h().clone()
     }
 
     fn a(&self) -> &T {
-        return &self.0[0];
+        &self.0[0]
     }
     fn b(&self) -> &T {
-        return &self.0[1];
+        &self.0[1]
     }
     fn c(&self) -> &T {
-        return &self.0[2];
+        &self.0[2]
     }
     fn d(&self) -> &T {
-        return &self.0[3];
+        &self.0[3]
     }
     fn e(&self) -> &T {
-        return &self.0[4];
+        &self.0[4]
     }
     fn f(&self) -> &T {
-        return &self.0[5];
+        &self.0[5]
     }
     fn g(&self) -> &T {
-        return &self.0[6];
+        &self.0[6]
     }
     fn h(&self) -> &T {
-        return &self.0[7];
+        &self.0[7]
     }
     fn i(&self) -> &T {
-        return &self.0[8];
+        &self.0[8]
+    }
+}
+
+impl<T> Matrix<T> {
+    /**
+     * @brief decompose - split affine part of the matrix into translation, rotation, scale and shear.
+     *  Perspective factors are ignored.
+     *  | a  b |   | cos(θ) -sin(θ) |   | sx  k  |
+     *  | d  e | = | sin(θ)  cos(θ) | * | 0   sy |
+     * @return Decomposition such as `Matrix::compose(m.decompose())` is affine part of `m`
+     */
+    pub fn decompose(&self) -> Decomposition<T>
+    where
+        T: Clone
+            + Zero
+            + One
+            + PartialEq
+            + Sq<Output = T>
+            + Sqrt<Output = T>
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>,
+    {
+        let sx = (self.a().clone().sq() + self.d().clone().sq()).sqrt();
+        let (cos, sin) = if sx == T::zero() {
+            (T::one(), T::zero())
+        } else {
+            (self.a().clone() / sx.clone(), self.d().clone() / sx.clone())
+        };
+        let shear = cos.clone() * self.b().clone() + sin.clone() * self.e().clone();
+        let sy = cos.clone() * self.e().clone() - sin.clone() * self.b().clone();
+        Decomposition {
+            translation: self.translation(),
+            rotation: Complex::from_cartesian(cos, sin),
+            scale: (sx, sy).into(),
+            shear,
+        }
+    }
+
+    /**
+     * @brief compose - build affine matrix back from components (see `decompose`)
+     */
+    pub fn compose(decomposition: Decomposition<T>) -> Self
+    where
+        T: Clone + Zero + One + Neg<Output = T> + Add<Output = T> + Mul<Output = T>,
+    {
+        let Decomposition {
+            translation,
+            rotation,
+            scale,
+            shear,
+        } = decomposition;
+        let (sx, sy) = scale.into();
+        let scale_shear = Self([
+            sx,
+            shear,
+            T::zero(),
+            T::zero(),
+            sy,
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            T::one(),
+        ]);
+        &(&Self::translate(translation) * &Self::rotate(rotation)) * &scale_shear
+    }
+
+    /**
+     * @brief interpolate - blend two affine transformations.
+     *  Translation, scale and shear are interpolated linearly, rotation by the shortest arc,
+     *  so that intermediate matrices never distort the shape as entrywise lerp does.
+     * @param t - 0 gives `a`, 1 gives `b`
+     */
+    pub fn interpolate(a: &Self, b: &Self, t: T) -> Self
+    where
+        T: Clone
+            + Zero
+            + One
+            + Two
+            + Pi
+            + PartialOrd
+            + Sq<Output = T>
+            + Sqrt<Output = T>
+            + Abs<Output = T>
+            + Atan2<Output = T>
+            + Cos<Output = T>
+            + Sin<Output = T>
+            + RemEuclid<Output = T>
+            + Neg<Output = T>
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>,
+    {
+        let a = a.decompose();
+        let b = b.decompose();
+
+        let a_angle = a.rotation.imag().clone().atan2(a.rotation.real().clone());
+        let b_angle = b.rotation.imag().clone().atan2(b.rotation.real().clone());
+        let angle = a_angle.clone() + b_angle.signed_distance(a_angle) * t.clone();
+
+        let (asx, asy) = a.scale.into();
+        let (bsx, bsy) = b.scale.into();
+
+        Self::compose(Decomposition {
+            translation: lerp2(a.translation, b.translation, t.clone()),
+            rotation: Complex::from_polar(T::one(), angle),
+            scale: (lerp2(asx, bsx, t.clone()), lerp2(asy, bsy, t.clone())).into(),
+            shear: lerp2(a.shear, b.shear, t),
+        })
+    }
+}
+
+impl<T> Interpolate<T> for Matrix<T>
+where
+    T: Clone
+        + Zero
+        + One
+        + Two
+        + Pi
+        + PartialOrd
+        + Sq<Output = T>
+        + Sqrt<Output = T>
+        + Abs<Output = T>
+        + Atan2<Output = T>
+        + Cos<Output = T>
+        + Sin<Output = T>
+        + RemEuclid<Output = T>
+        + Neg<Output = T>
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>,
+{
+    fn interpolate(a: Self, b: Self, t: T) -> Self {
+        Matrix::interpolate(&a, &b, t)
     }
 }
 
@@ -593,6 +1010,43 @@ where
     }
 }
 
+impl<T> Matrix<T> {
+    /// Elementwise `Cast::cast`
+    pub fn cast<U>(self) -> Matrix<U>
+    where
+        T: Cast<U>,
+    {
+        Matrix(self.0.map(Cast::cast))
+    }
+
+    /// Elementwise `Cast::try_cast`
+    pub fn try_cast<U>(self) -> Result<Matrix<U>, CastError>
+    where
+        T: Cast<U> + Copy,
+    {
+        for value in self.0 {
+            Cast::<U>::try_cast(value)?;
+        }
+        Ok(self.cast())
+    }
+
+    /// Elementwise `Cast::saturating_cast`
+    pub fn saturating_cast<U>(self) -> Matrix<U>
+    where
+        T: Cast<U>,
+    {
+        Matrix(self.0.map(Cast::saturating_cast))
+    }
+
+    /// Elementwise `Cast::round_cast`
+    pub fn round_cast<U>(self) -> Matrix<U>
+    where
+        T: Cast<U>,
+    {
+        Matrix(self.0.map(Cast::round_cast))
+    }
+}
+
 impl Matrix<f32> {
     pub fn as_f64(self) -> Matrix<f64> {
         Matrix(self.0.map(|x| x as f64))
@@ -605,8 +1059,151 @@ impl Matrix<f64> {
     }
 }
 
+/// Elements in row-major order, see `indices`
+impl<T> From<[T; 9]> for Matrix<T> {
+    fn from(value: [T; 9]) -> Self {
+        Self(value)
+    }
+}
+
 impl<T> From<Matrix<T>> for [T; 9] {
     fn from(value: Matrix<T>) -> Self {
         value.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{FitMode, Matrix};
+    use crate::math::{Point, Rect, Vector};
+    use approx::assert_abs_diff_eq;
+
+    fn assert_maps(m: &Matrix<f64>, from: (f64, f64), to: (f64, f64)) {
+        let p = m * &Point::from(from);
+        assert_abs_diff_eq!(*p.x(), to.0, epsilon = 1e-9);
+        assert_abs_diff_eq!(*p.y(), to.1, epsilon = 1e-9);
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn rotate_about() {
+        use crate::math::Angle;
+
+        let m = Matrix::rotate_about((1., 1.).into(), Angle::from_degrees(90.));
+        assert_maps(&m, (1., 1.), (1., 1.));
+        assert_maps(&m, (2., 1.), (1., 2.));
+    }
+
+    #[test]
+    fn scale_about() {
+        let m = Matrix::scale_about((1., 1.).into(), (2., 3.).into());
+        assert_maps(&m, (1., 1.), (1., 1.));
+        assert_maps(&m, (2., 2.), (3., 4.));
+    }
+
+    #[test]
+    fn reflect_across() {
+        let m = Matrix::reflect_across((0., 1.).into(), Vector::from((1., 1.))).unwrap();
+        assert_maps(&m, (0., 1.), (0., 1.));
+        assert_maps(&m, (1., 0.), (-1., 2.));
+        assert!(Matrix::reflect_across((0., 0.).into(), Vector::from((0., 0.))).is_none());
+    }
+
+    #[test]
+    fn from_rect_to_rect() {
+        let src: Rect<f64> = (0., 0., 2., 1.).into();
+        let dst: Rect<f64> = (10., 10., 4., 4.).into();
+
+        let fit = Matrix::from_rect_to_rect(src, dst, FitMode::Fit).unwrap();
+        assert_maps(&fit, (0., 0.), (10., 11.));
+        assert_maps(&fit, (2., 1.), (14., 13.));
+
+        let fill = Matrix::from_rect_to_rect(src, dst, FitMode::Fill).unwrap();
+        assert_maps(&fill, (0., 0.), (8., 10.));
+        assert_maps(&fill, (2., 1.), (16., 14.));
+
+        let stretch = Matrix::from_rect_to_rect(src, dst, FitMode::Stretch).unwrap();
+        assert_maps(&stretch, (0., 0.), (10., 10.));
+        assert_maps(&stretch, (2., 1.), (14., 14.));
+
+        let flat: Rect<f64> = (0., 0., 2., 0.).into();
+        assert!(Matrix::from_rect_to_rect(flat, dst, FitMode::Stretch).is_none());
+        assert!(
+            Matrix::<i32>::from_rect_to_rect(
+                (0, 0, 0, 5).into(),
+                (0, 0, 4, 4).into(),
+                FitMode::Fit
+            )
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn from_points() {
+        let src = [(0., 0.), (1., 0.), (0., 1.)].map(Point::from);
+        let dst = [(1., 2.), (1., 4.), (-2., 2.)].map(Point::from);
+        let m = Matrix::from_points(src, dst).unwrap();
+        assert_maps(&m, (0., 0.), (1., 2.));
+        assert_maps(&m, (1., 0.), (1., 4.));
+        assert_maps(&m, (0., 1.), (-2., 2.));
+        assert_maps(&m, (1., 1.), (-2., 4.));
+
+        let collinear = [(0., 0.), (1., 1.), (2., 2.)].map(Point::from);
+        assert!(Matrix::from_points(collinear, dst).is_none());
+    }
+
+    #[test]
+    fn homography_from_quads() {
+        let src = [(0., 0.), (1., 0.), (1., 1.), (0., 1.)].map(Point::from);
+        let dst = [(0., 0.), (4., 0.), (3., 2.), (1., 2.)].map(Point::from);
+        let m = Matrix::homography_from_quads(src, dst).unwrap();
+        for (s, d) in src.into_iter().zip(dst) {
+            assert_maps(&m, s.into(), d.into());
+        }
+        assert_maps(&m, (0.5, 0.), (2., 0.));
+
+        let collinear = [(0., 0.), (4., 0.), (3., 2.), (2., 0.)].map(Point::from);
+        assert!(Matrix::homography_from_quads(src, collinear).is_none());
+        assert!(Matrix::homography_from_quads(collinear, dst).is_none());
+        let collinear = [(0., 0.), (1., 0.), (2., 0.), (1., 2.)].map(Point::from);
+        assert!(Matrix::homography_from_quads(src, collinear).is_none());
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn decompose_compose() {
+        use crate::math::Angle;
+
+        let m = &(&Matrix::rotate_about((3., -1.).into(), Angle::from_degrees(30.))
+            * &Matrix::shear(0.5, 0.))
+            * &Matrix::scale(2., -3.);
+        let r = Matrix::compose(m.decompose());
+        for p in [(0., 0.), (1., 0.), (0., 1.), (5., 7.)] {
+            let expected = &m * &Point::from(p);
+            assert_maps(&r, p, (*expected.x(), *expected.y()));
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn interpolate() {
+        use crate::math::{Angle, LerpIntegrator};
+
+        let a = Matrix::rotate_about((0., 0.).into(), Angle::from_degrees(350.));
+        let b = &Matrix::translate((2., 0.).into())
+            * &Matrix::rotate_about((0., 0.).into(), Angle::from_degrees(10.));
+
+        let m = Matrix::interpolate(&a, &b, 0.5);
+        assert_maps(&m, (0., 0.), (1., 0.));
+        assert_maps(&m, (1., 0.), (2., 0.));
+
+        let m = Matrix::interpolate(&a, &b, 0.);
+        let expected = &a * &Point::from((1., 0.));
+        assert_maps(&m, (1., 0.), (*expected.x(), *expected.y()));
+
+        let mut integrator = LerpIntegrator::new(0.5);
+        integrator.proceed(a);
+        let m = integrator.proceed(b);
+        assert_maps(m, (1., 0.), (2., 0.));
+    }
+}
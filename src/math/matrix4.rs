@@ -0,0 +1,268 @@
+use super::{Angle, Cos, One, Quaternion, Sin, Two, Vector3, Zero};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// 4x4 row-major matrix of 3D homogeneous transformations.
+/// Like `Matrix`, it is applied to column vectors: `M * [x, y, z, 1]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Matrix4<T>([T; 16]);
+
+impl<T> Matrix4<T> {
+    pub fn from_rows(rows: [[T; 4]; 4]) -> Self {
+        let [[a, b, c, d], [e, f, g, h], [i, j, k, l], [m, n, o, p]] = rows;
+        Self([a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p])
+    }
+
+    pub fn identity() -> Self
+    where
+        T: Zero + One,
+    {
+        Self::scale(T::one(), T::one(), T::one())
+    }
+
+    /**
+     * @brief scale - create scale matrix
+     *  | x  0  0  0 |
+     *  | 0  y  0  0 |
+     *  | 0  0  z  0 |
+     *  | 0  0  0  1 |
+     */
+    pub fn scale(x: T, y: T, z: T) -> Self
+    where
+        T: Zero + One,
+    {
+        Self::from_rows([
+            [x, T::zero(), T::zero(), T::zero()],
+            [T::zero(), y, T::zero(), T::zero()],
+            [T::zero(), T::zero(), z, T::zero()],
+            [T::zero(), T::zero(), T::zero(), T::one()],
+        ])
+    }
+
+    /**
+     * @brief translate - create translate matrix
+     *  | 1  0  0  x |
+     *  | 0  1  0  y |
+     *  | 0  0  1  z |
+     *  | 0  0  0  1 |
+     */
+    pub fn translate(offset: Vector3<T>) -> Self
+    where
+        T: Zero + One,
+    {
+        let (x, y, z) = offset.into();
+        Self::from_rows([
+            [T::one(), T::zero(), T::zero(), x],
+            [T::zero(), T::one(), T::zero(), y],
+            [T::zero(), T::zero(), T::one(), z],
+            [T::zero(), T::zero(), T::zero(), T::one()],
+        ])
+    }
+
+    /**
+     * @brief rotate - create rotation matrix from unit quaternion
+     *  | 1-2(y²+z²)   2(xy-wz)    2(xz+wy)   0 |
+     *  |  2(xy+wz)   1-2(x²+z²)   2(yz-wx)   0 |
+     *  |  2(xz-wy)    2(yz+wx)   1-2(x²+y²)  0 |
+     *  |     0           0           0       1 |
+     */
+    pub fn rotate(rotor: Quaternion<T>) -> Self
+    where
+        T: Zero + One + Two + Clone + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+    {
+        let (w, x, y, z) = (
+            rotor.w().clone(),
+            rotor.x().clone(),
+            rotor.y().clone(),
+            rotor.z().clone(),
+        );
+        let two = T::two();
+        let xx = x.clone() * x.clone();
+        let yy = y.clone() * y.clone();
+        let zz = z.clone() * z.clone();
+        let xy = x.clone() * y.clone();
+        let xz = x.clone() * z.clone();
+        let yz = y.clone() * z.clone();
+        let wx = w.clone() * x;
+        let wy = w.clone() * y;
+        let wz = w * z;
+        Self::from_rows([
+            [
+                T::one() - two.clone() * (yy.clone() + zz.clone()),
+                two.clone() * (xy.clone() - wz.clone()),
+                two.clone() * (xz.clone() + wy.clone()),
+                T::zero(),
+            ],
+            [
+                two.clone() * (xy + wz),
+                T::one() - two.clone() * (xx.clone() + zz),
+                two.clone() * (yz.clone() - wx.clone()),
+                T::zero(),
+            ],
+            [
+                two.clone() * (xz - wy),
+                two.clone() * (yz + wx),
+                T::one() - two * (xx + yy),
+                T::zero(),
+            ],
+            [T::zero(), T::zero(), T::zero(), T::one()],
+        ])
+    }
+
+    /**
+     * @brief perspective - right handed perspective projection (camera looks along -z, clip z in -1..1)
+     *  | f/aspect  0       0             0        |
+     *  |    0      f       0             0        |
+     *  |    0      0  (f+n)/(n-f)   2fn/(n-f)     |
+     *  |    0      0      -1             0        |
+     *  where f = 1 / tan(fov_y / 2) = (1 + cos(fov_y)) / sin(fov_y)
+     */
+    pub fn perspective(fov_y: Angle<T>, aspect: T, near: T, far: T) -> Self
+    where
+        T: Clone
+            + Zero
+            + One
+            + Two
+            + Neg<Output = T>
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>
+            + Cos<Output = T>
+            + Sin<Output = T>,
+    {
+        let f = (T::one() + fov_y.clone().cos()) / fov_y.sin();
+        let d = near.clone() - far.clone();
+        Self::from_rows([
+            [f.clone() / aspect, T::zero(), T::zero(), T::zero()],
+            [T::zero(), f, T::zero(), T::zero()],
+            [
+                T::zero(),
+                T::zero(),
+                (far.clone() + near.clone()) / d.clone(),
+                T::two() * far * near / d,
+            ],
+            [T::zero(), T::zero(), -T::one(), T::zero()],
+        ])
+    }
+
+    /**
+     * @brief orthographic - right handed orthographic projection (camera looks along -z, clip z in -1..1)
+     *  | 2/(r-l)    0        0       -(r+l)/(r-l) |
+     *  |    0    2/(t-b)     0       -(t+b)/(t-b) |
+     *  |    0       0    -2/(f-n)    -(f+n)/(f-n) |
+     *  |    0       0        0             1      |
+     */
+    pub fn orthographic(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self
+    where
+        T: Clone
+            + Zero
+            + One
+            + Two
+            + Neg<Output = T>
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Div<Output = T>,
+    {
+        let w = right.clone() - left.clone();
+        let h = top.clone() - bottom.clone();
+        let d = far.clone() - near.clone();
+        Self::from_rows([
+            [
+                T::two() / w.clone(),
+                T::zero(),
+                T::zero(),
+                -(right + left) / w,
+            ],
+            [
+                T::zero(),
+                T::two() / h.clone(),
+                T::zero(),
+                -(top + bottom) / h,
+            ],
+            [
+                T::zero(),
+                T::zero(),
+                -T::two() / d.clone(),
+                -(far + near) / d,
+            ],
+            [T::zero(), T::zero(), T::zero(), T::one()],
+        ])
+    }
+
+    pub fn row(&self, i: usize) -> [T; 4]
+    where
+        T: Clone,
+    {
+        [
+            self.0[i * 4].clone(),
+            self.0[i * 4 + 1].clone(),
+            self.0[i * 4 + 2].clone(),
+            self.0[i * 4 + 3].clone(),
+        ]
+    }
+
+    /// Transform point dividing result by homogeneous coordinate
+    pub fn transform_point(&self, p: Vector3<T>) -> Vector3<T>
+    where
+        T: Clone + One + Add<Output = T> + Mul<Output = T> + Div<Output = T>,
+    {
+        let (x, y, z) = p.into();
+        let [x, y, z, w] = self * [x, y, z, T::one()];
+        Vector3::new(x / w.clone(), y / w.clone(), z / w)
+    }
+
+    /// Transform direction ignoring translation and perspective
+    pub fn transform_vector(&self, v: Vector3<T>) -> Vector3<T>
+    where
+        T: Clone + Zero + Add<Output = T> + Mul<Output = T>,
+    {
+        let (x, y, z) = v.into();
+        let [x, y, z, _] = self * [x, y, z, T::zero()];
+        Vector3::new(x, y, z)
+    }
+}
+
+impl<T> Mul for &Matrix4<T>
+where
+    T: Clone + Add<Output = T> + Mul<Output = T>,
+{
+    type Output = Matrix4<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let l = &self.0;
+        let r = &rhs.0;
+        Matrix4(core::array::from_fn(|idx| {
+            let (i, j) = (idx / 4, idx % 4);
+            l[i * 4].clone() * r[j].clone()
+                + l[i * 4 + 1].clone() * r[4 + j].clone()
+                + l[i * 4 + 2].clone() * r[8 + j].clone()
+                + l[i * 4 + 3].clone() * r[12 + j].clone()
+        }))
+    }
+}
+
+impl<T> Mul<[T; 4]> for &Matrix4<T>
+where
+    T: Clone + Add<Output = T> + Mul<Output = T>,
+{
+    type Output = [T; 4];
+
+    fn mul(self, rhs: [T; 4]) -> Self::Output {
+        core::array::from_fn(|i| {
+            self.0[i * 4].clone() * rhs[0].clone()
+                + self.0[i * 4 + 1].clone() * rhs[1].clone()
+                + self.0[i * 4 + 2].clone() * rhs[2].clone()
+                + self.0[i * 4 + 3].clone() * rhs[3].clone()
+        })
+    }
+}
+
+impl<T> From<Matrix4<T>> for [T; 16] {
+    fn from(value: Matrix4<T>) -> Self {
+        value.0
+    }
+}
@@ -1,4 +1,4 @@
-use super::{Clamp, MinusOne, One, Zero};
+use super::{Clamp, MinusOne, One, Two, Zero};
 use crate::range::{Range, RangeInclusive};
 use core::{
     error::Error,
@@ -128,3 +128,33 @@ where
         T::minus_one()
     }
 }
+
+/// Integer `n` built with double-and-add so that only `Zero`/`One`/`Two` are needed
+pub(crate) fn from_u32<T>(n: u32) -> T
+where
+    T: Zero + One + Two + Add<Output = T> + Mul<Output = T>,
+{
+    (0..u32::BITS - n.leading_zeros())
+        .rev()
+        .fold(T::zero(), |acc, i| {
+            let acc = acc * T::two();
+            if (n >> i) & 1 == 1 {
+                acc + T::one()
+            } else {
+                acc
+            }
+        })
+}
+
+/// `numerator / denominator` built from integers, used for exact constants in generic code
+pub(crate) fn ratio<T>(numerator: i32, denominator: u32) -> T
+where
+    T: Zero + One + Two + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    let value = from_u32::<T>(numerator.unsigned_abs()) / from_u32(denominator);
+    if numerator < 0 {
+        T::zero() - value
+    } else {
+        value
+    }
+}
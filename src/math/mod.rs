@@ -1,27 +1,39 @@
 mod angle;
+mod cast;
 mod complex;
+mod easing;
 mod lerp;
 mod matrix;
+mod matrix4;
 mod misc;
 mod non_neg;
 mod point;
 mod positive;
+mod quaternion;
 mod rational;
 mod rect;
 mod size;
+mod spring;
 mod traits;
 mod vector;
+mod vector3;
 
 pub use angle::*;
+pub use cast::*;
 pub use complex::*;
+pub use easing::*;
 pub use lerp::*;
 pub use matrix::*;
+pub use matrix4::*;
 pub use misc::*;
 pub use non_neg::*;
 pub use point::*;
 pub use positive::*;
+pub use quaternion::*;
 pub use rational::*;
 pub use rect::*;
 pub use size::*;
+pub use spring::*;
 pub use traits::*;
 pub use vector::*;
+pub use vector3::*;
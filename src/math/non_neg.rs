@@ -34,7 +34,7 @@ impl<'de, T: Deserialize<'de> + IsNeg + Debug> Deserialize<'de> for NonNeg<T> {
         let value = T::deserialize(deserializer)?;
         if value.is_neg() {
             #[cfg(feature = "std")]
-            let err = Err(serde::de::Error::custom(&std::format!(
+            let err = Err(serde::de::Error::custom(std::format!(
                 "Can not deserialize {:?} as NoNeg because it is negative.",
                 value
             )));
@@ -129,7 +129,8 @@ impl<T> NonNeg<T> {
         T: SubAssign + PartialOrd,
     {
         if self.value >= rhs.value {
-            Ok(self.value -= rhs.value)
+            self.value -= rhs.value;
+            Ok(())
         } else {
             Err(rhs)
         }
@@ -154,14 +155,7 @@ where
     }
 }
 
-impl<T> Eq for NonNeg<T>
-where
-    T: Eq,
-{
-    fn assert_receiver_is_total_eq(&self) {
-        self.value.assert_receiver_is_total_eq()
-    }
-}
+impl<T> Eq for NonNeg<T> where T: Eq {}
 
 impl<T, U> PartialOrd<NonNeg<U>> for NonNeg<T>
 where
@@ -33,6 +33,19 @@ impl<T> From<Point<T>> for [T; 2] {
     }
 }
 
+impl<T> From<[T; 2]> for Point<T> {
+    fn from([x, y]: [T; 2]) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<Vector<T>> for Point<T> {
+    fn from(value: Vector<T>) -> Self {
+        let (x, y) = value.into();
+        Self { x, y }
+    }
+}
+
 impl<T: Sub> Sub for Point<T> {
     type Output = Vector<<T as Sub>::Output>;
     fn sub(self, rhs: Self) -> Self::Output {
@@ -74,6 +87,14 @@ impl<T> Point<T> {
         &self.y
     }
 
+    /// Point with swapped coordinates
+    pub fn yx(self) -> Self {
+        Self {
+            x: self.y,
+            y: self.x,
+        }
+    }
+
     pub fn absolute(self, origin: Point<T>) -> Self
     where
         T: Add<Output = T>,
@@ -36,7 +36,7 @@ impl<'de, T: Deserialize<'de> + IsPositive + Debug> Deserialize<'de> for Positiv
             Ok(Self { value })
         } else {
             #[cfg(feature = "std")]
-            let err = Err(serde::de::Error::custom(&std::format!(
+            let err = Err(serde::de::Error::custom(std::format!(
                 "Can not deserialize {:?} as Positive because it is not a positive number.",
                 value
             )));
@@ -118,7 +118,8 @@ impl<T> Positive<T> {
         T: SubAssign + PartialOrd,
     {
         if self.value > rhs.value {
-            Ok(self.value -= rhs.value)
+            self.value -= rhs.value;
+            Ok(())
         } else {
             Err(rhs)
         }
@@ -143,14 +144,7 @@ where
     }
 }
 
-impl<T> Eq for Positive<T>
-where
-    T: Eq,
-{
-    fn assert_receiver_is_total_eq(&self) {
-        self.value.assert_receiver_is_total_eq()
-    }
-}
+impl<T> Eq for Positive<T> where T: Eq {}
 
 impl<T, U> PartialOrd<Positive<U>> for Positive<T>
 where
@@ -0,0 +1,202 @@
+use super::{Angle, Cos, One, Pi, RemEuclid, Sin, Sq, Sqrt, Two, Vector3, Zero};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Quaternion `w + xi + yj + zk`. Unit quaternions are used as 3D rotors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Quaternion<T> {
+    w: T,
+    x: T,
+    y: T,
+    z: T,
+}
+
+impl<T> Quaternion<T> {
+    pub fn new(w: T, x: T, y: T, z: T) -> Self {
+        Self { w, x, y, z }
+    }
+
+    pub fn identity() -> Self
+    where
+        T: Zero + One,
+    {
+        Self {
+            w: T::one(),
+            x: T::zero(),
+            y: T::zero(),
+            z: T::zero(),
+        }
+    }
+
+    /// Rotor rotating counterclockwise (right hand rule) around `axis` which must be normalized
+    pub fn from_axis_angle(axis: Vector3<T>, angle: Angle<T>) -> Self
+    where
+        T: Clone
+            + Pi
+            + Two
+            + Mul<Output = T>
+            + Div<Output = T>
+            + RemEuclid<Output = T>
+            + Cos<Output = T>
+            + Sin<Output = T>,
+    {
+        let half = angle.radians() / T::two();
+        let sin = half.clone().sin();
+        let (x, y, z) = (axis * sin).into();
+        Self {
+            w: half.cos(),
+            x,
+            y,
+            z,
+        }
+    }
+
+    /**
+     * @brief from_basis - rotor which maps unit axes onto given orthonormal right handed basis
+     * @param x - image of (1, 0, 0)
+     * @param y - image of (0, 1, 0)
+     * @param z - image of (0, 0, 1)
+     */
+    pub fn from_basis(x: Vector3<T>, y: Vector3<T>, z: Vector3<T>) -> Self
+    where
+        T: Clone
+            + Zero
+            + One
+            + Two
+            + PartialOrd
+            + Sqrt<Output = T>
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>,
+    {
+        let (m00, m10, m20) = x.into();
+        let (m01, m11, m21) = y.into();
+        let (m02, m12, m22) = z.into();
+        let two = T::two();
+        let trace = m00.clone() + m11.clone() + m22.clone();
+        if trace > T::zero() {
+            let s = (trace + T::one()).sqrt() * two.clone();
+            Self {
+                w: s.clone() / (two.clone() * two),
+                x: (m21 - m12) / s.clone(),
+                y: (m02 - m20) / s.clone(),
+                z: (m10 - m01) / s,
+            }
+        } else if m00 > m11 && m00 > m22 {
+            let s = (T::one() + m00 - m11 - m22).sqrt() * two.clone();
+            Self {
+                w: (m21 - m12) / s.clone(),
+                x: s.clone() / (two.clone() * two),
+                y: (m01 + m10) / s.clone(),
+                z: (m02 + m20) / s,
+            }
+        } else if m11 > m22 {
+            let s = (T::one() + m11 - m00 - m22).sqrt() * two.clone();
+            Self {
+                w: (m02 - m20) / s.clone(),
+                x: (m01 + m10) / s.clone(),
+                y: s.clone() / (two.clone() * two),
+                z: (m12 + m21) / s,
+            }
+        } else {
+            let s = (T::one() + m22 - m00 - m11).sqrt() * two.clone();
+            Self {
+                w: (m10 - m01) / s.clone(),
+                x: (m02 + m20) / s.clone(),
+                y: (m12 + m21) / s.clone(),
+                z: s.clone() / (two.clone() * two),
+            }
+        }
+    }
+
+    pub fn w(&self) -> &T {
+        &self.w
+    }
+
+    pub fn x(&self) -> &T {
+        &self.x
+    }
+
+    pub fn y(&self) -> &T {
+        &self.y
+    }
+
+    pub fn z(&self) -> &T {
+        &self.z
+    }
+
+    /// Inverse rotation for unit quaternions
+    pub fn conjugate(self) -> Self
+    where
+        T: Neg<Output = T>,
+    {
+        Self {
+            w: self.w,
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+
+    pub fn len(self) -> T
+    where
+        T: Sq<Output = T> + Add<Output = T> + Sqrt<Output = T>,
+    {
+        (self.w.sq() + self.x.sq() + self.y.sq() + self.z.sq()).sqrt()
+    }
+
+    pub fn norm(self) -> Self
+    where
+        T: Clone + Sq<Output = T> + Add<Output = T> + Sqrt<Output = T> + Div<Output = T>,
+    {
+        let len = self.clone().len();
+        Self {
+            w: self.w / len.clone(),
+            x: self.x / len.clone(),
+            y: self.y / len.clone(),
+            z: self.z / len,
+        }
+    }
+
+    /// Rotate vector by unit quaternion: `v + 2w(u × v) + 2u × (u × v)`
+    pub fn rotate(&self, v: Vector3<T>) -> Vector3<T>
+    where
+        T: Clone + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+    {
+        let u = Vector3::new(self.x.clone(), self.y.clone(), self.z.clone());
+        let uv = u.clone().cross(v.clone());
+        let uuv = u.cross(uv.clone());
+        let uv = uv.clone() + uv;
+        let uuv = uuv.clone() + uuv;
+        v + uv * self.w.clone() + uuv
+    }
+}
+
+/// Hamilton product, `a * b` rotates by `b` first and then by `a`
+impl<T> Mul for Quaternion<T>
+where
+    T: Clone + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    type Output = Quaternion<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (a1, b1, c1, d1) = (self.w, self.x, self.y, self.z);
+        let (a2, b2, c2, d2) = (rhs.w, rhs.x, rhs.y, rhs.z);
+        Quaternion {
+            w: a1.clone() * a2.clone()
+                - b1.clone() * b2.clone()
+                - c1.clone() * c2.clone()
+                - d1.clone() * d2.clone(),
+            x: a1.clone() * b2.clone() + b1.clone() * a2.clone() + c1.clone() * d2.clone()
+                - d1.clone() * c2.clone(),
+            y: a1.clone() * c2.clone() - b1.clone() * d2.clone()
+                + c1.clone() * a2.clone()
+                + d1.clone() * b2.clone(),
+            z: a1 * d2 + b1 * c2 - c1 * b2 + d1 * a2,
+        }
+    }
+}
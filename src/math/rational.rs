@@ -32,6 +32,36 @@ where
     }
 }
 
+/// Exact product, not reduced
+impl<T> Mul for Rational<T, T>
+where
+    T: Mul<Output = T>,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.numerator * rhs.numerator,
+            self.denominator * rhs.denominator,
+        )
+    }
+}
+
+/// Exact quotient, not reduced
+impl<T> Div for Rational<T, T>
+where
+    T: Mul<Output = T>,
+{
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Self::new(
+            self.numerator * rhs.denominator,
+            self.denominator * rhs.numerator,
+        )
+    }
+}
+
 pub trait ApplyRationalPrecision<T> {
     fn apply_rational_precision(x: T) -> Self;
     fn precision() -> Self;
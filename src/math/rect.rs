@@ -241,45 +241,36 @@ impl<T> Rect<T> {
         l < r && t < b
     }
 
+    /// Point of rect (including its interior) closest to `point`
+    pub fn closest_point(&self, point: Point<T>) -> Point<T>
+    where
+        T: Add<Output = T> + Clone + PartialOrd,
+    {
+        let (px, py) = point.into();
+        let x = if px < self.left() {
+            self.left()
+        } else if px > self.right() {
+            self.right()
+        } else {
+            px
+        };
+        let y = if py < self.top() {
+            self.top()
+        } else if py > self.bottom() {
+            self.bottom()
+        } else {
+            py
+        };
+        (x, y).into()
+    }
+
     pub fn intersects_circle(&self, center: Point<T>, radius: NonNeg<T>) -> bool
     where
         T: Add<Output = T> + Sub<Output = T> + Clone + Sq<Output = T> + PartialOrd,
     {
-        let cx = center.x();
-        let cy = center.y();
-
-        let rx = self.x.clone();
-        let ry = self.y.clone();
-        let rw = self.w.clone();
-        let rh = self.h.clone();
-
-        // temporary variables to set edges for testing
-        let mut test_x = cx.clone();
-        let mut test_y = cy.clone();
-
-        // which edge is closest?
-        if *cx < rx {
-            test_x = rx.clone();
-        }
-        // test left edge
-        else if *cx > rx.clone() + rw.clone() {
-            test_x = rx.clone() + rw.clone();
-        } // right edge
-        if *cy < ry {
-            test_y = ry.clone();
-        }
-        // top edge
-        else if *cy > ry.clone() + rh.clone() {
-            test_y = ry.clone() + rh.clone();
-        } // bottom edge
-
-        // get distance from the closest edges
-        let dist_x = cx.clone() - test_x;
-        let dist_y = cy.clone() - test_y;
-        let distance_sqr = dist_x.sq() + dist_y.sq();
-
-        // if the distance is less than the radius, collision!
-        distance_sqr <= radius.into_inner().sq()
+        let closest = self.closest_point(center.clone());
+        let (dist_x, dist_y) = (center - closest).into();
+        dist_x.sq() + dist_y.sq() <= radius.into_inner().sq()
     }
 
     pub fn extended(self, vec: Vector<T>) -> Rect<T>
@@ -372,6 +363,12 @@ impl<T> From<(T, T, T, T)> for Rect<T> {
     }
 }
 
+impl<T> From<Rect<T>> for (T, T, T, T) {
+    fn from(value: Rect<T>) -> Self {
+        (value.x, value.y, value.w, value.h)
+    }
+}
+
 impl<T> Mul<T> for Rect<T>
 where
     T: Two + Add<Output = T> + Div<Output = T> + Mul<Output = T> + Sub<Output = T> + Clone,
@@ -19,6 +19,12 @@ impl<T> From<(T, T)> for Size<T> {
     }
 }
 
+impl<T> From<[T; 2]> for Size<T> {
+    fn from([w, h]: [T; 2]) -> Self {
+        Self { w, h }
+    }
+}
+
 impl<T> From<Size<T>> for (T, T) {
     fn from(value: Size<T>) -> Self {
         (value.w, value.h)
@@ -72,6 +78,14 @@ impl<T> Size<T> {
     pub fn h(&self) -> &T {
         &self.h
     }
+
+    /// Size with swapped width and height
+    pub fn hw(self) -> Self {
+        Self {
+            w: self.h,
+            h: self.w,
+        }
+    }
 }
 
 impl<T> Div<T> for Size<T>
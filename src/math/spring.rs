@@ -0,0 +1,306 @@
+use super::{
+    Abs, Angle, DeltaAngle, One, Pi, Point, Positive, RemEuclid, Sqrt, Two, Vector, Vector3, Zero,
+    ratio,
+};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Value which moves by its delta type: `Point` by `Vector`, `Angle` by `DeltaAngle`, vectors and scalars by themselves
+pub trait Displace {
+    type Delta;
+
+    /// Delta moving `from` to `self` (shortest one for angles)
+    fn displacement(self, from: Self) -> Self::Delta;
+
+    fn displaced(self, delta: Self::Delta) -> Self;
+}
+
+macro_rules! impl_displace {
+    ( $($tp: ty),+ ) => {
+        $(
+            impl Displace for $tp {
+                type Delta = $tp;
+
+                fn displacement(self, from: Self) -> Self::Delta { self - from }
+
+                fn displaced(self, delta: Self::Delta) -> Self { self + delta }
+            }
+        )+
+    };
+}
+
+impl_displace! { f32, f64 }
+
+impl<T> Displace for Vector<T>
+where
+    T: Add<Output = T> + Sub<Output = T>,
+{
+    type Delta = Vector<T>;
+
+    fn displacement(self, from: Self) -> Self::Delta {
+        self - from
+    }
+
+    fn displaced(self, delta: Self::Delta) -> Self {
+        self + delta
+    }
+}
+
+impl<T> Displace for Vector3<T>
+where
+    T: Add<Output = T> + Sub<Output = T>,
+{
+    type Delta = Vector3<T>;
+
+    fn displacement(self, from: Self) -> Self::Delta {
+        self - from
+    }
+
+    fn displaced(self, delta: Self::Delta) -> Self {
+        self + delta
+    }
+}
+
+impl<T> Displace for Point<T>
+where
+    T: Add<Output = T> + Sub<Output = T>,
+{
+    type Delta = Vector<T>;
+
+    fn displacement(self, from: Self) -> Self::Delta {
+        self - from
+    }
+
+    fn displaced(self, delta: Self::Delta) -> Self {
+        self + delta
+    }
+}
+
+impl<T> Displace for Angle<T>
+where
+    T: Clone
+        + Pi
+        + Two
+        + Zero
+        + PartialOrd
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Abs<Output = T>
+        + RemEuclid<Output = T>,
+{
+    type Delta = DeltaAngle<T>;
+
+    fn displacement(self, from: Self) -> Self::Delta {
+        self.signed_distance(from)
+    }
+
+    fn displaced(self, delta: Self::Delta) -> Self {
+        self + delta
+    }
+}
+
+/// Damped harmonic oscillator pulling value towards target: `m·a = k·(target - x) - c·v`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpringDamper<V: Displace, T> {
+    value: V,
+    velocity: V::Delta,
+    stiffness: T,
+    damping: T,
+    mass: T,
+}
+
+impl<V: Displace, T> SpringDamper<V, T> {
+    /// Spring at rest at `value`
+    pub fn new(value: V, stiffness: Positive<T>, damping: T, mass: Positive<T>) -> Self
+    where
+        V: Clone,
+    {
+        Self {
+            velocity: value.clone().displacement(value.clone()),
+            value,
+            stiffness: stiffness.into_inner(),
+            damping,
+            mass: mass.into_inner(),
+        }
+    }
+
+    /// Damping `c = 2·√(k·m)` which reaches target fastest without overshoot
+    pub fn critically_damped(value: V, stiffness: Positive<T>, mass: Positive<T>) -> Self
+    where
+        T: Clone + Two + Mul<Output = T> + Sqrt<Output = T>,
+        V: Clone,
+    {
+        let damping =
+            T::two() * (stiffness.clone().into_inner() * mass.clone().into_inner()).sqrt();
+        Self::new(value, stiffness, damping, mass)
+    }
+
+    pub fn value(&self) -> &V {
+        &self.value
+    }
+
+    pub fn velocity(&self) -> &V::Delta {
+        &self.velocity
+    }
+
+    /// Place value without changing velocity
+    pub fn set_value(&mut self, value: V) {
+        self.value = value
+    }
+
+    pub fn set_velocity(&mut self, velocity: V::Delta) {
+        self.velocity = velocity
+    }
+
+    pub fn stiffness(&self) -> &T {
+        &self.stiffness
+    }
+
+    pub fn damping(&self) -> &T {
+        &self.damping
+    }
+
+    pub fn mass(&self) -> &T {
+        &self.mass
+    }
+
+    /// Advance by `dt` with semi-implicit Euler method and return new value
+    pub fn step(&mut self, target: V, dt: T) -> &V
+    where
+        V: Clone,
+        V::Delta: Clone + Add<Output = V::Delta> + Mul<T, Output = V::Delta>,
+        T: Clone + One + Neg<Output = T> + Div<Output = T>,
+    {
+        let force = target.displacement(self.value.clone()) * self.stiffness.clone()
+            + self.velocity.clone() * -self.damping.clone();
+        let acceleration = force * (T::one() / self.mass.clone());
+        self.velocity = self.velocity.clone() + acceleration * dt.clone();
+        self.value = self.value.clone().displaced(self.velocity.clone() * dt);
+        &self.value
+    }
+}
+
+/**
+ * @brief smooth_damp - critically damped smoothing towards target (same as Unity `SmoothDamp`)
+ *  Frame-rate independent: exact solution of critically damped spring is approximated with
+ *  `exp(-x) ≈ 1 / (1 + x + 0.48x² + 0.235x³)` where `x = 2·dt/smooth_time`.
+ * @param velocity - current velocity, updated by call. Should be stored between frames
+ * @param smooth_time - approximate time to reach target
+ */
+pub fn smooth_damp<V, T>(
+    current: V,
+    target: V,
+    velocity: &mut V::Delta,
+    smooth_time: Positive<T>,
+    dt: T,
+) -> V
+where
+    V: Displace + Clone,
+    V::Delta: Clone + Add<Output = V::Delta> + Sub<Output = V::Delta> + Mul<T, Output = V::Delta>,
+    T: Clone
+        + Zero
+        + One
+        + Two
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>,
+{
+    let omega = T::two() / smooth_time.into_inner();
+    let x = omega.clone() * dt.clone();
+    let x2 = x.clone() * x.clone();
+    let decay = T::one()
+        / (T::one()
+            + x.clone()
+            + ratio::<T>(48, 100) * x2.clone()
+            + ratio::<T>(235, 1000) * x2 * x);
+    // offset from target to current
+    let change = current.displacement(target.clone());
+    let temp = (velocity.clone() + change.clone() * omega.clone()) * dt;
+    *velocity = (velocity.clone() - temp.clone() * omega) * decay.clone();
+    target.displaced((change + temp) * decay)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SpringDamper, smooth_damp};
+    use crate::math::{Point, Positive, Vector};
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn smooth_damp_converges() {
+        let target = Point::from((10., -4.));
+        let mut current = Point::from((0., 0.));
+        let mut velocity = Vector::from((0., 0.));
+        let smooth_time = Positive::new(0.3).unwrap();
+        for _ in 0..200 {
+            current = smooth_damp(current, target, &mut velocity, smooth_time, 1. / 60.);
+        }
+        assert_abs_diff_eq!(*current.x(), 10., epsilon = 1e-3);
+        assert_abs_diff_eq!(*current.y(), -4., epsilon = 1e-3);
+    }
+
+    #[test]
+    fn smooth_damp_frame_rate_independent() {
+        let smooth_time = Positive::new(0.5).unwrap();
+        let (mut a, mut va) = (0., 0.);
+        let (mut b, mut vb) = (0., 0.);
+        for _ in 0..30 {
+            a = smooth_damp(a, 1., &mut va, smooth_time, 1. / 30.);
+        }
+        for _ in 0..120 {
+            b = smooth_damp(b, 1., &mut vb, smooth_time, 1. / 120.);
+        }
+        assert_abs_diff_eq!(a, b, epsilon = 1e-2);
+        assert!(a < 1. && b < 1.);
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn smooth_damp_angle_takes_shortest_way() {
+        use crate::math::{Angle, DeltaAngle};
+        use core::f64::consts::PI;
+
+        let target = Angle::from_radians(-0.9 * PI);
+        let mut current = Angle::from_radians(0.9 * PI);
+        let mut velocity = DeltaAngle::from_radians(0.);
+        let smooth_time = Positive::new(0.1).unwrap();
+        current = smooth_damp(current, target, &mut velocity, smooth_time, 0.01);
+        assert!(velocity.into_inner() > 0.);
+        for _ in 0..200 {
+            current = smooth_damp(current, target, &mut velocity, smooth_time, 0.01);
+        }
+        assert_abs_diff_eq!(current.radians(), target.radians(), epsilon = 1e-3);
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn critically_damped_does_not_overshoot() {
+        let mut spring = SpringDamper::critically_damped(
+            0.,
+            Positive::new(100.).unwrap(),
+            Positive::new(1.).unwrap(),
+        );
+        assert_eq!(*spring.damping(), 20.);
+        for _ in 0..1000 {
+            let value = *spring.step(1., 0.001);
+            assert!(value <= 1.);
+        }
+        assert_abs_diff_eq!(*spring.value(), 1., epsilon = 1e-3);
+    }
+
+    #[test]
+    fn underdamped_oscillates() {
+        let mut spring = SpringDamper::new(
+            Vector::from((0., 0.)),
+            Positive::new(100.).unwrap(),
+            1.,
+            Positive::new(1.).unwrap(),
+        );
+        let mut max: f64 = 0.;
+        for _ in 0..1000 {
+            max = max.max(*spring.step(Vector::from((1., 0.)), 0.001).x());
+        }
+        assert!(max > 1.5);
+    }
+}
@@ -195,6 +195,88 @@ impl Sin for f64 {
     }
 }
 
+pub trait Exp {
+    type Output;
+    fn exp(self) -> Self::Output;
+}
+
+#[cfg(feature = "std")]
+impl Exp for f32 {
+    type Output = f32;
+
+    fn exp(self) -> Self::Output {
+        f32::exp(self)
+    }
+}
+
+#[cfg(feature = "libm")]
+impl Exp for f32 {
+    type Output = f32;
+
+    fn exp(self) -> Self::Output {
+        libm::expf(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Exp for f64 {
+    type Output = f64;
+
+    fn exp(self) -> Self::Output {
+        f64::exp(self)
+    }
+}
+
+#[cfg(feature = "libm")]
+impl Exp for f64 {
+    type Output = f64;
+
+    fn exp(self) -> Self::Output {
+        libm::exp(self)
+    }
+}
+
+pub trait Powf<Rhs = Self> {
+    type Output;
+    fn powf(self, n: Rhs) -> Self::Output;
+}
+
+#[cfg(feature = "std")]
+impl Powf for f32 {
+    type Output = f32;
+
+    fn powf(self, n: Self) -> Self::Output {
+        f32::powf(self, n)
+    }
+}
+
+#[cfg(feature = "libm")]
+impl Powf for f32 {
+    type Output = f32;
+
+    fn powf(self, n: Self) -> Self::Output {
+        libm::powf(self, n)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Powf for f64 {
+    type Output = f64;
+
+    fn powf(self, n: Self) -> Self::Output {
+        f64::powf(self, n)
+    }
+}
+
+#[cfg(feature = "libm")]
+impl Powf for f64 {
+    type Output = f64;
+
+    fn powf(self, n: Self) -> Self::Output {
+        libm::pow(self, n)
+    }
+}
+
 pub trait Atan2<Rhs = Self> {
     type Output;
     fn atan2(self, rhs: Rhs) -> Angle<Self::Output>;
@@ -300,6 +382,20 @@ impl Abs for f64 {
     }
 }
 
+macro_rules! impl_abs {
+    ( $($tp: ty),+ ) => {
+        $(
+            impl Abs for $tp {
+                type Output = $tp;
+
+                fn abs(self) -> Self::Output { <$tp>::abs(self) }
+            }
+        )+
+    };
+}
+
+impl_abs! { i8, i16, i32, i64, i128 }
+
 pub trait Floor {
     type Output;
     fn floor(self) -> Self::Output;
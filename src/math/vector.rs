@@ -1,8 +1,12 @@
-use super::{Abs, Angle, Atan2, Sq, Sqrt};
-use crate::math::{Complex, Cos, Sin};
-use core::ops::{Add, Div, Mul, Sub};
+use super::{Abs, Angle, Atan2, DeltaAngle, NonNeg, Point, Sq, Sqrt, Two, Zero};
+use crate::math::{self, Complex, Cos, Sin};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Vector<T> {
     x: T,
     y: T,
@@ -23,6 +27,26 @@ impl<T> From<Vector<T>> for (T, T) {
     }
 }
 
+impl<T> From<[T; 2]> for Vector<T> {
+    fn from([x, y]: [T; 2]) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<Vector<T>> for [T; 2] {
+    fn from(value: Vector<T>) -> Self {
+        [value.x, value.y]
+    }
+}
+
+/// Position vector of point relative to origin
+impl<T> From<Point<T>> for Vector<T> {
+    fn from(value: Point<T>) -> Self {
+        let (x, y) = value.into();
+        Self { x, y }
+    }
+}
+
 impl<T> Add for Vector<T>
 where
     T: Add,
@@ -51,6 +75,69 @@ where
     }
 }
 
+impl<T> Neg for Vector<T>
+where
+    T: Neg,
+{
+    type Output = Vector<<T as Neg>::Output>;
+
+    fn neg(self) -> Self::Output {
+        Self::Output {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl<T> AddAssign for Vector<T>
+where
+    T: AddAssign,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T> SubAssign for Vector<T>
+where
+    T: SubAssign,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<T> MulAssign<T> for Vector<T>
+where
+    T: MulAssign + Clone,
+{
+    fn mul_assign(&mut self, rhs: T) {
+        self.x *= rhs.clone();
+        self.y *= rhs;
+    }
+}
+
+impl<T> DivAssign<T> for Vector<T>
+where
+    T: DivAssign + Clone,
+{
+    fn div_assign(&mut self, rhs: T) {
+        self.x /= rhs.clone();
+        self.y /= rhs;
+    }
+}
+
+impl<T: Zero> Zero for Vector<T> {
+    fn zero() -> Self {
+        Self {
+            x: T::zero(),
+            y: T::zero(),
+        }
+    }
+}
+
 impl<T> Mul<T> for Vector<T>
 where
     T: Mul + Clone,
@@ -121,6 +208,17 @@ impl<T> Vector<T> {
         }
     }
 
+    /// Unit vector pointing at `angle`
+    pub fn from_angle(angle: Angle<T>) -> Self
+    where
+        T: Clone + Cos<Output = T> + Sin<Output = T>,
+    {
+        Self {
+            x: angle.clone().cos(),
+            y: angle.sin(),
+        }
+    }
+
     pub fn len(self) -> <<<T as Sq>::Output as Add>::Output as Sqrt>::Output
     where
         T: Sq,
@@ -177,6 +275,121 @@ impl<T> Vector<T> {
         x0 * y1 - y0 * x1
     }
 
+    /// Rotated by 90° counterclockwise (y-up axes)
+    pub fn perp(self) -> Vector<T>
+    where
+        T: Neg<Output = T>,
+    {
+        Self {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+
+    /// Component of `self` parallel to `other`.
+    /// Zero `other` gives NaN components for floats and panics for integers
+    pub fn project_onto(self, other: Self) -> Vector<T>
+    where
+        T: Clone + Sq<Output = T> + Add<Output = T> + Mul<Output = T> + Div<Output = T>,
+    {
+        other.clone() * (self.dot(other.clone()) / other.len_sqr())
+    }
+
+    /// Component of `self` perpendicular to `other`, same zero `other` caveat as `project_onto`
+    pub fn reject_from(self, other: Self) -> Vector<T>
+    where
+        T: Clone
+            + Sq<Output = T>
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>,
+    {
+        self.clone() - self.project_onto(other)
+    }
+
+    /// Mirrored against line with unit `normal`
+    pub fn reflect(self, normal: Self) -> Vector<T>
+    where
+        T: Clone + Two + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+    {
+        self.clone() - normal.clone() * (T::two() * self.dot(normal))
+    }
+
+    /// Signed angle of rotation from `self` to `other` in range -PI..=PI, positive counterclockwise
+    pub fn angle_between(self, other: Self) -> DeltaAngle<T>
+    where
+        T: Clone + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Atan2<Output = T>,
+    {
+        let (cross, dot) = (self.clone().cross(other.clone()), self.dot(other));
+        DeltaAngle::from_radians(cross.atan2(dot).into_inner())
+    }
+
+    /// Shortened to `max` length if longer, direction is kept
+    pub fn clamp_len(self, max: NonNeg<T>) -> Vector<T>
+    where
+        T: Clone
+            + PartialOrd
+            + Sq<Output = T>
+            + Add<Output = T>
+            + Sqrt<Output = T>
+            + Div<Output = T>
+            + Mul<Output = T>,
+    {
+        let max = max.into_inner();
+        let len = self.clone().len();
+        if len > max { self / len * max } else { self }
+    }
+
+    pub fn component_mul(self, rhs: Self) -> Vector<<T as Mul>::Output>
+    where
+        T: Mul,
+    {
+        Vector {
+            x: self.x * rhs.x,
+            y: self.y * rhs.y,
+        }
+    }
+
+    pub fn component_div(self, rhs: Self) -> Vector<<T as Div>::Output>
+    where
+        T: Div,
+    {
+        Vector {
+            x: self.x / rhs.x,
+            y: self.y / rhs.y,
+        }
+    }
+
+    /// Componentwise minimum
+    pub fn min(self, rhs: Self) -> Self
+    where
+        T: PartialOrd,
+    {
+        Self {
+            x: if rhs.x < self.x { rhs.x } else { self.x },
+            y: if rhs.y < self.y { rhs.y } else { self.y },
+        }
+    }
+
+    /// Componentwise maximum
+    pub fn max(self, rhs: Self) -> Self
+    where
+        T: PartialOrd,
+    {
+        Self {
+            x: if rhs.x > self.x { rhs.x } else { self.x },
+            y: if rhs.y > self.y { rhs.y } else { self.y },
+        }
+    }
+
+    pub fn lerp(self, other: Self, t: T) -> Vector<T>
+    where
+        T: Clone + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+    {
+        math::lerp(self, other, t)
+    }
+
     pub fn rotor(self) -> Complex<T>
     where
         T: Sq<Output = T>,
@@ -206,4 +419,71 @@ impl<T> Vector<T> {
     pub fn y(&self) -> &T {
         &self.y
     }
+
+    /// Vector with swapped components
+    pub fn yx(self) -> Self {
+        Self {
+            x: self.y,
+            y: self.x,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Vector;
+    use crate::math::Zero;
+
+    #[test]
+    fn operators() {
+        let mut v = Vector::from((3., -4.));
+        assert_eq!(-v, Vector::from((-3., 4.)));
+        v += Vector::from((1., 1.));
+        v -= Vector::from((2., 0.));
+        v *= 2.;
+        v /= 4.;
+        assert_eq!(v, Vector::from((1., -1.5)));
+        assert_eq!(Vector::<f64>::zero(), Vector::default());
+        let (a, b) = (Vector::from((1, 6)), Vector::from((4, -2)));
+        assert_eq!(a.component_mul(b), Vector::from((4, -12)));
+        assert_eq!(a.component_div(b), Vector::from((0, -3)));
+        assert_eq!(a.min(b), Vector::from((1, -2)));
+        assert_eq!(a.max(b), Vector::from((4, 6)));
+    }
+
+    #[test]
+    fn geometry() {
+        let v = Vector::from((3., 4.));
+        assert_eq!(v.perp(), Vector::from((-4., 3.)));
+        let axis = Vector::from((2., 0.));
+        assert_eq!(v.project_onto(axis), Vector::from((3., 0.)));
+        assert_eq!(v.reject_from(axis), Vector::from((0., 4.)));
+        let (x, y): (f64, f64) = v.project_onto(Vector::zero()).into();
+        assert!(x.is_nan() && y.is_nan());
+        assert_eq!(v.reflect(Vector::from((0., 1.))), Vector::from((3., -4.)));
+        assert_eq!(v.lerp(Vector::from((5., 0.)), 0.5), Vector::from((4., 2.)));
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn angles_and_length() {
+        use crate::math::{Angle, NonNeg};
+        use approx::assert_abs_diff_eq;
+        use core::f64::consts::{FRAC_PI_2, PI};
+
+        let (x, y) = (Vector::from((1., 0.)), Vector::from((0., 2.)));
+        assert_abs_diff_eq!(x.angle_between(y).into_inner(), FRAC_PI_2);
+        assert_abs_diff_eq!(y.angle_between(x).into_inner(), -FRAC_PI_2);
+        assert_abs_diff_eq!(x.angle_between(-x).into_inner(), PI);
+
+        let (x, y) = Vector::from_angle(Angle::from_radians(PI / 3.)).into();
+        assert_abs_diff_eq!([x, y][..], [0.5, 3f64.sqrt() / 2.][..], epsilon = 1e-12);
+
+        let v = Vector::from((3., 4.));
+        assert_eq!(v.clamp_len(NonNeg::new(10.).unwrap()), v);
+        assert_eq!(
+            v.clamp_len(NonNeg::new(2.5).unwrap()),
+            Vector::from((1.5, 2.))
+        );
+    }
 }
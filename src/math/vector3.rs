@@ -0,0 +1,168 @@
+use super::{Sq, Sqrt};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// 3D vector, also used for points in 3D space
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Vector3<T> {
+    x: T,
+    y: T,
+    z: T,
+}
+
+impl<T> From<(T, T, T)> for Vector3<T> {
+    fn from(value: (T, T, T)) -> Self {
+        Self {
+            x: value.0,
+            y: value.1,
+            z: value.2,
+        }
+    }
+}
+
+impl<T> From<Vector3<T>> for (T, T, T) {
+    fn from(value: Vector3<T>) -> Self {
+        (value.x, value.y, value.z)
+    }
+}
+
+impl<T> From<Vector3<T>> for [T; 3] {
+    fn from(value: Vector3<T>) -> Self {
+        [value.x, value.y, value.z]
+    }
+}
+
+impl<T> Add for Vector3<T>
+where
+    T: Add,
+{
+    type Output = Vector3<<T as Add>::Output>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::Output {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+impl<T> Sub for Vector3<T>
+where
+    T: Sub,
+{
+    type Output = Vector3<<T as Sub>::Output>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::Output {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+impl<T> Neg for Vector3<T>
+where
+    T: Neg,
+{
+    type Output = Vector3<<T as Neg>::Output>;
+
+    fn neg(self) -> Self::Output {
+        Self::Output {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+impl<T> Mul<T> for Vector3<T>
+where
+    T: Mul + Clone,
+{
+    type Output = Vector3<<T as Mul>::Output>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::Output {
+            x: self.x * rhs.clone(),
+            y: self.y * rhs.clone(),
+            z: self.z * rhs,
+        }
+    }
+}
+
+impl<T> Div<T> for Vector3<T>
+where
+    T: Div + Clone,
+{
+    type Output = Vector3<<T as Div>::Output>;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Self::Output {
+            x: self.x / rhs.clone(),
+            y: self.y / rhs.clone(),
+            z: self.z / rhs,
+        }
+    }
+}
+
+impl<T> Vector3<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn x(&self) -> &T {
+        &self.x
+    }
+
+    pub fn y(&self) -> &T {
+        &self.y
+    }
+
+    pub fn z(&self) -> &T {
+        &self.z
+    }
+
+    pub fn len(self) -> T
+    where
+        T: Sq<Output = T> + Add<Output = T> + Sqrt<Output = T>,
+    {
+        self.len_sqr().sqrt()
+    }
+
+    pub fn len_sqr(self) -> T
+    where
+        T: Sq<Output = T> + Add<Output = T>,
+    {
+        self.x.sq() + self.y.sq() + self.z.sq()
+    }
+
+    pub fn norm(self) -> Vector3<T>
+    where
+        T: Sq<Output = T> + Add<Output = T> + Sqrt<Output = T> + Div<Output = T> + Clone,
+    {
+        self.clone() / self.len()
+    }
+
+    pub fn dot(self, rhs: Self) -> T
+    where
+        T: Mul<Output = T> + Add<Output = T>,
+    {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+
+    pub fn cross(self, rhs: Self) -> Self
+    where
+        T: Mul<Output = T> + Sub<Output = T> + Clone,
+    {
+        Self {
+            x: self.y.clone() * rhs.z.clone() - self.z.clone() * rhs.y.clone(),
+            y: self.z * rhs.x.clone() - self.x.clone() * rhs.z,
+            z: self.x * rhs.y - self.y * rhs.x,
+        }
+    }
+}
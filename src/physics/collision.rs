@@ -0,0 +1,721 @@
+use super::RigidBody;
+use crate::math::{
+    Abs, Angle, Complex, Cos, One, Point, Positive, Rect, Sin, Size, Sq, Sqrt, Two, Vector, Zero,
+};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Contact<T> {
+    pub point: Point<T>,
+    pub penetration: T,
+}
+
+/// Result of narrow phase collision detection.
+/// `normal` is unit vector pointing from the first shape to the second one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Manifold<T> {
+    normal: Vector<T>,
+    contacts: [Option<Contact<T>>; 2],
+}
+
+impl<T> Manifold<T> {
+    pub fn new(normal: Vector<T>, first: Contact<T>, second: Option<Contact<T>>) -> Self {
+        Self {
+            normal,
+            contacts: [Some(first), second],
+        }
+    }
+
+    pub fn normal(&self) -> &Vector<T> {
+        &self.normal
+    }
+
+    pub fn contacts(&self) -> impl Iterator<Item = &Contact<T>> {
+        self.contacts.iter().flatten()
+    }
+
+    /// Maximal penetration among contacts
+    pub fn penetration(&self) -> T
+    where
+        T: Clone + PartialOrd,
+    {
+        self.contacts()
+            .map(|c| c.penetration.clone())
+            .reduce(|a, b| if b > a { b } else { a })
+            .expect("manifold always has at least one contact")
+    }
+
+    /// Same manifold seen from the second shape
+    pub fn flipped(self) -> Self
+    where
+        T: Neg<Output = T>,
+    {
+        Self {
+            normal: -self.normal,
+            contacts: self.contacts,
+        }
+    }
+
+    fn map_points<F: FnMut(Point<T>) -> Point<T>>(self, mut f: F) -> Self {
+        Self {
+            normal: self.normal,
+            contacts: self.contacts.map(|c| {
+                c.map(|c| Contact {
+                    point: f(c.point),
+                    penetration: c.penetration,
+                })
+            }),
+        }
+    }
+}
+
+/// Collision shape attached to `RigidBody`, centered at its position and rotated with it
+#[derive(Debug, Clone, Copy)]
+pub enum Shape<T> {
+    Circle(Positive<T>),
+    Box(Size<T>),
+}
+
+pub fn circle_circle<T>(
+    a: Point<T>,
+    a_radius: Positive<T>,
+    b: Point<T>,
+    b_radius: Positive<T>,
+) -> Option<Manifold<T>>
+where
+    T: Clone
+        + Zero
+        + One
+        + PartialOrd
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Sq<Output = T>
+        + Sqrt<Output = T>,
+{
+    let d = b - a.clone();
+    let a_radius = a_radius.into_inner();
+    let r = a_radius.clone() + b_radius.into_inner();
+    let dist_sqr = d.clone().len_sqr();
+    if dist_sqr > r.clone().sq() {
+        return None;
+    }
+    let dist = dist_sqr.sqrt();
+    let normal = if dist > T::zero() {
+        d / dist.clone()
+    } else {
+        (T::one(), T::zero()).into()
+    };
+    let contact = Contact {
+        point: a + normal.clone() * a_radius,
+        penetration: r - dist,
+    };
+    Some(Manifold::new(normal, contact, None))
+}
+
+/// Circle against axis aligned rect. Contact point lies on the rect boundary
+pub fn circle_rect<T>(center: Point<T>, radius: Positive<T>, rect: &Rect<T>) -> Option<Manifold<T>>
+where
+    T: Clone
+        + Zero
+        + One
+        + PartialOrd
+        + Neg<Output = T>
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Sq<Output = T>
+        + Sqrt<Output = T>,
+{
+    let radius = radius.into_inner();
+    let closest = rect.closest_point(center.clone());
+    if closest != center {
+        let d = closest.clone() - center;
+        let dist_sqr = d.clone().len_sqr();
+        if dist_sqr > radius.clone().sq() {
+            return None;
+        }
+        let dist = dist_sqr.sqrt();
+        let contact = Contact {
+            point: closest,
+            penetration: radius - dist.clone(),
+        };
+        return Some(Manifold::new(d / dist, contact, None));
+    }
+
+    // center is inside: push out through the nearest edge
+    let (cx, cy) = (center.x().clone(), center.y().clone());
+    let o = T::zero;
+    let l = T::one;
+    let edges: [(T, Vector<T>); 4] = [
+        (cx.clone() - rect.left(), (-l(), o()).into()),
+        (rect.right() - cx, (l(), o()).into()),
+        (cy.clone() - rect.top(), (o(), -l()).into()),
+        (rect.bottom() - cy, (o(), l()).into()),
+    ];
+    let (depth, outward) = edges
+        .into_iter()
+        .reduce(|a, b| if b.0 < a.0 { b } else { a })
+        .expect("rect has four edges");
+    let contact = Contact {
+        point: center + outward.clone() * depth.clone(),
+        penetration: radius + depth,
+    };
+    Some(Manifold::new(-outward, contact, None))
+}
+
+/// Circle against box of `size` centered at `box_center` and rotated by `orientation`
+pub fn circle_box<T>(
+    center: Point<T>,
+    radius: Positive<T>,
+    box_center: Point<T>,
+    orientation: Angle<T>,
+    size: Size<T>,
+) -> Option<Manifold<T>>
+where
+    T: Clone
+        + Zero
+        + One
+        + Two
+        + PartialOrd
+        + Neg<Output = T>
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Sq<Output = T>
+        + Sqrt<Output = T>
+        + Cos<Output = T>
+        + Sin<Output = T>,
+{
+    let rotor = Complex::from_polar(T::one(), orientation);
+    let inverse = !rotor.clone();
+    let local = Point::origin() + (center - box_center.clone()) * inverse;
+    let rect = Rect::from_center(Point::origin(), size);
+    circle_rect(local, radius, &rect).map(|m| {
+        Manifold {
+            normal: m.normal.clone() * rotor.clone(),
+            contacts: m.contacts,
+        }
+        .map_points(|p| box_center.clone() + (p - Point::origin()) * rotor.clone())
+    })
+}
+
+/// Oriented box
+struct Obb<T> {
+    center: Point<T>,
+    axes: [Vector<T>; 2],
+    half: [T; 2],
+}
+
+impl<T> Obb<T>
+where
+    T: Clone
+        + Two
+        + Neg<Output = T>
+        + Add<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Abs<Output = T>
+        + Cos<Output = T>
+        + Sin<Output = T>,
+{
+    fn new(center: Point<T>, orientation: Angle<T>, size: Size<T>) -> Self {
+        let (c, s) = (orientation.clone().cos(), orientation.sin());
+        let (w, h) = size.into();
+        Self {
+            center,
+            axes: [(c.clone(), s.clone()).into(), (-s, c).into()],
+            half: [w / T::two(), h / T::two()],
+        }
+    }
+
+    /// Half length of projection onto unit axis `n`
+    fn radius_along(&self, n: &Vector<T>) -> T {
+        self.half[0].clone() * self.axes[0].clone().dot(n.clone()).abs()
+            + self.half[1].clone() * self.axes[1].clone().dot(n.clone()).abs()
+    }
+}
+
+/**
+ * @brief box_box - separating axis test of two oriented boxes
+ *  Axis of minimal overlap selects reference face, the most antiparallel face of other box is
+ *  clipped against side planes of reference face, clipped points below reference face become contacts.
+ */
+pub fn box_box<T>(
+    a_center: Point<T>,
+    a_orientation: Angle<T>,
+    a_size: Size<T>,
+    b_center: Point<T>,
+    b_orientation: Angle<T>,
+    b_size: Size<T>,
+) -> Option<Manifold<T>>
+where
+    T: Clone
+        + Zero
+        + Two
+        + PartialOrd
+        + Neg<Output = T>
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Abs<Output = T>
+        + Cos<Output = T>
+        + Sin<Output = T>,
+{
+    let boxes = [
+        Obb::new(a_center, a_orientation, a_size),
+        Obb::new(b_center, b_orientation, b_size),
+    ];
+    let d = boxes[1].center.clone() - boxes[0].center.clone();
+
+    let mut best: Option<(T, usize, usize)> = None;
+    for owner in 0..2 {
+        for axis in 0..2 {
+            let n = &boxes[owner].axes[axis];
+            let overlap = boxes[0].radius_along(n) + boxes[1].radius_along(n)
+                - d.clone().dot(n.clone()).abs();
+            if overlap < T::zero() {
+                return None;
+            }
+            if best.as_ref().is_none_or(|b| overlap < b.0) {
+                best = Some((overlap, owner, axis));
+            }
+        }
+    }
+    let (overlap, owner, axis) = best?;
+    let reference = &boxes[owner];
+    let incident = &boxes[1 - owner];
+
+    // reference face normal pointing towards incident box
+    let n = reference.axes[axis].clone();
+    let n = if (incident.center.clone() - reference.center.clone()).dot(n.clone()) < T::zero() {
+        -n
+    } else {
+        n
+    };
+    let side = reference.axes[1 - axis].clone();
+    let side_half = reference.half[1 - axis].clone();
+    let face_half = reference.half[axis].clone();
+
+    // incident face with normal most antiparallel to `n`
+    let dots = [
+        incident.axes[0].clone().dot(n.clone()),
+        incident.axes[1].clone().dot(n.clone()),
+    ];
+    let j = if dots[0].clone().abs() > dots[1].clone().abs() {
+        0
+    } else {
+        1
+    };
+    let m = if dots[j] > T::zero() {
+        -incident.axes[j].clone()
+    } else {
+        incident.axes[j].clone()
+    };
+    let edge_center = incident.center.clone() + m * incident.half[j].clone();
+    let edge = incident.axes[1 - j].clone() * incident.half[1 - j].clone();
+    let mut p = [edge_center.clone() + edge.clone(), edge_center - edge];
+
+    // clip incident edge by side planes of reference face
+    let along = |p: &Point<T>| side.clone().dot(p.clone() - reference.center.clone());
+    for limit in [side_half.clone(), -side_half] {
+        let sign = |x: T| if limit < T::zero() { -x } else { x };
+        let u = [sign(along(&p[0])), sign(along(&p[1]))];
+        let bound = sign(limit.clone());
+        for k in 0..2 {
+            if u[k] > bound && u[1 - k] < u[k] {
+                let t = (u[k].clone() - bound.clone()) / (u[k].clone() - u[1 - k].clone());
+                p[k] = p[k].clone() + (p[1 - k].clone() - p[k].clone()) * t;
+            }
+        }
+    }
+
+    let contact = |p: Point<T>| {
+        let separation = n.clone().dot(p.clone() - reference.center.clone()) - face_half.clone();
+        (separation <= T::zero()).then(|| Contact {
+            point: p,
+            penetration: -separation,
+        })
+    };
+    let [p0, p1] = p;
+    let normal = if owner == 0 { n.clone() } else { -n.clone() };
+    let manifold = match (contact(p0.clone()), contact(p1)) {
+        (Some(c0), c1) => Manifold::new(normal, c0, c1),
+        (None, Some(c1)) => Manifold::new(normal, c1, None),
+        // boxes barely touch and clipping lost both points because of rounding
+        (None, None) => Manifold::new(
+            normal,
+            Contact {
+                point: p0,
+                penetration: overlap,
+            },
+            None,
+        ),
+    };
+    Some(manifold)
+}
+
+/// Narrow phase for shapes attached to bodies
+pub fn collide<T>(
+    a_shape: &Shape<T>,
+    a: &RigidBody<T>,
+    b_shape: &Shape<T>,
+    b: &RigidBody<T>,
+) -> Option<Manifold<T>>
+where
+    T: Clone
+        + Zero
+        + One
+        + Two
+        + PartialOrd
+        + Neg<Output = T>
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Sq<Output = T>
+        + Sqrt<Output = T>
+        + Abs<Output = T>
+        + Cos<Output = T>
+        + Sin<Output = T>,
+{
+    let (pa, pb) = (a.position().clone(), b.position().clone());
+    let (oa, ob) = (a.orientation().clone(), b.orientation().clone());
+    match (a_shape.clone(), b_shape.clone()) {
+        (Shape::Circle(ra), Shape::Circle(rb)) => circle_circle(pa, ra, pb, rb),
+        (Shape::Circle(r), Shape::Box(size)) => circle_box(pa, r, pb, ob, size),
+        (Shape::Box(size), Shape::Circle(r)) => {
+            circle_box(pb, r, pa, oa, size).map(Manifold::flipped)
+        }
+        (Shape::Box(sa), Shape::Box(sb)) => box_box(pa, oa, sa, pb, ob, sb),
+    }
+}
+
+/// Surface properties of colliding pair
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Material<T> {
+    /// 0 for perfectly inelastic, 1 for perfectly elastic collision
+    pub restitution: T,
+    /// Coulomb friction coefficient
+    pub friction: T,
+}
+
+/// Inverse mass, inverse inertia, position and velocity at point of optional (static if none) body
+fn body_response<T>(
+    body: &Option<&mut RigidBody<T>>,
+    point: &Point<T>,
+) -> (T, T, Vector<T>, Vector<T>)
+where
+    T: Clone
+        + Zero
+        + One
+        + Neg<Output = T>
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>,
+{
+    match body {
+        Some(body) => (
+            body.inverse_mass(),
+            body.inverse_inertia(),
+            point.clone() - body.position().clone(),
+            body.velocity_at(point.clone()),
+        ),
+        None => (
+            T::zero(),
+            T::zero(),
+            (T::zero(), T::zero()).into(),
+            (T::zero(), T::zero()).into(),
+        ),
+    }
+}
+
+/// Apply `impulse` to the second body and opposite one to the first body
+fn apply_pair_impulse<T>(
+    a: &mut Option<&mut RigidBody<T>>,
+    b: &mut Option<&mut RigidBody<T>>,
+    point: &Point<T>,
+    impulse: Vector<T>,
+) where
+    T: Clone
+        + One
+        + Neg<Output = T>
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>,
+{
+    if let Some(a) = a {
+        a.apply_impulse_at(-impulse.clone(), point.clone());
+    }
+    if let Some(b) = b {
+        b.apply_impulse_at(impulse, point.clone());
+    }
+}
+
+fn resolve<T>(
+    mut a: Option<&mut RigidBody<T>>,
+    mut b: Option<&mut RigidBody<T>>,
+    manifold: &Manifold<T>,
+    material: &Material<T>,
+) where
+    T: Clone
+        + Zero
+        + One
+        + PartialOrd
+        + Neg<Output = T>
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Sq<Output = T>
+        + Sqrt<Output = T>,
+{
+    let n = manifold.normal.clone();
+    for contact in manifold.contacts() {
+        let point = &contact.point;
+        let (inv_ma, inv_ia, ra, va) = body_response(&a, point);
+        let (inv_mb, inv_ib, rb, vb) = body_response(&b, point);
+        let effective_mass = |dir: &Vector<T>| {
+            inv_ma.clone()
+                + inv_mb.clone()
+                + ra.clone().cross(dir.clone()).sq() * inv_ia.clone()
+                + rb.clone().cross(dir.clone()).sq() * inv_ib.clone()
+        };
+
+        let rv = vb - va;
+        let vn = rv.clone().dot(n.clone());
+        if vn > T::zero() {
+            continue;
+        }
+        let j = -(T::one() + material.restitution.clone()) * vn / effective_mass(&n);
+        apply_pair_impulse(&mut a, &mut b, point, n.clone() * j.clone());
+
+        // friction with recomputed relative velocity
+        let (_, _, _, va) = body_response(&a, point);
+        let (_, _, _, vb) = body_response(&b, point);
+        let rv = vb - va;
+        let tangent = rv.clone() - n.clone() * rv.clone().dot(n.clone());
+        let tangent_len = tangent.clone().len();
+        if tangent_len <= T::zero() {
+            continue;
+        }
+        let tangent = tangent / tangent_len;
+        let jt = -rv.dot(tangent.clone()) / effective_mass(&tangent);
+        let max = material.friction.clone() * j;
+        let jt = if jt > max.clone() {
+            max
+        } else if jt < -max.clone() {
+            -max
+        } else {
+            jt
+        };
+        apply_pair_impulse(&mut a, &mut b, point, tangent * jt);
+    }
+}
+
+/// Sequential impulse resolution of velocities of two colliding bodies
+pub fn resolve_collision<T>(
+    a: &mut RigidBody<T>,
+    b: &mut RigidBody<T>,
+    manifold: &Manifold<T>,
+    material: &Material<T>,
+) where
+    T: Clone
+        + Zero
+        + One
+        + PartialOrd
+        + Neg<Output = T>
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Sq<Output = T>
+        + Sqrt<Output = T>,
+{
+    resolve(Some(a), Some(b), manifold, material)
+}
+
+/// Same as `resolve_collision` when the second shape is static (infinite mass)
+pub fn resolve_static_collision<T>(
+    body: &mut RigidBody<T>,
+    manifold: &Manifold<T>,
+    material: &Material<T>,
+) where
+    T: Clone
+        + Zero
+        + One
+        + PartialOrd
+        + Neg<Output = T>
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Sq<Output = T>
+        + Sqrt<Output = T>,
+{
+    resolve(Some(body), None, manifold, material)
+}
+
+/**
+ * @brief correct_positions - push bodies apart proportionally to inverse masses to prevent sinking
+ * @param percent - part of penetration resolved per call (usually 0.2..0.8)
+ * @param slop - penetration which is allowed to remain to avoid jitter
+ */
+pub fn correct_positions<T>(
+    a: &mut RigidBody<T>,
+    b: Option<&mut RigidBody<T>>,
+    manifold: &Manifold<T>,
+    percent: T,
+    slop: T,
+) where
+    T: Clone
+        + Zero
+        + One
+        + PartialOrd
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>,
+{
+    let depth = manifold.penetration() - slop;
+    if depth <= T::zero() {
+        return;
+    }
+    let inv_ma = a.inverse_mass();
+    let inv_mb = b.as_ref().map(|b| b.inverse_mass()).unwrap_or_else(T::zero);
+    let correction =
+        manifold.normal.clone() * (depth * percent / (inv_ma.clone() + inv_mb.clone()));
+    a.set_position(a.position().clone() - correction.clone() * inv_ma);
+    if let Some(b) = b {
+        b.set_position(b.position().clone() + correction * inv_mb);
+    }
+}
+
+#[cfg(test)]
+#[cfg(any(feature = "std", feature = "libm"))]
+mod tests {
+    use super::{
+        Material, Shape, box_box, circle_circle, circle_rect, collide, correct_positions,
+        resolve_collision, resolve_static_collision,
+    };
+    use crate::math::{Angle, Point, Positive, Rect, Vector};
+    use crate::physics::RigidBody;
+    use approx::assert_abs_diff_eq;
+
+    fn positive(x: f64) -> Positive<f64> {
+        Positive::new(x).unwrap()
+    }
+
+    #[test]
+    fn circles() {
+        let m = circle_circle(
+            (0., 0.).into(),
+            positive(1.),
+            (1.5, 0.).into(),
+            positive(1.),
+        )
+        .unwrap();
+        assert_eq!(*m.normal(), Vector::from((1., 0.)));
+        assert_eq!(m.penetration(), 0.5);
+        assert!(
+            circle_circle((0., 0.).into(), positive(1.), (3., 0.).into(), positive(1.)).is_none()
+        );
+
+        let rect = Rect::from((0., 0., 2., 2.));
+        let m = circle_rect((1., -0.5).into(), positive(1.), &rect).unwrap();
+        assert_eq!(*m.normal(), Vector::from((0., 1.)));
+        assert_eq!(m.penetration(), 0.5);
+        assert_eq!(m.contacts().next().unwrap().point, Point::from((1., 0.)));
+
+        // center inside, nearest edge is right one
+        let m = circle_rect((1.8, 1.).into(), positive(0.5), &rect).unwrap();
+        assert_eq!(*m.normal(), Vector::from((-1., 0.)));
+        assert_abs_diff_eq!(m.penetration(), 0.7, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn boxes() {
+        let zero = Angle::from_radians(0.);
+        let m = box_box(
+            (0., 0.).into(),
+            zero,
+            (2., 2.).into(),
+            (1.5, 0.5).into(),
+            zero,
+            (2., 2.).into(),
+        )
+        .unwrap();
+        assert_eq!(*m.normal(), Vector::from((1., 0.)));
+        assert_eq!(m.contacts().count(), 2);
+        assert_abs_diff_eq!(m.penetration(), 0.5);
+        for c in m.contacts() {
+            assert_abs_diff_eq!(*c.point.x(), 0.5);
+        }
+
+        // rotated by 45°, corner touches face
+        let m = box_box(
+            (0., 0.).into(),
+            zero,
+            (2., 2.).into(),
+            (0., 2.3).into(),
+            Angle::from_degrees(45.),
+            (2., 2.).into(),
+        )
+        .unwrap();
+        assert_abs_diff_eq!(f64::abs(*m.normal().x()), 0., epsilon = 1e-12);
+        assert_abs_diff_eq!(*m.normal().y(), 1., epsilon = 1e-12);
+        assert_eq!(m.contacts().count(), 1);
+        assert_abs_diff_eq!(m.penetration(), 2_f64.sqrt() - 1.3, epsilon = 1e-12);
+
+        assert!(
+            box_box(
+                (0., 0.).into(),
+                zero,
+                (2., 2.).into(),
+                (0., 2.5).into(),
+                Angle::from_degrees(45.),
+                (2., 2.).into(),
+            )
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn resolution() {
+        let material = Material {
+            restitution: 1.,
+            friction: 0.,
+        };
+        let mut a = RigidBody::new((0., 0.).into(), positive(1.), positive(1.));
+        let mut b = RigidBody::new((1.5, 0.).into(), positive(1.), positive(1.));
+        a.set_velocity((1., 0.).into());
+        let shape = Shape::Circle(positive(1.));
+        let m = collide(&shape, &a, &shape, &b).unwrap();
+        resolve_collision(&mut a, &mut b, &m, &material);
+        // elastic collision of equal masses exchanges velocities
+        assert_abs_diff_eq!(*a.velocity().x(), 0.);
+        assert_abs_diff_eq!(*b.velocity().x(), 1.);
+
+        correct_positions(&mut a, Some(&mut b), &m, 1., 0.);
+        assert_abs_diff_eq!(*b.position().x() - *a.position().x(), 2.);
+
+        // sliding box stopped by friction on static ground
+        let material = Material {
+            restitution: 0.,
+            friction: 1.,
+        };
+        let mut body = RigidBody::new((0., -0.9).into(), positive(1.), positive(1e9));
+        body.set_velocity((0.1, 1.).into());
+        let ground = Rect::from((-10., 0., 20., 1.));
+        let m = circle_rect(*body.position(), positive(1.), &ground).unwrap();
+        resolve_static_collision(&mut body, &m, &material);
+        assert_abs_diff_eq!(*body.velocity().x(), 0., epsilon = 1e-6);
+        assert_abs_diff_eq!(*body.velocity().y(), 0., epsilon = 1e-6);
+    }
+}
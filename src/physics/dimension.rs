@@ -0,0 +1,233 @@
+use core::marker::PhantomData;
+
+/// Type-level integer. Canonical forms are `Z0`, `Succ<Succ<..Z0>>` (positive) and `Pred<Pred<..Z0>>` (negative).
+pub trait Integer: Default + Copy {
+    const VALUE: i8;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Z0;
+
+/// `N + 1`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Succ<N>(PhantomData<N>);
+
+/// `N - 1`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Pred<N>(PhantomData<N>);
+
+pub type P1 = Succ<Z0>;
+pub type P2 = Succ<P1>;
+pub type P3 = Succ<P2>;
+pub type P4 = Succ<P3>;
+pub type N1 = Pred<Z0>;
+pub type N2 = Pred<N1>;
+pub type N3 = Pred<N2>;
+pub type N4 = Pred<N3>;
+
+impl Integer for Z0 {
+    const VALUE: i8 = 0;
+}
+
+impl<N: Integer> Integer for Succ<N> {
+    const VALUE: i8 = N::VALUE + 1;
+}
+
+impl<N: Integer> Integer for Pred<N> {
+    const VALUE: i8 = N::VALUE - 1;
+}
+
+pub trait Inc {
+    type Output: Integer;
+}
+
+pub trait Dec {
+    type Output: Integer;
+}
+
+impl Inc for Z0 {
+    type Output = P1;
+}
+
+impl<N: Integer> Inc for Succ<N> {
+    type Output = Succ<Succ<N>>;
+}
+
+impl<N: Integer> Inc for Pred<N> {
+    type Output = N;
+}
+
+impl Dec for Z0 {
+    type Output = N1;
+}
+
+impl<N: Integer> Dec for Succ<N> {
+    type Output = N;
+}
+
+impl<N: Integer> Dec for Pred<N> {
+    type Output = Pred<Pred<N>>;
+}
+
+pub trait IntNeg {
+    type Output: Integer;
+}
+
+impl IntNeg for Z0 {
+    type Output = Z0;
+}
+
+impl<N: IntNeg> IntNeg for Succ<N>
+where
+    Pred<<N as IntNeg>::Output>: Integer,
+{
+    type Output = Pred<<N as IntNeg>::Output>;
+}
+
+impl<N: IntNeg> IntNeg for Pred<N>
+where
+    Succ<<N as IntNeg>::Output>: Integer,
+{
+    type Output = Succ<<N as IntNeg>::Output>;
+}
+
+pub trait IntAdd<Rhs> {
+    type Output: Integer;
+}
+
+impl<B: Integer> IntAdd<B> for Z0 {
+    type Output = B;
+}
+
+impl<A, B: Inc> IntAdd<B> for Succ<A>
+where
+    A: IntAdd<<B as Inc>::Output>,
+{
+    type Output = <A as IntAdd<<B as Inc>::Output>>::Output;
+}
+
+impl<A, B: Dec> IntAdd<B> for Pred<A>
+where
+    A: IntAdd<<B as Dec>::Output>,
+{
+    type Output = <A as IntAdd<<B as Dec>::Output>>::Output;
+}
+
+pub trait IntSub<Rhs> {
+    type Output: Integer;
+}
+
+impl<A, B: IntNeg> IntSub<B> for A
+where
+    A: IntAdd<<B as IntNeg>::Output>,
+{
+    type Output = <A as IntAdd<<B as IntNeg>::Output>>::Output;
+}
+
+/// Physical dimension as exponents of the seven SI base units:
+/// length (m), mass (kg), time (s), electric current (A), temperature (K), amount of substance (mol), luminous intensity (cd)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Dim<L, M, T, I, Th, N, J>(PhantomData<(L, M, T, I, Th, N, J)>);
+
+pub trait Dimension: Default + Copy {
+    /// exponents in order m, kg, s, A, K, mol, cd
+    const EXPONENTS: [i8; 7];
+}
+
+impl<L, M, T, I, Th, N, J> Dimension for Dim<L, M, T, I, Th, N, J>
+where
+    L: Integer,
+    M: Integer,
+    T: Integer,
+    I: Integer,
+    Th: Integer,
+    N: Integer,
+    J: Integer,
+{
+    const EXPONENTS: [i8; 7] = [
+        L::VALUE,
+        M::VALUE,
+        T::VALUE,
+        I::VALUE,
+        Th::VALUE,
+        N::VALUE,
+        J::VALUE,
+    ];
+}
+
+/// Dimension of a product
+pub trait DimMul<Rhs> {
+    type Output: Dimension;
+}
+
+/// Dimension of a quotient
+pub trait DimDiv<Rhs> {
+    type Output: Dimension;
+}
+
+impl<La, Ma, Ta, Ia, Tha, Na, Ja, Lb, Mb, Tb, Ib, Thb, Nb, Jb>
+    DimMul<Dim<Lb, Mb, Tb, Ib, Thb, Nb, Jb>> for Dim<La, Ma, Ta, Ia, Tha, Na, Ja>
+where
+    La: IntAdd<Lb>,
+    Ma: IntAdd<Mb>,
+    Ta: IntAdd<Tb>,
+    Ia: IntAdd<Ib>,
+    Tha: IntAdd<Thb>,
+    Na: IntAdd<Nb>,
+    Ja: IntAdd<Jb>,
+{
+    type Output = Dim<
+        <La as IntAdd<Lb>>::Output,
+        <Ma as IntAdd<Mb>>::Output,
+        <Ta as IntAdd<Tb>>::Output,
+        <Ia as IntAdd<Ib>>::Output,
+        <Tha as IntAdd<Thb>>::Output,
+        <Na as IntAdd<Nb>>::Output,
+        <Ja as IntAdd<Jb>>::Output,
+    >;
+}
+
+impl<La, Ma, Ta, Ia, Tha, Na, Ja, Lb, Mb, Tb, Ib, Thb, Nb, Jb>
+    DimDiv<Dim<Lb, Mb, Tb, Ib, Thb, Nb, Jb>> for Dim<La, Ma, Ta, Ia, Tha, Na, Ja>
+where
+    La: IntSub<Lb>,
+    Ma: IntSub<Mb>,
+    Ta: IntSub<Tb>,
+    Ia: IntSub<Ib>,
+    Tha: IntSub<Thb>,
+    Na: IntSub<Nb>,
+    Ja: IntSub<Jb>,
+{
+    type Output = Dim<
+        <La as IntSub<Lb>>::Output,
+        <Ma as IntSub<Mb>>::Output,
+        <Ta as IntSub<Tb>>::Output,
+        <Ia as IntSub<Ib>>::Output,
+        <Tha as IntSub<Thb>>::Output,
+        <Na as IntSub<Nb>>::Output,
+        <Ja as IntSub<Jb>>::Output,
+    >;
+}
+
+pub type Dimensionless = Dim<Z0, Z0, Z0, Z0, Z0, Z0, Z0>;
+pub type Length = Dim<P1, Z0, Z0, Z0, Z0, Z0, Z0>;
+pub type Mass = Dim<Z0, P1, Z0, Z0, Z0, Z0, Z0>;
+pub type Time = Dim<Z0, Z0, P1, Z0, Z0, Z0, Z0>;
+pub type Current = Dim<Z0, Z0, Z0, P1, Z0, Z0, Z0>;
+pub type Temperature = Dim<Z0, Z0, Z0, Z0, P1, Z0, Z0>;
+pub type Amount = Dim<Z0, Z0, Z0, Z0, Z0, P1, Z0>;
+pub type LuminousIntensity = Dim<Z0, Z0, Z0, Z0, Z0, Z0, P1>;
+
+pub type Area = Dim<P2, Z0, Z0, Z0, Z0, Z0, Z0>;
+pub type Volume = Dim<P3, Z0, Z0, Z0, Z0, Z0, Z0>;
+pub type Density = Dim<N3, P1, Z0, Z0, Z0, Z0, Z0>;
+pub type Frequency = Dim<Z0, Z0, N1, Z0, Z0, Z0, Z0>;
+pub type Velocity = Dim<P1, Z0, N1, Z0, Z0, Z0, Z0>;
+pub type Acceleration = Dim<P1, Z0, N2, Z0, Z0, Z0, Z0>;
+pub type Momentum = Dim<P1, P1, N1, Z0, Z0, Z0, Z0>;
+pub type Force = Dim<P1, P1, N2, Z0, Z0, Z0, Z0>;
+pub type Pressure = Dim<N1, P1, N2, Z0, Z0, Z0, Z0>;
+pub type Energy = Dim<P2, P1, N2, Z0, Z0, Z0, Z0>;
+pub type Power = Dim<P2, P1, N3, Z0, Z0, Z0, Z0>;
+pub type Charge = Dim<Z0, Z0, P1, P1, Z0, Z0, Z0>;
+pub type Voltage = Dim<P2, P1, N3, N1, Z0, Z0, Z0>;
@@ -0,0 +1,211 @@
+use crate::math::{Interpolate, Positive, Zero};
+use core::cmp::Ordering;
+use core::ops::{Add, Div, Rem, Sub};
+
+/// Outcome of `FixedStepper::advance`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Steps<T> {
+    /// Number of fixed steps executed
+    pub executed: usize,
+    /// Simulated time (whole steps) skipped because of `max_steps` limit
+    pub dropped: T,
+    /// Position of current time between last two simulated states in range 0..1
+    pub alpha: T,
+}
+
+/// Fixed timestep simulation loop.
+/// Real frame time is accumulated and consumed in steps of constant `dt` so that simulation does not depend on frame rate.
+/// Rendering should blend last two simulated states with returned `alpha` (see `interpolate`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FixedStepper<T> {
+    dt: T,
+    accumulator: T,
+    max_steps: usize,
+}
+
+impl<T> FixedStepper<T> {
+    /// `max_steps` limits steps per frame, so that slow simulation does not fall into "spiral of death"
+    pub fn new(dt: Positive<T>, max_steps: usize) -> Self
+    where
+        T: Zero,
+    {
+        Self {
+            dt: dt.into_inner(),
+            accumulator: T::zero(),
+            max_steps,
+        }
+    }
+
+    pub fn dt(&self) -> &T {
+        &self.dt
+    }
+
+    pub fn max_steps(&self) -> usize {
+        self.max_steps
+    }
+
+    /// Time accumulated but not simulated yet
+    pub fn accumulator(&self) -> &T {
+        &self.accumulator
+    }
+
+    pub fn alpha(&self) -> T
+    where
+        T: Clone + Div<Output = T>,
+    {
+        self.accumulator.clone() / self.dt.clone()
+    }
+
+    /// Accumulate `frame_dt` and call `step` with fixed `dt` as many times as fits (but not more than `max_steps`).
+    /// Time of whole steps exceeding limit is dropped, so accumulator always stays in range `0..dt`.
+    /// Non-finite `frame_dt` resets accumulator to zero.
+    pub fn advance<F>(&mut self, frame_dt: T, mut step: F) -> Steps<T>
+    where
+        T: Clone
+            + Zero
+            + PartialOrd
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Div<Output = T>
+            + Rem<Output = T>,
+        F: FnMut(T),
+    {
+        let mut accumulator = self.accumulator.clone() + frame_dt;
+        let mut executed = 0;
+        while accumulator >= self.dt && executed < self.max_steps {
+            accumulator = accumulator - self.dt.clone();
+            executed += 1;
+        }
+        let mut dropped = T::zero();
+        if accumulator >= self.dt {
+            let rest = accumulator.clone() % self.dt.clone();
+            if rest >= T::zero() && rest < self.dt {
+                dropped = accumulator - rest.clone();
+                accumulator = rest;
+            } else {
+                // infinite accumulator has no remainder
+                dropped = accumulator;
+                accumulator = T::zero();
+            }
+        }
+        // NaN or negative frame_dt
+        if !accumulator
+            .partial_cmp(&T::zero())
+            .is_some_and(Ordering::is_ge)
+        {
+            accumulator = T::zero();
+        }
+        self.accumulator = accumulator;
+        for _ in 0..executed {
+            step(self.dt.clone());
+        }
+        Steps {
+            executed,
+            dropped,
+            alpha: self.alpha(),
+        }
+    }
+
+    /// Blend `previous` and `current` simulated states with current `alpha` for rendering
+    pub fn interpolate<V>(&self, previous: V, current: V) -> V
+    where
+        V: Interpolate<T>,
+        T: Clone + Div<Output = T>,
+    {
+        V::interpolate(previous, current, self.alpha())
+    }
+
+    pub fn reset(&mut self)
+    where
+        T: Zero,
+    {
+        self.accumulator = T::zero()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FixedStepper, Steps};
+    use crate::math::Positive;
+
+    #[test]
+    fn advance() {
+        let mut stepper = FixedStepper::new(Positive::new(0.25).unwrap(), 3);
+        let mut time = 0.;
+        let mut previous = 0.;
+
+        let steps = stepper.advance(0.625, |dt| {
+            previous = time;
+            time += dt;
+        });
+        assert_eq!(
+            steps,
+            Steps {
+                executed: 2,
+                dropped: 0.,
+                alpha: 0.5
+            }
+        );
+        assert_eq!(time, 0.5);
+        assert_eq!(stepper.interpolate(previous, time), 0.375);
+
+        let steps = stepper.advance(1.5, |dt| time += dt);
+        assert_eq!(
+            steps,
+            Steps {
+                executed: 3,
+                dropped: 0.75,
+                alpha: 0.5
+            }
+        );
+        assert_eq!(time, 1.25);
+    }
+
+    #[test]
+    fn huge_frame() {
+        let mut stepper = FixedStepper::new(Positive::new(0.001_f32).unwrap(), 5);
+        let mut calls = 0;
+        let steps = stepper.advance(40000., |_| calls += 1);
+        assert_eq!(steps.executed, 5);
+        assert_eq!(calls, 5);
+        assert!(steps.dropped > 39_999.);
+        assert!(*stepper.accumulator() >= 0. && *stepper.accumulator() < 0.001);
+
+        let steps = stepper.advance(f32::INFINITY, |_| calls += 1);
+        assert_eq!((steps.executed, steps.dropped), (5, f32::INFINITY));
+        assert_eq!((*stepper.accumulator(), steps.alpha), (0., 0.));
+
+        let steps = stepper.advance(f32::NAN, |_| calls += 1);
+        assert_eq!((steps.executed, steps.dropped), (0, 0.));
+        assert_eq!(*stepper.accumulator(), 0.);
+        assert_eq!(calls, 10);
+    }
+
+    #[test]
+    fn rounding_remainder() {
+        // 0.59 / 0.01 rounds below 59, but no simulated time may be lost
+        let mut stepper = FixedStepper::new(Positive::new(0.01).unwrap(), 100);
+        let mut time = 0.;
+        let mut frames = 0.;
+        for _ in 0..100 {
+            let steps = stepper.advance(0.59, |dt| time += dt);
+            assert_eq!(steps.dropped, 0.);
+            assert!(*stepper.accumulator() >= 0. && *stepper.accumulator() < 0.01);
+            frames += 0.59;
+        }
+        assert!((time + stepper.accumulator() - frames) < 1e-9);
+        assert!((time + stepper.accumulator() - frames) > -1e-9);
+    }
+
+    #[test]
+    fn f32_steps() {
+        let mut stepper = FixedStepper::new(Positive::new(0.5_f32).unwrap(), 10);
+        let mut time = 0_f32;
+        let steps = stepper.advance(1.75, |dt| time += dt);
+        assert_eq!((steps.executed, steps.dropped, steps.alpha), (3, 0., 0.5));
+        assert_eq!(time, 1.5);
+        let steps = stepper.advance(0.25, |dt| time += dt);
+        assert_eq!((steps.executed, steps.dropped, steps.alpha), (1, 0., 0.));
+        assert_eq!(time, 2.);
+    }
+}
@@ -1,101 +1,224 @@
-use crate::math::{One, Rational};
+use super::dimension::*;
+use crate::math::{One, Zero};
 use core::fmt::{Display, Formatter};
 use core::iter::Sum;
-use core::ops::{Add, Div, Mul, Sub};
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// Value of a physical quantity expressed in SI base units.
+/// `D` is a type-level `Dim` so that `Mul`/`Div` compute result dimension
+/// and `Add`/`Sub` compile only for quantities of the same dimension.
 #[repr(transparent)]
-#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct Kg<T>(pub T);
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Quantity<T, D> {
+    value: T,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    dimension: PhantomData<D>,
+}
 
-/// m³
-#[repr(transparent)]
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct M3<T>(pub T);
+impl<T, D> Quantity<T, D> {
+    pub const fn new(value: T) -> Self {
+        Self {
+            value,
+            dimension: PhantomData,
+        }
+    }
 
-impl Div for M3<f32> {
-    type Output = f32;
+    pub fn value(&self) -> &T {
+        &self.value
+    }
 
-    fn div(self, rhs: Self) -> Self::Output {
-        self.0 / rhs.0
+    pub fn into_inner(self) -> T {
+        self.value
     }
 }
 
-impl Div for M3<f64> {
-    type Output = f64;
+pub type Meters<T> = Quantity<T, Length>;
+pub type Kg<T> = Quantity<T, Mass>;
+pub type Seconds<T> = Quantity<T, Time>;
+pub type Amperes<T> = Quantity<T, Current>;
+pub type Kelvins<T> = Quantity<T, Temperature>;
+pub type Moles<T> = Quantity<T, Amount>;
+pub type Candelas<T> = Quantity<T, LuminousIntensity>;
 
-    fn div(self, rhs: Self) -> Self::Output {
-        self.0 / rhs.0
+/// m²
+pub type M2<T> = Quantity<T, Area>;
+/// m³
+pub type M3<T> = Quantity<T, Volume>;
+/// kg/m³. `Kg<u32> / M3<u32>` truncates as integer division does, exact integer density is
+/// `KgPerM3<Rational<u32, u32>>` which serializes as former `KgPerM3<u32>` did
+pub type KgPerM3<T> = Quantity<T, Density>;
+pub type Hertz<T> = Quantity<T, Frequency>;
+pub type MetersPerSecond<T> = Quantity<T, Velocity>;
+pub type MetersPerSecond2<T> = Quantity<T, Acceleration>;
+pub type Newtons<T> = Quantity<T, Force>;
+pub type Pascals<T> = Quantity<T, Pressure>;
+pub type Joules<T> = Quantity<T, Energy>;
+pub type Watts<T> = Quantity<T, Power>;
+pub type Coulombs<T> = Quantity<T, Charge>;
+pub type Volts<T> = Quantity<T, Voltage>;
+
+impl<T, D> Add for Quantity<T, D>
+where
+    T: Add,
+{
+    type Output = Quantity<<T as Add>::Output, D>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Quantity::new(self.value + rhs.value)
     }
 }
 
-impl Div for M3<u32> {
-    type Output = f32;
-
-    fn div(self, rhs: Self) -> Self::Output {
-        self.0 as f32 / rhs.0 as f32
+impl<T, D> AddAssign for Quantity<T, D>
+where
+    T: AddAssign,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        self.value += rhs.value
     }
 }
 
-impl Div for M3<u64> {
-    type Output = f64;
+impl<T, D> Sub for Quantity<T, D>
+where
+    T: Sub,
+{
+    type Output = Quantity<<T as Sub>::Output, D>;
 
-    fn div(self, rhs: Self) -> Self::Output {
-        self.0 as f64 / rhs.0 as f64
+    fn sub(self, rhs: Self) -> Self::Output {
+        Quantity::new(self.value - rhs.value)
     }
 }
 
-impl Div<usize> for M3<u32> {
-    type Output = M3<u32>;
+impl<T, D> SubAssign for Quantity<T, D>
+where
+    T: SubAssign,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        self.value -= rhs.value
+    }
+}
 
-    fn div(self, rhs: usize) -> Self::Output {
-        M3(self.0 / rhs as u32)
+impl<T, D> Neg for Quantity<T, D>
+where
+    T: Neg,
+{
+    type Output = Quantity<<T as Neg>::Output, D>;
+
+    fn neg(self) -> Self::Output {
+        Quantity::new(-self.value)
     }
 }
 
-impl<T> Add<Self> for M3<T>
+impl<T, U, D1, D2> Mul<Quantity<U, D2>> for Quantity<T, D1>
 where
-    T: Add<Output = T>,
+    T: Mul<U>,
+    D1: DimMul<D2>,
 {
-    type Output = Self;
+    type Output = Quantity<<T as Mul<U>>::Output, <D1 as DimMul<D2>>::Output>;
 
-    fn add(self, rhs: Self) -> Self {
-        Self(self.0 + rhs.0)
+    fn mul(self, rhs: Quantity<U, D2>) -> Self::Output {
+        Quantity::new(self.value * rhs.value)
     }
 }
 
-impl<T> Sub for M3<T>
+impl<T, U, D1, D2> Div<Quantity<U, D2>> for Quantity<T, D1>
 where
-    T: Sub<Output = T>,
+    T: Div<U>,
+    D1: DimDiv<D2>,
 {
-    type Output = Self;
+    type Output = Quantity<<T as Div<U>>::Output, <D1 as DimDiv<D2>>::Output>;
 
-    fn sub(self, rhs: Self) -> Self {
-        Self(self.0 - rhs.0)
+    fn div(self, rhs: Quantity<U, D2>) -> Self::Output {
+        Quantity::new(self.value / rhs.value)
     }
 }
 
-impl<T> Sum for M3<T>
+macro_rules! impl_scalar_ops {
+    ( $($tp: ty),+ ) => {
+        $(
+            impl<D> Mul<$tp> for Quantity<$tp, D> {
+                type Output = Self;
+
+                fn mul(self, rhs: $tp) -> Self::Output {
+                    Quantity::new(self.value * rhs)
+                }
+            }
+
+            impl<D> Mul<Quantity<$tp, D>> for $tp {
+                type Output = Quantity<$tp, D>;
+
+                fn mul(self, rhs: Quantity<$tp, D>) -> Self::Output {
+                    Quantity::new(self * rhs.value)
+                }
+            }
+
+            impl<D> Div<$tp> for Quantity<$tp, D> {
+                type Output = Self;
+
+                fn div(self, rhs: $tp) -> Self::Output {
+                    Quantity::new(self.value / rhs)
+                }
+            }
+
+            impl<D> Div<Quantity<$tp, D>> for $tp
+            where
+                Dimensionless: DimDiv<D>,
+            {
+                type Output = Quantity<$tp, <Dimensionless as DimDiv<D>>::Output>;
+
+                fn div(self, rhs: Quantity<$tp, D>) -> Self::Output {
+                    Quantity::new(self / rhs.value)
+                }
+            }
+        )+
+    };
+}
+
+impl_scalar_ops! { f32, f64, u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize }
+
+impl<T, D> Sum for Quantity<T, D>
 where
     T: Sum,
 {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-        Self(iter.map(|x| x.0).sum())
+        Quantity::new(iter.map(|x| x.value).sum())
     }
 }
 
-impl<T> Mul<T> for M3<T>
-where
-    T: Mul<T, Output = T>,
-{
-    type Output = Self;
+impl<T: Zero, D> Zero for Quantity<T, D> {
+    fn zero() -> Self {
+        Quantity::new(T::zero())
+    }
+}
+
+impl<T: One> One for Quantity<T, Dimensionless> {
+    fn one() -> Self {
+        Quantity::new(T::one())
+    }
+}
+
+impl<T> From<T> for Quantity<T, Dimensionless> {
+    fn from(value: T) -> Self {
+        Quantity::new(value)
+    }
+}
+
+impl<T: One> One for M3<T> {
+    fn one() -> Self {
+        Quantity::new(T::one())
+    }
+}
+
+impl Div<usize> for M3<u32> {
+    type Output = M3<u32>;
 
-    fn mul(self, rhs: T) -> Self {
-        Self(self.0 * rhs)
+    fn div(self, rhs: usize) -> Self::Output {
+        Quantity::new(self.value / rhs as u32)
     }
 }
 
@@ -103,7 +226,7 @@ impl Mul<f32> for M3<u32> {
     type Output = Self;
 
     fn mul(self, rhs: f32) -> Self::Output {
-        Self((self.0 as f32 * rhs) as u32)
+        Quantity::new((self.value as f32 * rhs) as u32)
     }
 }
 
@@ -111,54 +234,153 @@ impl Mul<f64> for M3<u64> {
     type Output = Self;
 
     fn mul(self, rhs: f64) -> Self::Output {
-        Self((self.0 as f64 * rhs) as u64)
+        Quantity::new((self.value as f64 * rhs) as u64)
     }
 }
 
-impl<T: One> One for M3<T> {
-    fn one() -> Self {
-        M3(T::one())
+impl<T> From<T> for KgPerM3<T> {
+    fn from(value: T) -> Self {
+        Quantity::new(value)
     }
 }
 
-impl<T: Display> Display for M3<T> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{} m³", self.0)
-    }
-}
+/// Writes unit as product of SI base units with positive exponents over the ones with negative exponents, e.g. `kg·m/s²`
+pub(crate) fn fmt_base_units(exponents: [i8; 7], f: &mut Formatter<'_>) -> core::fmt::Result {
+    const SYMBOLS: [&str; 7] = ["m", "kg", "s", "A", "K", "mol", "cd"];
+    // kg goes first as in conventional notation (kg·m/s² rather than m·kg/s²)
+    const ORDER: [usize; 7] = [1, 0, 2, 3, 4, 5, 6];
 
-/// kg/m³
-#[repr(transparent)]
-#[derive(Debug, PartialEq, Eq, Hash, Clone)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct KgPerM3<T>(pub Rational<Kg<T>, M3<T>>);
+    let write_group = |f: &mut Formatter<'_>, positive: bool| -> core::fmt::Result {
+        let mut first = true;
+        for i in ORDER {
+            let e = exponents[i];
+            if e == 0 || (e > 0) != positive {
+                continue;
+            }
+            if !first {
+                f.write_str("·")?;
+            }
+            first = false;
+            f.write_str(SYMBOLS[i])?;
+            fmt_superscript(e.unsigned_abs(), f)?;
+        }
+        Ok(())
+    };
 
-impl<T> From<T> for KgPerM3<T>
-where
-    M3<T>: One,
-{
-    fn from(value: T) -> Self {
-        KgPerM3(Rational::<Kg<T>, M3<T>>::from(Kg(value)))
+    let has_positive = exponents.iter().any(|e| *e > 0);
+    let has_negative = exponents.iter().any(|e| *e < 0);
+    if has_positive {
+        write_group(f, true)?;
+    } else if has_negative {
+        f.write_str("1")?;
+    }
+    if has_negative {
+        f.write_str("/")?;
+        write_group(f, false)?;
     }
+    Ok(())
 }
 
-impl<T> Div<M3<T>> for Kg<T> {
-    type Output = KgPerM3<T>;
+pub(crate) fn fmt_superscript(e: u8, f: &mut Formatter<'_>) -> core::fmt::Result {
+    const DIGITS: [&str; 10] = ["⁰", "¹", "²", "³", "⁴", "⁵", "⁶", "⁷", "⁸", "⁹"];
+    if e == 1 {
+        return Ok(());
+    }
+    if e >= 10 {
+        fmt_superscript(e / 10, f)?;
+    }
+    f.write_str(DIGITS[(e % 10) as usize])
+}
 
-    fn div(self, rhs: M3<T>) -> Self::Output {
-        KgPerM3(Rational::new(self, rhs))
+impl<T: Display, D: Dimension> Display for Quantity<T, D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.value)?;
+        if D::EXPONENTS.iter().any(|e| *e != 0) {
+            f.write_str(" ")?;
+            fmt_base_units(D::EXPONENTS, f)?;
+        }
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::{Joules, Kg, M3, Meters, Newtons, Pascals, Quantity, Seconds};
+    use crate::physics::{Dimensionless, M2};
+
     #[test]
     #[cfg(feature = "serde")]
     fn kg_per_m3_serde() {
-        use crate::physics::{Kg, KgPerM3, M3};
-        let value = Kg(2) / M3(4);
+        use crate::math::Rational;
+        use crate::physics::KgPerM3;
+
+        let value = Kg::new(Rational::from(2u32)) / M3::new(Rational::from(4u32));
         let json = serde_json::to_string(&value).unwrap();
-        let res: KgPerM3<u32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(json, r#"{"numerator":2,"denominator":4}"#);
+        let res: KgPerM3<Rational<u32, u32>> = serde_json::from_str(&json).unwrap();
         assert_eq!(value, res)
     }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn kg_per_m3_serde_float() {
+        use crate::physics::KgPerM3;
+
+        let value = Kg::new(2.) / M3::new(4.);
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, "0.5");
+        let res: KgPerM3<f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(value, res)
+    }
+
+    #[test]
+    fn exact_integer_density() {
+        use crate::math::Rational;
+        use crate::physics::KgPerM3;
+
+        let density = Kg::new(Rational::from(9u32)) / M3::new(Rational::new(4, 2));
+        assert_eq!(density, KgPerM3::new(Rational::new(18, 4)));
+        assert_eq!(Kg::new(9u32) / M3::new(4u32), KgPerM3::new(2));
+    }
+
+    #[test]
+    fn legacy_volume_ops() {
+        use crate::math::One;
+
+        assert_eq!(M3::new(9u32) / 2usize, M3::new(4));
+        assert_eq!(M3::new(10u32) * 1.5f32, M3::new(15));
+        assert_eq!(M3::new(10u64) * 0.25f64, M3::new(2));
+        assert_eq!(M3::<u8>::one(), M3::new(1));
+    }
+
+    #[test]
+    fn dimensions() {
+        let mass = Kg::new(2.);
+        let acceleration = Meters::new(9.) / (Seconds::new(1.) * Seconds::new(2.));
+        let force: Newtons<f64> = mass * acceleration;
+        assert_eq!(*force.value(), 9.);
+
+        let work: Joules<f64> = force * Meters::new(2.);
+        assert_eq!(*work.value(), 18.);
+
+        let pressure: Pascals<f64> = force / M2::new(3.);
+        assert_eq!(*pressure.value(), 3.);
+
+        let ratio: Quantity<f64, Dimensionless> = M3::new(3.) / M3::new(2.);
+        assert_eq!(ratio.into_inner(), 1.5);
+
+        assert_eq!(M3::new(1.) + M3::new(2.) * 2., M3::new(5.));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn display() {
+        use std::string::ToString;
+
+        assert_eq!(M3::new(2).to_string(), "2 m³");
+        assert_eq!((Kg::new(2.) / M3::new(4.)).to_string(), "0.5 kg/m³");
+        assert_eq!(Newtons::new(1).to_string(), "1 kg·m/s²");
+        assert_eq!((1. / Seconds::new(2_f64)).to_string(), "0.5 1/s");
+        assert_eq!(Quantity::<_, Dimensionless>::new(3).to_string(), "3");
+    }
 }
@@ -1,3 +1,13 @@
+mod collision;
+mod dimension;
+mod fixed_stepper;
 mod measurement_units;
+mod rigid_body;
+mod unit;
 
+pub use collision::*;
+pub use dimension::*;
+pub use fixed_stepper::*;
 pub use measurement_units::*;
+pub use rigid_body::*;
+pub use unit::*;
@@ -0,0 +1,453 @@
+use super::{KgPerM3, M2, Meters};
+use crate::math::{
+    Angle, DeltaAngle, IsPositive, One, Pi, Point, Positive, Rect, Sq, Two, Vector, Zero,
+};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Mass, moment of inertia around centroid and centroid of a flat shape
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MassProperties<T> {
+    mass: Positive<T>,
+    inertia: Positive<T>,
+    centroid: Point<T>,
+}
+
+impl<T> MassProperties<T> {
+    pub fn new(mass: Positive<T>, inertia: Positive<T>, centroid: Point<T>) -> Self {
+        Self {
+            mass,
+            inertia,
+            centroid,
+        }
+    }
+
+    pub fn mass(&self) -> &Positive<T> {
+        &self.mass
+    }
+
+    pub fn inertia(&self) -> &Positive<T> {
+        &self.inertia
+    }
+
+    pub fn centroid(&self) -> &Point<T> {
+        &self.centroid
+    }
+
+    /// Mass of a plate with `area` and `depth` made of material with `density`
+    fn plate_mass(density: KgPerM3<T>, area: T, depth: Meters<T>) -> T
+    where
+        T: Mul<Output = T>,
+    {
+        (density * M2::new(area) * depth).into_inner()
+    }
+
+    /**
+     * @brief rect - solid rectangular plate
+     *  m = ρ·w·h·d, I = m(w² + h²)/12
+     * @return None if mass is not positive
+     */
+    pub fn rect(rect: Rect<T>, density: KgPerM3<T>, depth: Meters<T>) -> Option<Self>
+    where
+        T: Clone
+            + One
+            + Two
+            + IsPositive
+            + Add<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>
+            + Sq<Output = T>,
+    {
+        let (w, h) = (rect.w().clone(), rect.h().clone());
+        let mass = Self::plate_mass(density, w.clone() * h.clone(), depth);
+        let twelve = (T::two() + T::one()) * T::two() * T::two();
+        let inertia = mass.clone() * (w.sq() + h.sq()) / twelve;
+        Some(Self {
+            mass: Positive::new(mass).ok()?,
+            inertia: Positive::new(inertia).ok()?,
+            centroid: rect.center(),
+        })
+    }
+
+    /**
+     * @brief circle - solid disk
+     *  m = ρ·πr²·d, I = mr²/2
+     * @return None if mass is not positive
+     */
+    pub fn circle(
+        center: Point<T>,
+        radius: Positive<T>,
+        density: KgPerM3<T>,
+        depth: Meters<T>,
+    ) -> Option<Self>
+    where
+        T: Clone + Pi + Two + IsPositive + Mul<Output = T> + Div<Output = T> + Sq<Output = T>,
+    {
+        let r2 = radius.into_inner().sq();
+        let mass = Self::plate_mass(density, T::pi() * r2.clone(), depth);
+        let inertia = mass.clone() * r2 / T::two();
+        Some(Self {
+            mass: Positive::new(mass).ok()?,
+            inertia: Positive::new(inertia).ok()?,
+            centroid: center,
+        })
+    }
+
+    /**
+     * @brief polygon - solid simple polygon with vertices in any winding order
+     *  A = Σ(pᵢ × pᵢ₊₁)/2
+     *  c = Σ(pᵢ + pᵢ₊₁)(pᵢ × pᵢ₊₁)/(6A)
+     *  I₀ = ρd·Σ(pᵢ × pᵢ₊₁)(pᵢ·pᵢ + pᵢ·pᵢ₊₁ + pᵢ₊₁·pᵢ₊₁)/12, I = I₀ - m|c|²
+     * @return None if polygon is degenerate or mass is not positive
+     */
+    pub fn polygon(vertices: &[Point<T>], density: KgPerM3<T>, depth: Meters<T>) -> Option<Self>
+    where
+        T: Clone
+            + Zero
+            + One
+            + Two
+            + IsPositive
+            + PartialOrd
+            + Neg<Output = T>
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>,
+    {
+        let origin = vertices.first()?.clone();
+        let mut double_area = T::zero();
+        let mut cx = T::zero();
+        let mut cy = T::zero();
+        let mut inertia = T::zero();
+        // computed relatively to first vertex to reduce cancellation
+        for (i, p0) in vertices.iter().enumerate() {
+            let p1 = &vertices[(i + 1) % vertices.len()];
+            let a = p0.clone() - origin.clone();
+            let b = p1.clone() - origin.clone();
+            let cross = a.clone().cross(b.clone());
+            double_area = double_area + cross.clone();
+            cx = cx + (a.x().clone() + b.x().clone()) * cross.clone();
+            cy = cy + (a.y().clone() + b.y().clone()) * cross.clone();
+            inertia =
+                inertia + cross * (a.clone().dot(a.clone()) + a.dot(b.clone()) + b.clone().dot(b));
+        }
+
+        let (double_area, cx, cy, inertia) = if double_area < T::zero() {
+            (-double_area, -cx, -cy, -inertia)
+        } else {
+            (double_area, cx, cy, inertia)
+        };
+        if !double_area.is_positive() {
+            return None;
+        }
+
+        let three = T::two() + T::one();
+        let six = three.clone() * T::two();
+        let area = double_area.clone() / T::two();
+        let c = Vector::from((
+            cx / (three.clone() * double_area.clone()),
+            cy / (three * double_area.clone()),
+        ));
+        let density_depth = (density * depth).into_inner();
+        let mass = density_depth.clone() * area;
+        let inertia =
+            density_depth * inertia / (six * T::two()) - mass.clone() * c.clone().dot(c.clone());
+        Some(Self {
+            mass: Positive::new(mass).ok()?,
+            inertia: Positive::new(inertia).ok()?,
+            centroid: origin + c,
+        })
+    }
+}
+
+/// 2D rigid body. Position is its center of mass.
+/// Forces and torques are accumulated until next `step`, impulses change velocities immediately.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RigidBody<T> {
+    position: Point<T>,
+    orientation: Angle<T>,
+    velocity: Vector<T>,
+    angular_velocity: DeltaAngle<T>,
+    mass: Positive<T>,
+    inertia: Positive<T>,
+    force: Vector<T>,
+    torque: T,
+}
+
+impl<T> RigidBody<T> {
+    pub fn new(position: Point<T>, mass: Positive<T>, inertia: Positive<T>) -> Self
+    where
+        T: Zero,
+    {
+        Self {
+            position,
+            orientation: Angle::from_radians(T::zero()),
+            velocity: (T::zero(), T::zero()).into(),
+            angular_velocity: DeltaAngle::from_radians(T::zero()),
+            mass,
+            inertia,
+            force: (T::zero(), T::zero()).into(),
+            torque: T::zero(),
+        }
+    }
+
+    /// Body placed at centroid of shape
+    pub fn from_mass_properties(properties: MassProperties<T>) -> Self
+    where
+        T: Zero,
+    {
+        Self::new(properties.centroid, properties.mass, properties.inertia)
+    }
+
+    pub fn position(&self) -> &Point<T> {
+        &self.position
+    }
+
+    pub fn set_position(&mut self, position: Point<T>) {
+        self.position = position
+    }
+
+    pub fn orientation(&self) -> &Angle<T> {
+        &self.orientation
+    }
+
+    pub fn set_orientation(&mut self, orientation: Angle<T>) {
+        self.orientation = orientation
+    }
+
+    pub fn velocity(&self) -> &Vector<T> {
+        &self.velocity
+    }
+
+    pub fn set_velocity(&mut self, velocity: Vector<T>) {
+        self.velocity = velocity
+    }
+
+    pub fn angular_velocity(&self) -> &DeltaAngle<T> {
+        &self.angular_velocity
+    }
+
+    pub fn set_angular_velocity(&mut self, angular_velocity: DeltaAngle<T>) {
+        self.angular_velocity = angular_velocity
+    }
+
+    pub fn mass(&self) -> &Positive<T> {
+        &self.mass
+    }
+
+    pub fn set_mass(&mut self, mass: Positive<T>) {
+        self.mass = mass
+    }
+
+    /// Moment of inertia around center of mass
+    pub fn inertia(&self) -> &Positive<T> {
+        &self.inertia
+    }
+
+    pub fn set_inertia(&mut self, inertia: Positive<T>) {
+        self.inertia = inertia
+    }
+
+    /// Force accumulated since last `step`
+    pub fn force(&self) -> &Vector<T> {
+        &self.force
+    }
+
+    /// Torque accumulated since last `step`
+    pub fn torque(&self) -> &T {
+        &self.torque
+    }
+
+    pub fn inverse_mass(&self) -> T
+    where
+        T: Clone + One + Div<Output = T>,
+    {
+        T::one() / self.mass.clone().into_inner()
+    }
+
+    pub fn inverse_inertia(&self) -> T
+    where
+        T: Clone + One + Div<Output = T>,
+    {
+        T::one() / self.inertia.clone().into_inner()
+    }
+
+    /// Force applied at center of mass
+    pub fn apply_force(&mut self, force: Vector<T>)
+    where
+        T: Clone + Add<Output = T>,
+    {
+        self.force = self.force.clone() + force
+    }
+
+    /// Force applied at `point` in world coordinates producing torque `r × F`
+    pub fn apply_force_at(&mut self, force: Vector<T>, point: Point<T>)
+    where
+        T: Clone + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+    {
+        let r = point - self.position.clone();
+        self.torque = self.torque.clone() + r.cross(force.clone());
+        self.apply_force(force)
+    }
+
+    pub fn apply_torque(&mut self, torque: T)
+    where
+        T: Clone + Add<Output = T>,
+    {
+        self.torque = self.torque.clone() + torque
+    }
+
+    /// Impulse applied at center of mass: `Δv = J/m`
+    pub fn apply_impulse(&mut self, impulse: Vector<T>)
+    where
+        T: Clone + One + Add<Output = T> + Mul<Output = T> + Div<Output = T>,
+    {
+        self.velocity = self.velocity.clone() + impulse * self.inverse_mass()
+    }
+
+    /// Impulse applied at `point` in world coordinates: `Δv = J/m`, `Δω = (r × J)/I`
+    pub fn apply_impulse_at(&mut self, impulse: Vector<T>, point: Point<T>)
+    where
+        T: Clone + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+    {
+        let r = point - self.position.clone();
+        self.apply_angular_impulse(r.cross(impulse.clone()));
+        self.apply_impulse(impulse)
+    }
+
+    pub fn apply_angular_impulse(&mut self, impulse: T)
+    where
+        T: Clone + One + Add<Output = T> + Mul<Output = T> + Div<Output = T>,
+    {
+        self.angular_velocity = self.angular_velocity.clone()
+            + DeltaAngle::from_radians(impulse * self.inverse_inertia())
+    }
+
+    /// Velocity of body material at `point` in world coordinates: `v + ω × r`
+    pub fn velocity_at(&self, point: Point<T>) -> Vector<T>
+    where
+        T: Clone + Neg<Output = T> + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+    {
+        let (rx, ry) = (point - self.position.clone()).into();
+        let w = self.angular_velocity.clone().into_inner();
+        self.velocity.clone() + Vector::from((-ry * w.clone(), rx * w))
+    }
+
+    /// `p = mv`
+    pub fn momentum(&self) -> Vector<T>
+    where
+        T: Clone + Mul<Output = T>,
+    {
+        self.velocity.clone() * self.mass.clone().into_inner()
+    }
+
+    /// `L = Iω` around center of mass
+    pub fn angular_momentum(&self) -> T
+    where
+        T: Clone + Mul<Output = T>,
+    {
+        self.inertia.clone().into_inner() * self.angular_velocity.clone().into_inner()
+    }
+
+    /// `(mv² + Iω²)/2`
+    pub fn kinetic_energy(&self) -> T
+    where
+        T: Clone + Two + Add<Output = T> + Mul<Output = T> + Div<Output = T>,
+    {
+        let w = self.angular_velocity.clone().into_inner();
+        (self.mass.clone().into_inner() * self.velocity.clone().dot(self.velocity.clone())
+            + self.inertia.clone().into_inner() * w.clone() * w)
+            / T::two()
+    }
+
+    pub fn clear_forces(&mut self)
+    where
+        T: Zero,
+    {
+        self.force = (T::zero(), T::zero()).into();
+        self.torque = T::zero();
+    }
+
+    /// Advance body by `dt` with semi-implicit Euler method and clear accumulated forces
+    pub fn step(&mut self, dt: T)
+    where
+        T: Clone + Zero + One + Add<Output = T> + Mul<Output = T> + Div<Output = T>,
+    {
+        let acceleration = self.force.clone() * self.inverse_mass();
+        let angular_acceleration = self.torque.clone() * self.inverse_inertia();
+        self.velocity = self.velocity.clone() + acceleration * dt.clone();
+        self.angular_velocity = self.angular_velocity.clone()
+            + DeltaAngle::from_radians(angular_acceleration * dt.clone());
+        self.position = self.position.clone() + self.velocity.clone() * dt.clone();
+        self.orientation = self.orientation.clone() + self.angular_velocity.clone() * dt;
+        self.clear_forces();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MassProperties, RigidBody};
+    use crate::math::{DeltaAngle, Point, Positive, Rect, Vector};
+    use crate::physics::{KgPerM3, Meters};
+    use approx::assert_abs_diff_eq;
+
+    fn positive(x: f64) -> Positive<f64> {
+        Positive::new(x).unwrap()
+    }
+
+    #[test]
+    fn shapes() {
+        let density = KgPerM3::new(2.);
+        let depth = Meters::new(0.5);
+
+        let rect = MassProperties::rect(Rect::from((0., 0., 2., 3.)), density, depth).unwrap();
+        assert_eq!(rect.mass().into_inner(), 6.);
+        assert_eq!(rect.inertia().into_inner(), 6.5);
+        assert_eq!(*rect.centroid(), Point::from((1., 1.5)));
+
+        // same rectangle as polygon in clockwise order
+        let polygon = MassProperties::polygon(
+            &[
+                (0., 0.).into(),
+                (0., 3.).into(),
+                (2., 3.).into(),
+                (2., 0.).into(),
+            ],
+            density,
+            depth,
+        )
+        .unwrap();
+        assert_abs_diff_eq!(polygon.mass().into_inner(), 6.);
+        assert_abs_diff_eq!(polygon.inertia().into_inner(), 6.5, epsilon = 1e-12);
+        assert_abs_diff_eq!(*polygon.centroid().x(), 1.);
+        assert_abs_diff_eq!(*polygon.centroid().y(), 1.5);
+
+        let circle = MassProperties::circle((1., 1.).into(), positive(2.), density, depth).unwrap();
+        assert_abs_diff_eq!(circle.mass().into_inner(), 4. * core::f64::consts::PI);
+        assert_abs_diff_eq!(circle.inertia().into_inner(), 8. * core::f64::consts::PI);
+
+        assert!(
+            MassProperties::polygon(&[(0., 0.).into(), (1., 1.).into()], density, depth).is_none()
+        );
+    }
+
+    #[test]
+    fn forces_and_impulses() {
+        let mut body = RigidBody::new((0., 0.).into(), positive(2.), positive(4.));
+
+        body.apply_force_at(Vector::from((0., 4.)), (1., 0.).into());
+        assert_eq!(*body.torque(), 4.);
+        body.step(0.5);
+        assert_eq!(*body.velocity(), Vector::from((0., 1.)));
+        assert_eq!(body.angular_velocity().into_inner(), 0.5);
+        assert_eq!(*body.position(), Point::from((0., 0.5)));
+        assert_eq!(*body.torque(), 0.);
+
+        body.set_angular_velocity(DeltaAngle::from_radians(0.));
+        body.apply_impulse_at(Vector::from((2., 0.)), (0., 1.5).into());
+        assert_eq!(*body.velocity(), Vector::from((1., 1.)));
+        assert_eq!(body.angular_velocity().into_inner(), -0.5);
+        assert_eq!(body.velocity_at((0., 2.5).into()), Vector::from((2., 1.)));
+        assert_eq!(body.momentum(), Vector::from((2., 2.)));
+        assert_eq!(body.kinetic_energy(), 2.5);
+    }
+}
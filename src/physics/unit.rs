@@ -0,0 +1,606 @@
+use super::dimension::*;
+use super::measurement_units::{Quantity, fmt_base_units, fmt_superscript};
+use crate::math::One;
+use core::error::Error;
+use core::fmt::{Debug, Display, Formatter};
+use core::marker::PhantomData;
+use core::ops::{Add, Div, Mul, Sub};
+use core::str::FromStr;
+
+/// Scalar which can be converted between units.
+/// Factors are applied as exact integer ratios so that no precision is lost on an intermediate constant like `0.001`.
+pub trait UnitScalar:
+    Clone + One + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self>
+{
+    fn from_i64(value: i64) -> Self;
+}
+
+macro_rules! impl_unit_scalar {
+    ( $($tp: ty),+ ) => {
+        $(
+            impl UnitScalar for $tp {
+                fn from_i64(value: i64) -> Self { value as $tp }
+            }
+        )+
+    };
+}
+
+impl_unit_scalar! { f32, f64 }
+
+/// SI prefix
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Prefix {
+    Pico,
+    Nano,
+    Micro,
+    Milli,
+    Centi,
+    Deci,
+    Deca,
+    Hecto,
+    Kilo,
+    Mega,
+    Giga,
+    Tera,
+}
+
+impl Prefix {
+    const ALL: [Prefix; 12] = [
+        Prefix::Pico,
+        Prefix::Nano,
+        Prefix::Micro,
+        Prefix::Milli,
+        Prefix::Centi,
+        Prefix::Deci,
+        Prefix::Deca,
+        Prefix::Hecto,
+        Prefix::Kilo,
+        Prefix::Mega,
+        Prefix::Giga,
+        Prefix::Tera,
+    ];
+
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Prefix::Pico => "p",
+            Prefix::Nano => "n",
+            Prefix::Micro => "µ",
+            Prefix::Milli => "m",
+            Prefix::Centi => "c",
+            Prefix::Deci => "d",
+            Prefix::Deca => "da",
+            Prefix::Hecto => "h",
+            Prefix::Kilo => "k",
+            Prefix::Mega => "M",
+            Prefix::Giga => "G",
+            Prefix::Tera => "T",
+        }
+    }
+
+    /// Power of ten
+    pub const fn exponent(self) -> i8 {
+        match self {
+            Prefix::Pico => -12,
+            Prefix::Nano => -9,
+            Prefix::Micro => -6,
+            Prefix::Milli => -3,
+            Prefix::Centi => -2,
+            Prefix::Deci => -1,
+            Prefix::Deca => 1,
+            Prefix::Hecto => 2,
+            Prefix::Kilo => 3,
+            Prefix::Mega => 6,
+            Prefix::Giga => 9,
+            Prefix::Tera => 12,
+        }
+    }
+
+    /// Split prefix from the beginning of `s`. `u` is accepted as ascii spelling of `µ`
+    fn strip(s: &str) -> Option<(Prefix, &str)> {
+        if let Some(rest) = s.strip_prefix('u') {
+            return Some((Prefix::Micro, rest));
+        }
+        // "da" must be checked before "d"
+        Self::ALL
+            .iter()
+            .filter(|p| **p != Prefix::Deci)
+            .chain(core::iter::once(&Prefix::Deci))
+            .find_map(|p| s.strip_prefix(p.symbol()).map(|rest| (*p, rest)))
+    }
+}
+
+/// Exact scale factor `numerator / denominator * 10^exponent`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Scale {
+    numerator: i64,
+    denominator: i64,
+    exponent: i8,
+}
+
+impl Scale {
+    pub const ONE: Scale = Scale::ratio(1, 1);
+    pub const ZERO: Scale = Scale::ratio(0, 1);
+
+    pub const fn ratio(numerator: i64, denominator: i64) -> Self {
+        Self {
+            numerator,
+            denominator,
+            exponent: 0,
+        }
+    }
+
+    pub const fn pow10(exponent: i8) -> Self {
+        Self {
+            numerator: 1,
+            denominator: 1,
+            exponent,
+        }
+    }
+
+    const fn with_exponent(self, exponent: i8) -> Self {
+        Self {
+            numerator: self.numerator,
+            denominator: self.denominator,
+            exponent: self.exponent + exponent,
+        }
+    }
+
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Some(Self {
+            numerator: self.numerator.checked_mul(rhs.numerator)?,
+            denominator: self.denominator.checked_mul(rhs.denominator)?,
+            exponent: self.exponent.checked_add(rhs.exponent)?,
+        })
+    }
+
+    fn checked_powi(self, n: i8) -> Option<Self> {
+        let base = if n < 0 {
+            Self {
+                numerator: self.denominator,
+                denominator: self.numerator,
+                exponent: self.exponent.checked_neg()?,
+            }
+        } else {
+            self
+        };
+        (0..n.unsigned_abs()).try_fold(Self::ONE, |acc, _| acc.checked_mul(base))
+    }
+
+    fn ten_pow<T: UnitScalar>(n: u8) -> T {
+        (0..n).fold(T::one(), |acc, _| acc * T::from_i64(10))
+    }
+
+    /// `value * self`
+    pub fn apply<T: UnitScalar>(&self, value: T) -> T {
+        let value = value * T::from_i64(self.numerator) / T::from_i64(self.denominator);
+        if self.exponent < 0 {
+            value / Self::ten_pow(self.exponent.unsigned_abs())
+        } else {
+            value * Self::ten_pow(self.exponent.unsigned_abs())
+        }
+    }
+
+    /// `value / self`
+    pub fn unapply<T: UnitScalar>(&self, value: T) -> T {
+        let value = value * T::from_i64(self.denominator) / T::from_i64(self.numerator);
+        if self.exponent < 0 {
+            value * Self::ten_pow(self.exponent.unsigned_abs())
+        } else {
+            value / Self::ten_pow(self.exponent.unsigned_abs())
+        }
+    }
+}
+
+/// Unit of measurement of dimension `D`.
+/// Value `v` expressed in this unit equals `v * factor + offset` in SI base units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Unit<D> {
+    prefix: Option<Prefix>,
+    symbol: &'static str,
+    power: u8,
+    factor: Scale,
+    offset: Scale,
+    dimension: PhantomData<D>,
+}
+
+impl<D> Unit<D> {
+    /**
+     * @brief scaled - create unit from its symbol and factor relative to SI base units
+     * @param symbol - symbol without prefix and power
+     * @param power - power of symbol (3 for m³). Prefix is raised to the same power so that cm³ = (0.01 m)³
+     * @param factor - value of `1 symbol^power` in SI base units
+     */
+    pub const fn scaled(symbol: &'static str, power: u8, factor: Scale) -> Self {
+        Self {
+            prefix: None,
+            symbol,
+            power,
+            factor,
+            offset: Scale::ZERO,
+            dimension: PhantomData,
+        }
+    }
+
+    /// Coherent SI unit (factor 1)
+    pub const fn base(symbol: &'static str, power: u8) -> Self {
+        Self::scaled(symbol, power, Scale::ONE)
+    }
+
+    /// Affine unit like degree Celsius where zero does not match zero of the base unit
+    pub const fn with_offset(self, offset: Scale) -> Self {
+        Self { offset, ..self }
+    }
+
+    /// Replace prefix of unit, e.g. `GRAM.prefixed(Prefix::Kilo)`
+    pub const fn prefixed(self, prefix: Prefix) -> Self {
+        let old = match self.prefix {
+            Some(p) => p.exponent(),
+            None => 0,
+        };
+        let exponent = (prefix.exponent() - old) * self.power as i8;
+        Self {
+            prefix: Some(prefix),
+            factor: self.factor.with_exponent(exponent),
+            ..self
+        }
+    }
+
+    pub fn prefix(&self) -> Option<Prefix> {
+        self.prefix
+    }
+
+    pub fn factor(&self) -> Scale {
+        self.factor
+    }
+
+    pub fn offset(&self) -> Scale {
+        self.offset
+    }
+
+    /// Quantity of `value` expressed in this unit
+    pub fn quantity<T: UnitScalar>(&self, value: T) -> Quantity<T, D> {
+        Quantity::new(self.factor.apply(value) + self.offset.apply(T::one()))
+    }
+
+    /// Value of `quantity` expressed in this unit
+    pub fn value_of<T: UnitScalar>(&self, quantity: Quantity<T, D>) -> T {
+        self.factor
+            .unapply(quantity.into_inner() - self.offset.apply(T::one()))
+    }
+}
+
+impl<D> Display for Unit<D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if let Some(prefix) = self.prefix {
+            f.write_str(prefix.symbol())?;
+        }
+        f.write_str(self.symbol)?;
+        fmt_superscript(self.power, f)
+    }
+}
+
+pub const METRE: Unit<Length> = Unit::base("m", 1);
+pub const KILOMETRE: Unit<Length> = METRE.prefixed(Prefix::Kilo);
+pub const CENTIMETRE: Unit<Length> = METRE.prefixed(Prefix::Centi);
+pub const MILLIMETRE: Unit<Length> = METRE.prefixed(Prefix::Milli);
+
+pub const SQUARE_METRE: Unit<Area> = Unit::base("m", 2);
+
+pub const CUBIC_METRE: Unit<Volume> = Unit::base("m", 3);
+pub const CUBIC_CENTIMETRE: Unit<Volume> = CUBIC_METRE.prefixed(Prefix::Centi);
+pub const LITRE: Unit<Volume> = Unit::scaled("L", 1, Scale::pow10(-3));
+pub const MILLILITRE: Unit<Volume> = LITRE.prefixed(Prefix::Milli);
+
+pub const GRAM: Unit<Mass> = Unit::scaled("g", 1, Scale::pow10(-3));
+pub const KILOGRAM: Unit<Mass> = GRAM.prefixed(Prefix::Kilo);
+pub const TONNE: Unit<Mass> = Unit::scaled("t", 1, Scale::pow10(3));
+
+pub const SECOND: Unit<Time> = Unit::base("s", 1);
+pub const MILLISECOND: Unit<Time> = SECOND.prefixed(Prefix::Milli);
+pub const MINUTE: Unit<Time> = Unit::scaled("min", 1, Scale::ratio(60, 1));
+pub const HOUR: Unit<Time> = Unit::scaled("h", 1, Scale::ratio(3600, 1));
+
+pub const KELVIN: Unit<Temperature> = Unit::base("K", 1);
+pub const DEGREE_CELSIUS: Unit<Temperature> =
+    Unit::base("°C", 1).with_offset(Scale::ratio(27315, 100));
+pub const DEGREE_FAHRENHEIT: Unit<Temperature> =
+    Unit::scaled("°F", 1, Scale::ratio(5, 9)).with_offset(Scale::ratio(45967, 180));
+
+pub const KILOGRAM_PER_CUBIC_METRE: Unit<Density> = Unit::base("kg/m³", 1);
+pub const GRAM_PER_CUBIC_CENTIMETRE: Unit<Density> = Unit::scaled("g/cm³", 1, Scale::pow10(3));
+
+pub const NEWTON: Unit<Force> = Unit::base("N", 1);
+pub const PASCAL: Unit<Pressure> = Unit::base("Pa", 1);
+pub const JOULE: Unit<Energy> = Unit::base("J", 1);
+pub const WATT: Unit<Power> = Unit::base("W", 1);
+
+impl<T: UnitScalar, D> Quantity<T, D> {
+    pub fn from_unit(value: T, unit: &Unit<D>) -> Self {
+        unit.quantity(value)
+    }
+
+    pub fn to_unit(self, unit: &Unit<D>) -> T {
+        unit.value_of(self)
+    }
+
+    /// Display value converted to `unit` followed by its symbol, e.g. `2.5 L`
+    pub fn display_in(self, unit: &Unit<D>) -> InUnit<'_, T, D> {
+        InUnit {
+            value: self.to_unit(unit),
+            unit,
+        }
+    }
+}
+
+pub struct InUnit<'a, T, D> {
+    value: T,
+    unit: &'a Unit<D>,
+}
+
+impl<T: Display, D> Display for InUnit<'_, T, D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} {}", self.value, self.unit)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseQuantityError<E> {
+    /// Numeric part can not be parsed
+    Value(E),
+    /// Unit symbol is not known or its power is malformed
+    UnknownUnit,
+    /// Parsed unit has dimension other than requested
+    DimensionMismatch { expected: [i8; 7], found: [i8; 7] },
+    /// Scale factor of unit does not fit into `i64`
+    Overflow,
+}
+
+impl<E: Display> Display for ParseQuantityError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseQuantityError::Value(e) => write!(f, "invalid value: {}", e),
+            ParseQuantityError::UnknownUnit => f.write_str("unknown unit"),
+            ParseQuantityError::DimensionMismatch { expected, found } => {
+                f.write_str("expected unit of dimension ")?;
+                fmt_base_units(*expected, f)?;
+                f.write_str(", found ")?;
+                fmt_base_units(*found, f)
+            }
+            ParseQuantityError::Overflow => f.write_str("unit scale overflow"),
+        }
+    }
+}
+
+impl<E> Error for ParseQuantityError<E> where E: Debug + Display {}
+
+/// Unit parsed from string at runtime
+struct ParsedUnit {
+    exponents: [i8; 7],
+    factor: Scale,
+    offset: Scale,
+}
+
+/// Symbols which may be combined in compound units and take prefixes. (symbol, exponents, factor)
+const ATOMIC_UNITS: [(&str, [i8; 7], Scale); 17] = [
+    ("m", [1, 0, 0, 0, 0, 0, 0], Scale::ONE),
+    ("g", [0, 1, 0, 0, 0, 0, 0], Scale::pow10(-3)),
+    ("s", [0, 0, 1, 0, 0, 0, 0], Scale::ONE),
+    ("A", [0, 0, 0, 1, 0, 0, 0], Scale::ONE),
+    ("K", [0, 0, 0, 0, 1, 0, 0], Scale::ONE),
+    ("mol", [0, 0, 0, 0, 0, 1, 0], Scale::ONE),
+    ("cd", [0, 0, 0, 0, 0, 0, 1], Scale::ONE),
+    ("L", [3, 0, 0, 0, 0, 0, 0], Scale::pow10(-3)),
+    ("t", [0, 1, 0, 0, 0, 0, 0], Scale::pow10(3)),
+    ("Hz", [0, 0, -1, 0, 0, 0, 0], Scale::ONE),
+    ("N", [1, 1, -2, 0, 0, 0, 0], Scale::ONE),
+    ("Pa", [-1, 1, -2, 0, 0, 0, 0], Scale::ONE),
+    ("J", [2, 1, -2, 0, 0, 0, 0], Scale::ONE),
+    ("W", [2, 1, -3, 0, 0, 0, 0], Scale::ONE),
+    ("C", [0, 0, 1, 1, 0, 0, 0], Scale::ONE),
+    ("V", [2, 1, -3, -1, 0, 0, 0], Scale::ONE),
+    ("l", [3, 0, 0, 0, 0, 0, 0], Scale::pow10(-3)),
+];
+
+/// Symbols which are used without prefixes
+const UNPREFIXED_UNITS: [(&str, [i8; 7], Scale); 2] = [
+    ("min", [0, 0, 1, 0, 0, 0, 0], Scale::ratio(60, 1)),
+    ("h", [0, 0, 1, 0, 0, 0, 0], Scale::ratio(3600, 1)),
+];
+
+/// Units with offset which can not be part of compound units
+const AFFINE_UNITS: [Unit<Temperature>; 2] = [DEGREE_CELSIUS, DEGREE_FAHRENHEIT];
+
+fn parse_power(s: &str) -> Option<(&str, i8)> {
+    const DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+    if let Some((symbol, power)) = s.split_once('^') {
+        return power.parse().ok().map(|p| (symbol, p));
+    }
+    let start = s
+        .char_indices()
+        .find(|(_, c)| *c == '⁻' || DIGITS.contains(c))
+        .map(|(i, _)| i);
+    let Some(start) = start else {
+        return Some((s, 1));
+    };
+    let (symbol, power) = s.split_at(start);
+    let (negative, digits) = match power.strip_prefix('⁻') {
+        Some(digits) => (true, digits),
+        None => (false, power),
+    };
+    let value = digits.chars().try_fold(0_i8, |acc, c| {
+        let d = DIGITS.iter().position(|x| *x == c)? as i8;
+        acc.checked_mul(10)?.checked_add(d)
+    })?;
+    if digits.is_empty() {
+        return None;
+    }
+    Some((symbol, if negative { -value } else { value }))
+}
+
+fn lookup_atomic(symbol: &str) -> Option<([i8; 7], Scale)> {
+    let find = |table: &[(&str, [i8; 7], Scale)], s: &str| {
+        table
+            .iter()
+            .find(|(x, _, _)| *x == s)
+            .map(|(_, e, f)| (*e, *f))
+    };
+    find(&ATOMIC_UNITS, symbol)
+        .or_else(|| find(&UNPREFIXED_UNITS, symbol))
+        .or_else(|| {
+            let (prefix, rest) = Prefix::strip(symbol)?;
+            let (exponents, factor) = find(&ATOMIC_UNITS, rest)?;
+            Some((exponents, factor.with_exponent(prefix.exponent())))
+        })
+}
+
+fn parse_unit<E>(s: &str) -> Result<ParsedUnit, ParseQuantityError<E>> {
+    if let Some(unit) = AFFINE_UNITS.iter().find(|u| u.symbol == s) {
+        return Ok(ParsedUnit {
+            exponents: Temperature::EXPONENTS,
+            factor: unit.factor,
+            offset: unit.offset,
+        });
+    }
+
+    let mut result = ParsedUnit {
+        exponents: [0; 7],
+        factor: Scale::ONE,
+        offset: Scale::ZERO,
+    };
+    let (numerator, denominator) = match s.split_once('/') {
+        Some((n, d)) => (n, Some(d)),
+        None => (s, None),
+    };
+    let groups = core::iter::once((numerator, 1_i8)).chain(denominator.map(|d| (d, -1)));
+    for (group, sign) in groups {
+        for term in group.split(['·', '*', '.', ' ']).filter(|t| !t.is_empty()) {
+            if term == "1" {
+                continue;
+            }
+            let (symbol, power) = parse_power(term).ok_or(ParseQuantityError::UnknownUnit)?;
+            let (exponents, factor) =
+                lookup_atomic(symbol).ok_or(ParseQuantityError::UnknownUnit)?;
+            let power = power
+                .checked_mul(sign)
+                .ok_or(ParseQuantityError::Overflow)?;
+            for (acc, e) in result.exponents.iter_mut().zip(exponents) {
+                *acc = e
+                    .checked_mul(power)
+                    .and_then(|e| acc.checked_add(e))
+                    .ok_or(ParseQuantityError::Overflow)?;
+            }
+            result.factor = factor
+                .checked_powi(power)
+                .and_then(|f| result.factor.checked_mul(f))
+                .ok_or(ParseQuantityError::Overflow)?;
+        }
+    }
+    Ok(result)
+}
+
+/// Parse quantity from string like `2.5 kg/m³`, `3 km/h` or `20 °C` converting it into SI base units.
+/// Powers are written either as superscripts or as `^n`, units are multiplied with `·` or `*`.
+impl<T, D> FromStr for Quantity<T, D>
+where
+    T: UnitScalar + FromStr,
+    D: Dimension,
+{
+    type Err = ParseQuantityError<<T as FromStr>::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (value, unit) = match s.split_once(char::is_whitespace) {
+            Some((value, unit)) => (value, unit.trim()),
+            None => (s, ""),
+        };
+        let value: T = value.parse().map_err(ParseQuantityError::Value)?;
+        let unit = parse_unit(unit)?;
+        if unit.exponents != D::EXPONENTS {
+            return Err(ParseQuantityError::DimensionMismatch {
+                expected: D::EXPONENTS,
+                found: unit.exponents,
+            });
+        }
+        Ok(Quantity::new(
+            unit.factor.apply(value) + unit.offset.apply(T::one()),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics::{
+        Hertz, Kelvins, Kg, KgPerM3, M3, Meters, MetersPerSecond, Newtons, Seconds,
+    };
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn conversion() {
+        assert_eq!(*M3::from_unit(2.5, &LITRE).value(), 0.0025);
+        assert_eq!(M3::new(0.0025).to_unit(&MILLILITRE), 2500.);
+        assert_eq!(*M3::from_unit(1., &CUBIC_CENTIMETRE).value(), 1e-6);
+        assert_eq!(*Kg::from_unit(3., &TONNE).value(), 3000.);
+        assert_eq!(Kg::new(1.5).to_unit(&GRAM), 1500.);
+        assert_eq!(KILOGRAM.factor(), Scale::ONE);
+        assert_eq!(*Seconds::from_unit(2., &HOUR).value(), 7200.);
+        assert_abs_diff_eq!(
+            *Kelvins::from_unit(20., &DEGREE_CELSIUS).value(),
+            293.15,
+            epsilon = 1e-9
+        );
+        assert_abs_diff_eq!(
+            Kelvins::new(273.15).to_unit(&DEGREE_FAHRENHEIT),
+            32.,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn display() {
+        use std::string::ToString;
+
+        assert_eq!(CUBIC_CENTIMETRE.to_string(), "cm³");
+        assert_eq!(KILOGRAM.to_string(), "kg");
+        assert_eq!(M3::new(0.0025).display_in(&LITRE).to_string(), "2.5 L");
+        assert_eq!(Kelvins::new(300.).display_in(&KELVIN).to_string(), "300 K");
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!("2.5 kg/m³".parse::<KgPerM3<f64>>(), Ok(KgPerM3::new(2.5)));
+        assert_eq!(
+            "2.5 g/cm^3".parse::<KgPerM3<f64>>(),
+            Ok(KgPerM3::new(2500.))
+        );
+        assert_eq!("3 L".parse::<M3<f64>>(), Ok(M3::new(0.003)));
+        assert_eq!(
+            "36 km/h".parse::<MetersPerSecond<f64>>(),
+            Ok(MetersPerSecond::new(10.))
+        );
+        assert_eq!("2 kg·m·s⁻²".parse::<Newtons<f64>>(), Ok(Newtons::new(2.)));
+        assert_eq!("5 mm".parse::<Meters<f32>>(), Ok(Meters::new(0.005)));
+        assert_eq!("4 1/s".parse::<Hertz<f64>>(), Ok(Hertz::new(4.)));
+        assert_abs_diff_eq!("0 °C".parse::<Kelvins<f64>>().unwrap().into_inner(), 273.15);
+        assert_eq!(
+            "1 kg".parse::<M3<f64>>(),
+            Err(ParseQuantityError::DimensionMismatch {
+                expected: [3, 0, 0, 0, 0, 0, 0],
+                found: [0, 1, 0, 0, 0, 0, 0]
+            })
+        );
+        assert_eq!(
+            "1 furlong".parse::<Meters<f64>>(),
+            Err(ParseQuantityError::UnknownUnit)
+        );
+        assert!(matches!(
+            "x m".parse::<Meters<f64>>(),
+            Err(ParseQuantityError::Value(_))
+        ));
+        assert_eq!(
+            "1 m/m^-128".parse::<Meters<f64>>(),
+            Err(ParseQuantityError::Overflow)
+        );
+    }
+}
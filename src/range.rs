@@ -68,6 +68,7 @@ impl<Idx: PartialOrd<Idx>> Range<Idx> {
     }
 
     #[inline]
+    #[allow(clippy::neg_cmp_op_on_partial_ord)]
     pub fn is_empty(&self) -> bool {
         !(self.start < self.end)
     }